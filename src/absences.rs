@@ -0,0 +1,229 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Serialize, Deserialize};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::members::same_person;
+
+const ABSENCES_FILE: &str = "data/absences.json";
+
+const MONTHS: [&str; 12] = [
+    "january", "february", "march", "april", "may", "june",
+    "july", "august", "september", "october", "november", "december",
+];
+
+/// A family or person who's away, e.g. "the Smiths" from 6/1 through 6/14
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Absence {
+    pub id: u32,
+    pub who: String,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub recorded_by: String,
+}
+
+impl Absence {
+    /// "#2 the Smiths: 6/1 - 6/14"
+    pub fn describe(&self) -> String {
+        format!("#{} {}: {}", self.id, self.who, self.describe_dates())
+    }
+
+    /// "6/1 - 6/14", or just "7/4" for a single day
+    pub fn describe_dates(&self) -> String {
+        if self.start == self.end {
+            self.start.format("%-m/%-d").to_string()
+        } else {
+            format!("{} - {}", self.start.format("%-m/%-d"), self.end.format("%-m/%-d"))
+        }
+    }
+
+    pub fn covers(&self, name: &str, date: NaiveDate) -> bool {
+        (self.start..=self.end).contains(&date) && absence_matches(&self.who, name)
+    }
+}
+
+/// Whether an absence recorded for `who` applies to a volunteer name from the sheet.
+/// Families go by their surname, so "the Smiths" covers "Jane Smith" and "Joneses" covers "Bob Jones";
+/// anyone else has to match the whole name, so "Jane Doe" doesn't cover "Jane Smith".
+pub fn absence_matches(who: &str, name: &str) -> bool {
+    let words: Vec<String> = who.split_whitespace().map(|w| w.to_lowercase()).collect();
+    let is_family = words.len() == 1
+        || words.first().is_some_and(|w| w == "the")
+        || words.last().is_some_and(|w| w == "family");
+    if !is_family {
+        return same_person(who, name);
+    }
+
+    let Some(surname) = name.split_whitespace().last().map(str::to_lowercase) else {
+        return false;
+    };
+    words
+        .iter()
+        .filter(|w| !matches!(w.as_str(), "the" | "family"))
+        .any(|w| *w == surname || w.strip_suffix("es") == Some(surname.as_str()) || w.strip_suffix('s') == Some(surname.as_str()))
+}
+
+/// "june 1", "jun 1st", "6/1" or "6/1/2026" at the start of `text`; a bare day number uses `month`
+fn parse_day(text: &str, month: Option<u32>) -> Option<(Option<i32>, u32, u32)> {
+    let mut words = text.split_whitespace().map(|w| w.trim_matches(|c: char| c == ',' || c == '.'));
+    let first = words.next()?;
+    let day = |word: &str| -> Option<u32> {
+        word.trim_end_matches(|c: char| c.is_ascii_alphabetic()).parse().ok().filter(|d| (1..=31).contains(d))
+    };
+
+    if first.len() >= 3 && first.chars().all(|c| c.is_ascii_alphabetic()) {
+        let month = MONTHS.iter().position(|m| m.starts_with(first))? as u32 + 1;
+        return Some((None, month, day(words.next()?)?));
+    }
+    let parts: Vec<&str> = first.split('/').collect();
+    match parts.as_slice() {
+        [m, d] => Some((None, m.parse().ok()?, day(d)?)),
+        [m, d, y] => {
+            let year: i32 = y.parse().ok()?;
+            Some((Some(if year < 100 { 2000 + year } else { year }), m.parse().ok()?, day(d)?))
+        }
+        [d] => Some((None, month?, day(d)?)),
+        _ => None,
+    }
+}
+
+/// "june 1-14", "6/1 - 6/14", "june 1 to june 14", "6/28-7/3" or a single day like "7/4".
+/// Without a year the range is the next one that hasn't already ended.
+pub fn parse_date_range(text: &str, today: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+    let text = text.trim().to_lowercase().replace(['–', '—'], "-");
+    let text = text.strip_prefix("from ").or_else(|| text.strip_prefix("on ")).unwrap_or(&text);
+    // The first separator, so "june 1-14 to visit grandma" still ends on the 14th
+    let (first, last) = [" through ", " thru ", " until ", " till ", " to ", "-"]
+        .iter()
+        .filter_map(|sep| text.find(sep).map(|i| (i, sep.len())))
+        .min()
+        .map_or((text, text), |(i, len)| (&text[..i], &text[i + len..]));
+
+    let (start_year, start_month, start_day) = parse_day(first, None)?;
+    let (end_year, end_month, end_day) = parse_day(last, Some(start_month))?;
+
+    let year = start_year.or(end_year).unwrap_or(today.year());
+    let start = NaiveDate::from_ymd_opt(year, start_month, start_day)?;
+    let mut end = NaiveDate::from_ymd_opt(end_year.unwrap_or(year), end_month, end_day)?;
+    // "12/28-1/3" runs into the new year
+    if end < start {
+        end = end.with_year(end.year() + 1)?;
+    }
+    if start_year.is_none() && end_year.is_none() && end < today {
+        return Some((start.with_year(start.year() + 1)?, end.with_year(end.year() + 1)?));
+    }
+    // Long enough to be a typo rather than a vacation
+    (end - start <= Duration::days(366)).then_some((start, end))
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Absences {
+    next_id: u32,
+    entries: Vec<Absence>,
+}
+
+/// Who's away when, kept in data/absences.json. Past absences are dropped as new ones come in.
+#[derive(Clone)]
+pub struct AbsenceStore {
+    absences: Arc<RwLock<Absences>>,
+}
+
+impl Default for AbsenceStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AbsenceStore {
+    pub fn new() -> Self {
+        let absences = std::fs::read_to_string(ABSENCES_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self { absences: Arc::new(RwLock::new(absences)) }
+    }
+
+    fn save(absences: &Absences) {
+        if let Err(e) = std::fs::create_dir_all("data") { tracing::error!("Failed to create data dir: {}", e); }
+        if let Err(e) = std::fs::write(ABSENCES_FILE, serde_json::to_string_pretty(absences).unwrap_or_default()) {
+            tracing::error!("Failed to save absences: {}", e);
+        }
+    }
+
+    pub async fn add(&self, who: String, start: NaiveDate, end: NaiveDate, recorded_by: String, today: NaiveDate) -> Absence {
+        let mut absences = self.absences.write().await;
+        absences.entries.retain(|a| a.end >= today);
+        absences.next_id += 1;
+        let absence = Absence { id: absences.next_id, who, start, end, recorded_by };
+        absences.entries.push(absence.clone());
+        absences.entries.sort_by_key(|a| a.start);
+        Self::save(&absences);
+        absence
+    }
+
+    /// Current and upcoming absences, soonest first
+    pub async fn list(&self, today: NaiveDate) -> Vec<Absence> {
+        self.absences.read().await.entries.iter().filter(|a| a.end >= today).cloned().collect()
+    }
+
+    pub async fn remove(&self, id: u32) -> Option<Absence> {
+        let mut absences = self.absences.write().await;
+        let index = absences.entries.iter().position(|a| a.id == id)?;
+        let absence = absences.entries.remove(index);
+        Self::save(&absences);
+        Some(absence)
+    }
+
+    /// The absence keeping `name` away on `date`, if any
+    pub async fn away_on(&self, name: &str, date: NaiveDate) -> Option<Absence> {
+        self.absences.read().await.entries.iter().find(|a| a.covers(name, date)).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_parse_date_range() {
+        let today = date(5, 20);
+        assert_eq!(parse_date_range("june 1-14", today), Some((date(6, 1), date(6, 14))));
+        assert_eq!(parse_date_range("Jun 1st through June 14th", today), Some((date(6, 1), date(6, 14))));
+        assert_eq!(parse_date_range("6/1 - 6/14", today), Some((date(6, 1), date(6, 14))));
+        assert_eq!(parse_date_range("from 6/28 to 7/3", today), Some((date(6, 28), date(7, 3))));
+        assert_eq!(parse_date_range("7/4", today), Some((date(7, 4), date(7, 4))));
+        assert_eq!(
+            parse_date_range("12/28-1/3", today),
+            Some((date(12, 28), NaiveDate::from_ymd_opt(2027, 1, 3).unwrap()))
+        );
+        // Already over this year, so next year's
+        assert_eq!(
+            parse_date_range("may 1-3", today),
+            Some((NaiveDate::from_ymd_opt(2027, 5, 1).unwrap(), NaiveDate::from_ymd_opt(2027, 5, 3).unwrap()))
+        );
+        assert_eq!(parse_date_range("sick", today), None);
+        assert_eq!(parse_date_range("at second base", today), None);
+    }
+
+    #[test]
+    fn test_absence_matches() {
+        assert!(absence_matches("the Smiths", "Jane Smith"));
+        assert!(absence_matches("The Joneses", "Bob Jones"));
+        assert!(absence_matches("the Smith family", "Jane Smith"));
+        assert!(absence_matches("jane doe", "Jane Doe"));
+        assert!(!absence_matches("Jane Doe", "Jane Smith"));
+        assert!(!absence_matches("Jane Doe", "John Doe"));
+        assert!(!absence_matches("the Smiths", "Bob Jones"));
+        // Only the surname counts for a family
+        assert!(!absence_matches("the Smiths", "Smith Jones"));
+
+        let absence = Absence { id: 1, who: "the Smiths".to_string(), start: date(6, 1), end: date(6, 14), recorded_by: "Jane".to_string() };
+        assert!(absence.covers("Jane Smith", date(6, 14)));
+        assert!(!absence.covers("Jane Smith", date(6, 15)));
+        assert_eq!(absence.describe(), "#1 the Smiths: 6/1 - 6/14");
+    }
+}
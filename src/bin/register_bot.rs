@@ -0,0 +1,51 @@
+use groupme_bot::{config::Config, groupme_client::GroupMeClient, registration::sync_bot_registrations};
+
+/// Create or update the GroupMe bot registrations for this deployment.
+///
+///   register-bot          sync name, callback URL(s) and avatar (creates the bot if needed)
+///   register-bot list     show the bots owned by GROUPME_ACCESS_TOKEN
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🤖 GroupMe Bot Registration");
+    println!("===========================\n");
+
+    dotenvy::dotenv().ok();
+
+    // First-time setup has no bot id yet; sync will create the bot and print one
+    if std::env::var("GROUPME_BOT_ID").map(|id| id.is_empty()).unwrap_or(true) {
+        std::env::set_var("GROUPME_BOT_ID", "unregistered");
+    }
+
+    let config = match Config::from_env() {
+        Ok(config) => config,
+        Err(e) => {
+            println!("❌ Failed to load configuration: {}", e);
+            return Ok(());
+        }
+    };
+    let client = GroupMeClient::new(config.clone());
+
+    match std::env::args().nth(1).as_deref() {
+        Some("list") => match client.list_bots().await {
+            Ok(bots) if bots.is_empty() => println!("No bots registered to this access token."),
+            Ok(bots) => {
+                for bot in bots {
+                    println!("• {} ({}) in group {}", bot.name, bot.bot_id.unwrap_or_default(), bot.group_id);
+                    println!("    callback: {}", bot.callback_url.unwrap_or_else(|| "(none)".to_string()));
+                }
+            }
+            Err(e) => println!("❌ Failed to list bots: {}", e),
+        },
+        None | Some("sync") => match sync_bot_registrations(&config, &client).await {
+            Ok(report) => {
+                for line in report {
+                    println!("{}", line);
+                }
+            }
+            Err(e) => println!("❌ Registration failed: {}", e),
+        },
+        Some(other) => println!("Unknown command '{}'. Use: register-bot [sync|list]", other),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,536 @@
+use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+use chrono::{Duration, Local, NaiveDate};
+use rand::Rng;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio::sync::Semaphore;
+
+/// Fire a burst of concurrent GroupMe callbacks at the bot and check it keeps up:
+/// every request answered, one reply per distinct message, one sheet write per signup
+/// (even when GroupMe delivers a message twice) and bounded response times.
+///
+/// By default it starts `groupme-bot` from the same target directory, in a scratch data directory,
+/// with GroupMe and Google Sheets pointed at a mock served from this process. Settings:
+///
+///   STRESS_REQUESTS      callbacks to send (default 300, duplicates included)
+///   STRESS_CONCURRENCY   callbacks in flight at once (default 50)
+///   STRESS_MAX_P95_MS    slowest 95th-percentile response allowed (default 2000)
+///   STRESS_MOCK_PORT     port for the mock GroupMe/Sheets API (default 8099)
+///   STRESS_BOT_PORT      port for the bot it starts (default 8098)
+///   STRESS_TARGET        callback URL of a bot you started yourself instead; run it with
+///                        GROUPME_API_URL=http://127.0.0.1:<mock>/v3, SHEETS_API_URL=http://127.0.0.1:<mock>/v4,
+///                        GROUPME_BOT_NAME=PirateBot, TEAM_NAME=Pirates and a service account whose
+///                        token_uri is http://127.0.0.1:<mock>/token
+///
+/// Signups need a service account, so the bot it starts gets a throwaway key from `openssl genrsa`.
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🏴‍☠️ GroupMe Bot Stress Test");
+    println!("===========================\n");
+
+    let requests: usize = setting("STRESS_REQUESTS", 300)?;
+    let concurrency: usize = setting("STRESS_CONCURRENCY", 50)?;
+    let max_p95_ms: u128 = setting("STRESS_MAX_P95_MS", 2000)?;
+    let mock_port: u16 = setting("STRESS_MOCK_PORT", 8099)?;
+    let bot_port: u16 = setting("STRESS_BOT_PORT", 8098)?;
+
+    let plan = Plan::new(requests, Local::now().date_naive());
+    let upstream = web::Data::new(Upstream::new(plan.schedule_rows()));
+    let server = HttpServer::new({
+        let upstream = upstream.clone();
+        move || {
+            App::new()
+                .app_data(upstream.clone())
+                .route("/token", web::post().to(token))
+                .route("/v3/bots/post", web::post().to(bot_post))
+                .route("/v3/direct_messages", web::post().to(direct_message))
+                .route("/v4/spreadsheets/{sheet}/values:batchUpdate", web::post().to(batch_update))
+                .route("/v4/spreadsheets/{sheet}/values/{range}", web::get().to(read_range))
+                .route("/v4/spreadsheets/{sheet}/values/{range}", web::put().to(write_range))
+                .route("/v4/spreadsheets/{sheet}/values/{range}", web::post().to(write_range))
+                .default_service(web::to(unexpected))
+        }
+    })
+    .bind(("127.0.0.1", mock_port))?
+    .run();
+    tokio::spawn(server);
+    println!("🧪 Mock GroupMe/Sheets API on http://127.0.0.1:{}", mock_port);
+
+    // Keep the child (and its scratch directory) alive until the run is over
+    let (target, mut bot) = match std::env::var("STRESS_TARGET").ok().filter(|t| !t.trim().is_empty()) {
+        Some(target) => (target.trim().to_string(), None),
+        None => {
+            let bot = start_bot(mock_port, bot_port).await?;
+            (format!("http://127.0.0.1:{}/webhook", bot_port), Some(bot))
+        }
+    };
+    let health = target.rsplit_once('/').map(|(base, _)| format!("{}/healthz", base)).unwrap_or_default();
+    wait_until_up(&health, bot.as_mut()).await?;
+
+    // Anything the bot posts on its own at boot isn't a reply to us
+    tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    upstream.reset();
+
+    println!(
+        "🚀 Sending {} callbacks ({} distinct messages, {} malformed), {} at a time, to {}\n",
+        plan.deliveries.len(), plan.distinct_messages(), plan.malformed(), concurrency, target
+    );
+    let started = Instant::now();
+    let results = fire(&target, &plan, concurrency).await;
+    let elapsed = started.elapsed();
+
+    // Replies are sent before the callback is answered, but give stragglers a moment
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+    let report = check(&plan, &results, &upstream, max_p95_ms);
+    println!("⏱️  {} callbacks in {:.1}s", results.len(), elapsed.as_secs_f64());
+    for line in &report.lines {
+        println!("{}", line);
+    }
+    // exit() skips destructors, so stop the bot first or it keeps the port (and the ids it saw)
+    drop(bot);
+    if report.failures.is_empty() {
+        println!("\n✅ All checks passed");
+        Ok(())
+    } else {
+        println!("\n❌ {} check(s) failed:", report.failures.len());
+        for failure in &report.failures {
+            println!("  • {}", failure);
+        }
+        std::process::exit(1);
+    }
+}
+
+fn setting<T: std::str::FromStr>(name: &str, default: T) -> Result<T, String> {
+    match std::env::var(name) {
+        Ok(value) if !value.trim().is_empty() => value.trim().parse().map_err(|_| format!("{} must be a valid number", name)),
+        _ => Ok(default),
+    }
+}
+
+const BOT_NAME: &str = "PirateBot";
+/// Every role a signup can take; the mock's games are away games so scoreboard is open too
+const ROLES: [(&str, &str); 3] = [("snacks", "E"), ("livestream", "F"), ("scoreboard", "G")];
+
+/// One kind of callback and what the bot should do with it
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    /// "@PirateBot next game": one reply
+    NextGame,
+    /// "@PirateBot I'll bring snacks on 6/12": one reply and one cell written
+    Signup,
+    /// Gibberish at the bot: one "didn't understand" reply
+    Unknown,
+    /// Group chatter without a mention: no reply
+    Chatter,
+    /// The bot's own post coming back through the callback: no reply
+    Echo,
+    /// Not a GroupMe message at all: 400, no reply
+    Malformed,
+}
+
+impl Kind {
+    fn expects_reply(self) -> bool {
+        matches!(self, Kind::NextGame | Kind::Signup | Kind::Unknown)
+    }
+}
+
+struct Message {
+    kind: Kind,
+    body: String,
+    /// The A1 range a signup should write, e.g. "E12:E12"
+    writes: Option<String>,
+}
+
+/// The messages to send and the schedule the mock serves for them
+struct Plan {
+    messages: Vec<Message>,
+    /// Index into `messages` for each callback, shuffled, with repeat deliveries
+    deliveries: Vec<usize>,
+    games: Vec<NaiveDate>,
+}
+
+impl Plan {
+    fn new(requests: usize, today: NaiveDate) -> Self {
+        let mut rng = rand::thread_rng();
+        // About one message in six is delivered twice
+        let distinct = (requests * 6 / 7).max(1);
+        let signups = (0..distinct).filter(|i| Self::kind_for(*i) == Kind::Signup).count();
+        // Far enough out that no reminders fire during the run
+        let games: Vec<NaiveDate> = (0..signups.div_ceil(ROLES.len()).max(1))
+            .map(|i| today + Duration::days(10 + i as i64))
+            .collect();
+
+        let mut signup = 0;
+        let messages: Vec<Message> = (0..distinct)
+            .map(|i| {
+                let kind = Self::kind_for(i);
+                let id = format!("stress-{}", i);
+                let user = format!("{}", 900_000 + i);
+                let name = format!("Fan {}", i);
+                let (text, writes) = match kind {
+                    Kind::NextGame => (format!("@{} next game", BOT_NAME), None),
+                    Kind::Signup => {
+                        let (role, column) = ROLES[signup % ROLES.len()];
+                        let game = signup / ROLES.len();
+                        signup += 1;
+                        let text = format!("@{} I'll bring {} on {}", BOT_NAME, role, games[game].format("%-m/%-d"));
+                        (text, Some(format!("{}{}:{}{}", column, game + 2, column, game + 2)))
+                    }
+                    Kind::Unknown => (format!("@{} flibbertigibbet {}", BOT_NAME, i), None),
+                    Kind::Chatter => ("great hustle out there today".to_string(), None),
+                    Kind::Echo | Kind::Malformed => (String::new(), None),
+                };
+                let body = match kind {
+                    Kind::Echo => serde_json::json!({
+                        "id": id, "group_id": "stress-group", "text": "✅ Saved!", "sender_type": "bot",
+                        "name": BOT_NAME, "user_id": "stress-bot-id", "attachments": [],
+                    }).to_string(),
+                    Kind::Malformed => match i % 3 {
+                        0 => "{\"id\": \"broken\", \"text\": ".to_string(),
+                        1 => serde_json::json!({ "id": id, "text": "@PirateBot next game" }).to_string(),
+                        _ => "not json at all".to_string(),
+                    },
+                    _ => serde_json::json!({
+                        "id": id, "group_id": "stress-group", "text": text, "sender_type": "user",
+                        "name": name, "user_id": user, "attachments": [],
+                    }).to_string(),
+                };
+                Message { kind, body, writes }
+            })
+            .collect();
+
+        let mut deliveries: Vec<usize> = (0..distinct).collect();
+        while deliveries.len() < requests {
+            deliveries.push(rng.gen_range(0..distinct));
+        }
+        // Fisher-Yates, so repeats can land right next to (and race) the first delivery
+        for i in (1..deliveries.len()).rev() {
+            deliveries.swap(i, rng.gen_range(0..=i));
+        }
+        Plan { messages, deliveries, games }
+    }
+
+    fn kind_for(i: usize) -> Kind {
+        match i % 10 {
+            0..=2 => Kind::Signup,
+            3 | 4 => Kind::NextGame,
+            5 | 6 => Kind::Unknown,
+            7 => Kind::Chatter,
+            8 => Kind::Echo,
+            _ => Kind::Malformed,
+        }
+    }
+
+    fn distinct_messages(&self) -> usize {
+        self.messages.len()
+    }
+
+    fn malformed(&self) -> usize {
+        self.deliveries.iter().filter(|&&i| self.messages[i].kind == Kind::Malformed).count()
+    }
+
+    /// Sheet rows A2:J, one away game per date with every role open
+    fn schedule_rows(&self) -> Vec<Vec<String>> {
+        self.games
+            .iter()
+            .map(|date| {
+                let mut row = vec![date.format("%Y-%m-%d").to_string(), "10:00 AM".to_string(), "Riverside Park".to_string(), "Sharks".to_string()];
+                row.resize(10, String::new());
+                row
+            })
+            .collect()
+    }
+}
+
+/// What the mock GroupMe and Sheets APIs were asked to do
+struct Upstream {
+    rows: Vec<Vec<String>>,
+    posts: Mutex<Vec<String>>,
+    direct_messages: Mutex<usize>,
+    /// Cells written, by A1 range, with how many times each was written
+    writes: Mutex<HashMap<String, usize>>,
+    unexpected: Mutex<Vec<String>>,
+}
+
+impl Upstream {
+    fn new(rows: Vec<Vec<String>>) -> Self {
+        Upstream {
+            rows,
+            posts: Mutex::new(Vec::new()),
+            direct_messages: Mutex::new(0),
+            writes: Mutex::new(HashMap::new()),
+            unexpected: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn reset(&self) {
+        self.posts.lock().unwrap().clear();
+        *self.direct_messages.lock().unwrap() = 0;
+        self.writes.lock().unwrap().clear();
+        self.unexpected.lock().unwrap().clear();
+    }
+
+    fn record_write(&self, range: &str) {
+        *self.writes.lock().unwrap().entry(range.to_string()).or_default() += 1;
+    }
+}
+
+/// Real APIs take a while; so does the mock, so slow upstreams overlap the way they would live
+async fn upstream_delay() {
+    let millis = rand::thread_rng().gen_range(5..50);
+    tokio::time::sleep(std::time::Duration::from_millis(millis)).await;
+}
+
+async fn token() -> HttpResponse {
+    HttpResponse::Ok().json(serde_json::json!({ "access_token": "stress-token", "expires_in": 3600, "token_type": "Bearer" }))
+}
+
+async fn bot_post(upstream: web::Data<Upstream>, body: web::Json<serde_json::Value>) -> HttpResponse {
+    upstream_delay().await;
+    let text = body.get("text").and_then(|t| t.as_str()).unwrap_or_default().to_string();
+    upstream.posts.lock().unwrap().push(text);
+    HttpResponse::Accepted().finish()
+}
+
+async fn direct_message(upstream: web::Data<Upstream>) -> HttpResponse {
+    upstream_delay().await;
+    *upstream.direct_messages.lock().unwrap() += 1;
+    HttpResponse::Created().json(serde_json::json!({ "response": {} }))
+}
+
+async fn read_range(upstream: web::Data<Upstream>, path: web::Path<(String, String)>) -> HttpResponse {
+    upstream_delay().await;
+    let (_, range) = path.into_inner();
+    // Only the schedule is configured; other ranges (standings, events) are empty
+    let values = if range.starts_with("A2") { upstream.rows.clone() } else { Vec::new() };
+    HttpResponse::Ok().json(serde_json::json!({ "range": range, "values": values }))
+}
+
+async fn write_range(upstream: web::Data<Upstream>, path: web::Path<(String, String)>) -> HttpResponse {
+    upstream_delay().await;
+    let (_, range) = path.into_inner();
+    upstream.record_write(&range);
+    HttpResponse::Ok().json(serde_json::json!({ "updatedRange": range }))
+}
+
+async fn batch_update(upstream: web::Data<Upstream>, body: web::Json<serde_json::Value>) -> HttpResponse {
+    upstream_delay().await;
+    for range in body.get("data").and_then(|d| d.as_array()).into_iter().flatten().filter_map(|d| d.get("range")?.as_str()) {
+        upstream.record_write(range);
+    }
+    HttpResponse::Ok().json(serde_json::json!({}))
+}
+
+async fn unexpected(upstream: web::Data<Upstream>, req: HttpRequest) -> HttpResponse {
+    upstream.unexpected.lock().unwrap().push(format!("{} {}", req.method(), req.path()));
+    HttpResponse::NotFound().finish()
+}
+
+/// A bot process started for this run, in its own scratch directory; killed on drop
+struct SpawnedBot {
+    child: tokio::process::Child,
+    dir: PathBuf,
+}
+
+impl Drop for SpawnedBot {
+    fn drop(&mut self) {
+        println!("🧹 Bot logs were in {}", self.dir.join("logs").display());
+    }
+}
+
+async fn start_bot(mock_port: u16, bot_port: u16) -> Result<SpawnedBot, Box<dyn std::error::Error>> {
+    let binary = std::env::current_exe()?.with_file_name(format!("groupme-bot{}", std::env::consts::EXE_SUFFIX));
+    if !binary.exists() {
+        return Err(format!("{} not found; run `cargo build --bin groupme-bot` first", binary.display()).into());
+    }
+    let dir = std::env::temp_dir().join(format!("groupme-bot-stress-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+    let mock = format!("http://127.0.0.1:{}", mock_port);
+    let key_file = write_service_account(&dir, &mock)?;
+
+    let child = tokio::process::Command::new(&binary)
+        .current_dir(&dir)
+        // Nothing from the caller's environment, so a real deployment's settings can't leak in
+        .env_clear()
+        .env("PATH", std::env::var("PATH").unwrap_or_default())
+        .env("RUST_LOG", "warn")
+        .envs([
+            ("GROUPME_BOT_ID", "stress-bot-id"),
+            ("GROUPME_BOT_NAME", BOT_NAME),
+            ("TEAM_NAME", "Pirates"),
+            ("SHEET_ID", "stress-sheet"),
+            ("GOOGLE_API_KEY", "unused"),
+            ("ADMIN_USER_ID", "stress-admin"),
+            ("STARTUP_REPORT", "off"),
+            ("DIGEST_DAY", "off"),
+            ("MORNING_BRIEFING", "false"),
+            ("VALIDATE_VOLUNTEER_NAMES", "false"),
+            ("FEATURES_DISABLED", "weather"),
+            ("UNKNOWN_REPLY_LIMIT", "0"),
+        ])
+        .env("PORT", bot_port.to_string())
+        .env("GROUPME_API_URL", format!("{}/v3", mock))
+        .env("SHEETS_API_URL", format!("{}/v4", mock))
+        .env("GOOGLE_SERVICE_ACCOUNT_JSON", &key_file)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn()?;
+    println!("🤖 Started {} on port {} (data in {})", binary.display(), bot_port, dir.display());
+    Ok(SpawnedBot { child, dir })
+}
+
+/// A service account key whose token endpoint is the mock, signed with a throwaway RSA key
+fn write_service_account(dir: &Path, mock: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let pem = dir.join("stress-key.pem");
+    let status = std::process::Command::new("openssl")
+        .args(["genrsa", "-out"])
+        .arg(&pem)
+        .arg("2048")
+        .stderr(Stdio::null())
+        .status()
+        .map_err(|e| format!("openssl is needed to make a throwaway signing key: {}", e))?;
+    if !status.success() {
+        return Err("openssl genrsa failed".into());
+    }
+    let key = serde_json::json!({
+        "client_email": "stress@example.invalid",
+        "private_key": std::fs::read_to_string(&pem)?,
+        "token_uri": format!("{}/token", mock),
+    });
+    let path = dir.join("stress-service-account.json");
+    std::fs::write(&path, key.to_string())?;
+    Ok(path)
+}
+
+async fn wait_until_up(health: &str, mut bot: Option<&mut SpawnedBot>) -> Result<(), Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+    for _ in 0..60 {
+        // Otherwise a bot left over from another run could answer in its place
+        if let Some(status) = bot.as_mut().and_then(|b| b.child.try_wait().ok().flatten()) {
+            return Err(format!("the bot exited during startup ({}); is its port already in use?", status).into());
+        }
+        if client.get(health).send().await.is_ok_and(|r| r.status().is_success()) {
+            return Ok(());
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    }
+    Err(format!("bot never came up at {}", health).into())
+}
+
+/// How one callback went: the message it carried, the status (or transport error) and how long it took
+struct Outcome {
+    message: usize,
+    status: Result<u16, String>,
+    millis: u128,
+}
+
+async fn fire(target: &str, plan: &Plan, concurrency: usize) -> Vec<Outcome> {
+    let client = reqwest::Client::builder().timeout(std::time::Duration::from_secs(30)).build().unwrap_or_default();
+    let permits = Arc::new(Semaphore::new(concurrency.max(1)));
+    let mut tasks = tokio::task::JoinSet::new();
+    for &message in &plan.deliveries {
+        let Ok(permit) = permits.clone().acquire_owned().await else { break };
+        let request = client
+            .post(target)
+            .header("Content-Type", "application/json")
+            .body(plan.messages[message].body.clone());
+        tasks.spawn(async move {
+            let started = Instant::now();
+            let status = request.send().await.map(|r| r.status().as_u16()).map_err(|e| e.to_string());
+            drop(permit);
+            Outcome { message, status, millis: started.elapsed().as_millis() }
+        });
+    }
+    let mut outcomes = Vec::new();
+    while let Some(outcome) = tasks.join_next().await {
+        if let Ok(outcome) = outcome {
+            outcomes.push(outcome);
+        }
+    }
+    outcomes
+}
+
+struct Report {
+    lines: Vec<String>,
+    failures: Vec<String>,
+}
+
+fn check(plan: &Plan, outcomes: &[Outcome], upstream: &Upstream, max_p95_ms: u128) -> Report {
+    let mut lines = Vec::new();
+    let mut failures = Vec::new();
+
+    // Every callback answered, with the right status
+    let lost = plan.deliveries.len() - outcomes.len() + outcomes.iter().filter(|o| o.status.is_err()).count();
+    lines.push(format!("📨 {} answered, {} lost", outcomes.len() - outcomes.iter().filter(|o| o.status.is_err()).count(), lost));
+    if lost > 0 {
+        let sample = outcomes.iter().find_map(|o| o.status.as_ref().err()).cloned().unwrap_or_default();
+        failures.push(format!("{} callback(s) got no response (e.g. {})", lost, sample));
+    }
+    let wrong_status = outcomes
+        .iter()
+        .filter_map(|o| {
+            let expected = if plan.messages[o.message].kind == Kind::Malformed { 400 } else { 200 };
+            o.status.as_ref().ok().filter(|&&s| s != expected).map(|s| (o.message, *s, expected))
+        })
+        .collect::<Vec<_>>();
+    if let Some((message, got, expected)) = wrong_status.first() {
+        failures.push(format!("{} callback(s) got the wrong status (message {} got {}, expected {})", wrong_status.len(), message, got, expected));
+    }
+
+    // One reply per distinct message that wants one, however many times it was delivered
+    let posts = upstream.posts.lock().unwrap().clone();
+    let expected_replies = plan.messages.iter().filter(|m| m.kind.expects_reply()).count();
+    lines.push(format!("💬 {} replies posted (expected {}), {} DMs", posts.len(), expected_replies, upstream.direct_messages.lock().unwrap()));
+    if posts.len() != expected_replies {
+        failures.push(format!("{} replies posted, expected {}", posts.len(), expected_replies));
+    }
+    let failed_replies: Vec<&String> = posts.iter().filter(|p| p.contains('❌') || p.contains("ran into a problem")).collect();
+    if let Some(sample) = failed_replies.first() {
+        failures.push(format!("{} replies were errors (e.g. {:?})", failed_replies.len(), sample));
+    }
+
+    // One write per signup cell: a repeat delivery mustn't write again
+    let writes = upstream.writes.lock().unwrap().clone();
+    let expected_writes: Vec<&String> = plan.messages.iter().filter_map(|m| m.writes.as_ref()).collect();
+    lines.push(format!("📝 {} sheet writes to {} cells (expected {})", writes.values().sum::<usize>(), writes.len(), expected_writes.len()));
+    let duplicated: Vec<_> = writes.iter().filter(|(_, &count)| count > 1).collect();
+    if !duplicated.is_empty() {
+        failures.push(format!("{} cell(s) written more than once, e.g. {:?}", duplicated.len(), duplicated[0]));
+    }
+    let missing = expected_writes.iter().filter(|range| !writes.contains_key(range.as_str())).count();
+    if missing > 0 {
+        failures.push(format!("{} signup(s) never reached the sheet", missing));
+    }
+    let unexpected = upstream.unexpected.lock().unwrap().clone();
+    if !unexpected.is_empty() {
+        lines.push(format!("⚠️  {} call(s) to endpoints the mock doesn't serve, e.g. {}", unexpected.len(), unexpected[0]));
+    }
+
+    // Latency
+    let mut millis: Vec<u128> = outcomes.iter().filter(|o| o.status.is_ok()).map(|o| o.millis).collect();
+    millis.sort_unstable();
+    let percentile = |p: usize| millis.get((millis.len() * p / 100).min(millis.len().saturating_sub(1))).copied().unwrap_or_default();
+    let p95 = percentile(95);
+    lines.push(format!("⏱️  latency p50 {}ms, p95 {}ms, max {}ms", percentile(50), p95, millis.last().copied().unwrap_or_default()));
+    let slowest = [Kind::NextGame, Kind::Signup, Kind::Unknown, Kind::Chatter, Kind::Echo, Kind::Malformed]
+        .into_iter()
+        .filter_map(|kind| {
+            let of_kind = outcomes.iter().filter(|o| o.status.is_ok() && plan.messages[o.message].kind == kind);
+            Some((kind, of_kind.map(|o| o.millis).max()?))
+        })
+        .max_by_key(|(_, millis)| *millis);
+    if let Some((kind, millis)) = slowest {
+        lines.push(format!("🐢 slowest kind of message: {:?} (up to {}ms)", kind, millis));
+    }
+    if p95 > max_p95_ms {
+        failures.push(format!("p95 latency {}ms is over STRESS_MAX_P95_MS ({}ms)", p95, max_p95_ms));
+    }
+
+    Report { lines, failures }
+}
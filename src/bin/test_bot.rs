@@ -30,7 +30,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create services
     let moderators_store = ModeratorsStore::new();
     let bot_service = BotService::new(config.clone());
-    let command_parser = CommandParser::new(config.groupme_bot_name.clone());
+    let command_parser = CommandParser::new(config.groupme_bot_name.clone()).with_timezone(config.team_timezone);
 
     println!("🤖 GroupMe Bot CLI Tester");
     println!("Bot Name: {}", config.groupme_bot_name);
@@ -55,12 +55,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
 
         // Parse the command
-        match command_parser.parse_message(input, None, None, &[]).await {
+        match command_parser.parse_message(input, None, None, None, &[]).await {
             Ok(Some(command)) => {
                 println!("📝 Parsed command: {:?}", command);
                 
                 // Handle the command
-                match bot_service.handle_command(command, None, None, &moderators_store).await {
+                match bot_service.handle_command(command, None, None, None, &moderators_store).await {
                     Ok(response) => {
                         println!("🤖 Bot Response:");
                         println!("─────────────────");
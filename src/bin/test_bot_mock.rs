@@ -41,7 +41,7 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         }
 
         // Parse the command
-        match command_parser.parse_message(input, None, None, &[]).await {
+        match command_parser.parse_message(input, None, None, None, &[]).await {
             Ok(Some(command)) => {
                 println!("📝 Parsed command: {:?}", command);
                 
@@ -67,6 +67,7 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
 
 fn handle_command_mock(command: BotCommand, bot_name: &str) -> String {
     match command {
+        BotCommand::HelpByDm => "📬 Sent you a DM with what I can do (Mock)".to_string(),
         BotCommand::Commands => {
             format!(
                 "⚾ {} Commands:\\n\\n\
@@ -104,8 +105,8 @@ fn handle_command_mock(command: BotCommand, bot_name: &str) -> String {
             }
             response
         }
-        BotCommand::NextGameCategory(category) => {
-            match category.as_str() {
+        BotCommand::NextGameCategory(categories, _) => {
+            categories.iter().map(|category| match category.as_str() {
                 "location" => "⚾ Next game location: Memorial Stadium (https://maps.google.com/?q=Memorial%20Stadium)".to_string(),
                 "snacks" => "⚾ Next game snacks: Sarah Johnson".to_string(),
                 "livestream" => "⚾ Next game livestream: Mike Wilson".to_string(),
@@ -114,7 +115,7 @@ fn handle_command_mock(command: BotCommand, bot_name: &str) -> String {
                 "time" => "⚾ Next game time: 7:30pm".to_string(),
                 "home" => "⚾ Next game home: Away".to_string(),
                 _ => format!("❌ No {} information available for the next game.", category),
-            }
+            }).collect::<Vec<_>>().join("\n")
         }
         BotCommand::LetsGo(team) => {
             match team.as_str() {
@@ -163,6 +164,193 @@ fn handle_command_mock(command: BotCommand, bot_name: &str) -> String {
         BotCommand::ListBotMessages(count) => {
             format!("🏴‍☠️ Recent bot messages (Mock - last {}):\n\n1. ID: 12345678901234 - ⚾ Next Game: Pirates vs Cardinals...\n2. ID: 12345678901235 - ✅ John has been assigned to snacks...\n\n💡 Note: Messages can only be deleted manually through the GroupMe mobile app.", count)
         }
+        BotCommand::WhoIs(user_id) => {
+            format!("🏴‍☠️ {} is Jane Doe (Mock)", user_id)
+        }
+        BotCommand::RequestConfirmation(command) => {
+            format!("⚠️ About to run {:?} (Mock). Reply 'confirm' within 60s to proceed, or 'cancel'.", command)
+        }
+        BotCommand::Confirmed(command) => {
+            handle_command_mock(*command, bot_name)
+        }
+        BotCommand::CancelPending => {
+            "👍 Cancelled, nothing was changed (Mock).".to_string()
+        }
+        BotCommand::SetGameNote(date, note) => {
+            let date_str = date.map(|d| d.to_string()).unwrap_or_else(|| "next game".to_string());
+            format!("📝 Note added for {} (Mock): {}", date_str, note)
+        }
+        BotCommand::CancelGame(date, reason) => {
+            let date_str = date.map(|d| d.to_string()).unwrap_or_else(|| "next game".to_string());
+            format!("🚫 The game on {} is cancelled (Mock){}", date_str, reason.map(|r| format!(": {}", r)).unwrap_or_default())
+        }
+        BotCommand::ClearGameNote(date) => {
+            let date_str = date.map(|d| d.to_string()).unwrap_or_else(|| "next game".to_string());
+            format!("🧹 Cleared the note for {} (Mock)", date_str)
+        }
+        BotCommand::RequestBringItems(event, items) => {
+            format!("🎉 Added to the {} list (Mock): {}", event, items.join(", "))
+        }
+        BotCommand::BringItem(event, item, person) => {
+            format!("🎉 Thanks {}! You're bringing {} for the {} (Mock).", person, item, event.unwrap_or_else(|| "party".to_string()))
+        }
+        BotCommand::BringListStatus(event) => {
+            format!("🎉 {} bring list (Mock):\n✅ Jane - cupcakes\n\n⚠️ Still needed: plates, drinks", event.unwrap_or_else(|| "party".to_string()))
+        }
+        BotCommand::ClearBringList(event) => {
+            format!("🧹 Cleared the {} list (Mock)", event)
+        }
+        BotCommand::RecordResult(_, opponent, our_score, their_score) => {
+            format!("📊 Recorded {}-{} vs {} (Mock)", our_score, their_score, opponent.unwrap_or_else(|| "Chaos".to_string()))
+        }
+        BotCommand::LogPlayerStats(_, player, counts) => {
+            format!("📈 Logged for {} (Mock): {}", player, counts.summary())
+        }
+        BotCommand::PlayerStats(player) => {
+            format!("📈 {} (Mock): 5-12 (.417), 3 RBI over 4 games", player)
+        }
+        BotCommand::RenameBot(name) => {
+            format!("🏷️ I'm now {} (Mock)", name)
+        }
+        BotCommand::SetBotAvatar(_) => {
+            "🖼️ New avatar set (Mock)".to_string()
+        }
+        BotCommand::ClearAssignments(person) => {
+            format!("🧹 Cleared 2 upcoming spots for {} (Mock)", person)
+        }
+        BotCommand::ClearVolunteers(date) => {
+            format!("🧹 Cleared 3 volunteer spots for {} (Mock)", date.format("%-m/%-d"))
+        }
+        BotCommand::CopyVolunteers(from, to) => {
+            format!("📋 Copied 3 volunteers from {} to {} (Mock)", from.format("%-m/%-d"), to.format("%-m/%-d"))
+        }
+        BotCommand::ModeratorDigest => {
+            "🗒️ Moderator digest (Mock)\n⚠️ Signed up but not in the group:\n• snacks on 5/3: Jane Doe".to_string()
+        }
+        BotCommand::ImportSchedule(url) => {
+            format!("📥 Imported 12 games from {} (Mock)", url)
+        }
+        BotCommand::SetVenueTimezone(venue, timezone) => {
+            format!("🕐 {} now uses {} (Mock)", venue, timezone.unwrap_or_else(|| "local time".to_string()))
+        }
+        BotCommand::StartTournament(name) => {
+            format!("🏆 Started {} (Mock)", name)
+        }
+        BotCommand::SetBracketGame(game) => {
+            format!("🏆 Saved {} (Mock)", game.describe())
+        }
+        BotCommand::RecordBracketResult(number, _) => {
+            format!("🏆 Bracket updated for game {} (Mock)\n⏭️ Next up: Game 3: 6/14 3:00 PM @ Tournament Park", number)
+        }
+        BotCommand::ShowBracket => {
+            "🏆 Summer Slam (Mock)\n✅ Game 1: 6/14 9:00 AM @ Tournament Park vs Storm\n📅 Game 2: 6/14 1:00 PM @ Tournament Park".to_string()
+        }
+        BotCommand::ClearTournament => {
+            "🧹 Tournament cleared (Mock)".to_string()
+        }
+        BotCommand::SetTournamentDates(start, end) => {
+            format!("🏆 Tournament runs {}-{} (Mock)", start.format("%-m/%-d"), end.format("%-m/%-d"))
+        }
+        BotCommand::SetTournamentLogistics(label, value) => {
+            format!("🏆 {}: {} (Mock)", label, value.unwrap_or_else(|| "removed".to_string()))
+        }
+        BotCommand::SetFeature(feature, enabled, _) => {
+            format!("🎛️ {} turned {} (Mock)", feature.label(), if enabled { "on" } else { "off" })
+        }
+        BotCommand::ListFeatures => {
+            "🎛️ Features for this group (Mock):\n✅ weather\n✅ team facts".to_string()
+        }
+        BotCommand::RunPlugin(name, _) => {
+            format!("🧩 Ran the {} plugin (Mock)", name)
+        }
+        BotCommand::ScheduleMessage(_, time, text) => {
+            format!("🗓️ Scheduled #1 {} (Mock): {}", time.format("%-I:%M %p"), text)
+        }
+        BotCommand::ListScheduledMessages => {
+            "🗓️ Scheduled messages (Mock):\n• #1 Fri 5/2 6:00 PM: Practice moved to Field 2".to_string()
+        }
+        BotCommand::CancelScheduledMessage(id) => {
+            format!("🗑️ Cancelled #{} (Mock)", id)
+        }
+        BotCommand::RecordAbsence(who, start, end) => {
+            format!("🏖️ Got it: {} out {} - {} (Mock)", who, start.format("%-m/%-d"), end.format("%-m/%-d"))
+        }
+        BotCommand::ListAbsences => {
+            "🏖️ Away (Mock):\n• #1 the Smiths: 6/1 - 6/14".to_string()
+        }
+        BotCommand::RemoveAbsence(id) => {
+            format!("🗑️ Removed absence #{} (Mock)", id)
+        }
+        BotCommand::BlockWord(word) => {
+            format!("🚫 Blocked \"{}\" (Mock)", word)
+        }
+        BotCommand::UnblockWord(word) => {
+            format!("✅ Unblocked \"{}\" (Mock)", word)
+        }
+        BotCommand::ListBlockedWords => {
+            "🚫 Blocked words (Mock): none".to_string()
+        }
+        BotCommand::VolunteerAnalytics => {
+            "📊 Volunteer analytics (Mock): 75% of games fully staffed".to_string()
+        }
+        BotCommand::Version => {
+            "🤖 Running v0.2.0 (Mock)".to_string()
+        }
+        BotCommand::AddSpecialEvent(title, date, _, _) => {
+            format!("📅 Added #1 {} on {} (Mock)", title, date.format("%-m/%-d"))
+        }
+        BotCommand::ListSpecialEvents => {
+            "📅 Special events (Mock):\n• #1 📸 Picture Day: Saturday 5/9, 9:00 AM at Hall Field".to_string()
+        }
+        BotCommand::RemoveSpecialEvent(id) => {
+            format!("🗑️ Removed event #{} (Mock)", id)
+        }
+        BotCommand::SetEventReminder(kind, lead_hours, _) => {
+            format!("⏰ {} reminder set for {} hours before (Mock)", kind, lead_hours)
+        }
+        BotCommand::ShowEventReminders(kind) => {
+            format!("⏰ {} reminders (Mock): 72 hours, 18 hours before", kind)
+        }
+        BotCommand::ResetEventReminders(kind) => {
+            format!("⏰ {} reminders reset (Mock)", kind)
+        }
+        BotCommand::SetGameMode(on) => {
+            if on { "🎬 Game mode on (Mock)".to_string() } else { "🏁 Game mode off (Mock)".to_string() }
+        }
+        BotCommand::LiveScore(ours, theirs) => {
+            format!("📣 Pirates {}, Storm {} (Mock)", ours, theirs)
+        }
+        BotCommand::CollectPhotos(_) => String::new(),
+        BotCommand::GamePhotos(_) => {
+            "📸 1 photo(s) from 5/3 (Mock):\nhttps://i.groupme.com/mock.jpeg".to_string()
+        }
+        BotCommand::ClaimOpenRole(_, person) => {
+            format!("✅ {} signed up for snacks on 5/3 (Mock)\n🔄 Rather do something else? Still open: livestream, scoreboard.", person)
+        }
+        BotCommand::Weather(_) => {
+            "🌤️ Forecast for Hall Field at 10:00 AM (Mock): 72°F, 10% chance of rain".to_string()
+        }
+        BotCommand::AddToCalendar(_) => {
+            "📅 Tap to add 5/3 (Pirates vs Chaos) to your calendar (Mock):\nhttps://bot.example.com/event/2025-05-03.ics".to_string()
+        }
+        BotCommand::QrCode(_) => {
+            "📅 Scan to subscribe to the season calendar (Mock): https://bot.example.com/calendar.ics".to_string()
+        }
+        BotCommand::SignupLink(_) => {
+            "🔗 Signup page for 5/3 (Mock): https://bot.example.com/signup/2025-05-03-1a2b3c4d5e6f7a8b".to_string()
+        }
+        BotCommand::TournamentInfo => {
+            "🏆 Summer Slam (6/13-6/15) (Mock)\n🏨 Hotel: https://example.com/block\n🍝 Dinner: Saturday 7pm".to_string()
+        }
+        BotCommand::ListVenues => {
+            "📍 Venue time zones (Mock):\n• Tournament Park: America/Chicago".to_string()
+        }
+        BotCommand::TeamStats => {
+            "📈 Team stats (Mock):\n1. Jake 5-12 (.417), 3 RBI".to_string()
+        }
+        BotCommand::HeadToHead(opponent) => {
+            format!("📊 vs {} this season (Mock): 1-1\n• 4/12: L 4-5\n• 5/3: W 7-3", opponent)
+        }
     }
 }
 
@@ -91,7 +91,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     match google_client.get_sheets_data().await {
         Ok(data) => {
             println!("✅ Sheets data retrieved: {} rows", data.len());
-            for (i, (date, time, location, home_team, snacks, _livestream, _scoreboard, _pitch_count, _gamechanger)) in data.iter().take(3).enumerate() {
+            for (i, (date, time, location, home_team, snacks, _livestream, _scoreboard, _pitch_count, _gamechanger, _notes)) in data.iter().take(3).enumerate() {
                 let snacks_display = if snacks.trim().is_empty() { "NEEDED" } else { snacks };
                 println!("  {}. {} - {} at {} (Home/Away: {}) - Snacks: {}", i+1, date, time, location, home_team, snacks_display);
             }
@@ -26,7 +26,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("Column mapping:");
             println!("  A = Date, B = Time, C = Location, D = Home Team, E = Snacks, F = Livestream, G = Scoreboard, H = Pitch Count\n");
             
-            for (i, (date, time, location, home_team, snacks, livestream, scoreboard, pitch_count)) in data.iter().enumerate() {
+            for (i, (date, time, location, home_team, snacks, livestream, scoreboard, pitch_count, _gamechanger, _notes)) in data.iter().enumerate() {
                 if date.to_string().contains("2025-08-27") {
                     println!("🎯 FOUND 2025-08-27 EVENT:");
                     println!("  Date (A): {}", date);
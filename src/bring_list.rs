@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use serde::{Serialize, Deserialize};
+use tokio::sync::RwLock;
+
+const BRING_LISTS_FILE: &str = "data/bring_lists.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BringClaim {
+    pub person: String,
+    pub item: String,
+}
+
+/// Open-ended signup list for a non-game event like the end-of-season party
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BringList {
+    /// Items the organizers asked for
+    pub requested: Vec<String>,
+    /// What people said they'd bring, requested or not
+    pub claims: Vec<BringClaim>,
+}
+
+impl BringList {
+    /// Requested items nobody has claimed yet ("cups" is covered by "paper cups")
+    pub fn remaining_items(&self) -> Vec<String> {
+        self.requested
+            .iter()
+            .filter(|wanted| {
+                let wanted = wanted.to_lowercase();
+                !self.claims.iter().any(|c| {
+                    let item = c.item.to_lowercase();
+                    item.contains(&wanted) || wanted.contains(&item)
+                })
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+#[derive(Clone)]
+pub struct BringListStore {
+    lists: Arc<RwLock<BTreeMap<String, BringList>>>,
+}
+
+impl Default for BringListStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BringListStore {
+    pub fn new() -> Self {
+        let _ = std::fs::create_dir_all("data");
+        let lists = std::fs::read_to_string(BRING_LISTS_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self { lists: Arc::new(RwLock::new(lists)) }
+    }
+
+    fn save(lists: &BTreeMap<String, BringList>) {
+        if let Err(e) = std::fs::create_dir_all("data") { tracing::error!("Failed to create data dir: {}", e); }
+        if let Err(e) = std::fs::write(BRING_LISTS_FILE, serde_json::to_string_pretty(lists).unwrap_or_default()) {
+            tracing::error!("Failed to save bring lists: {}", e);
+        }
+    }
+
+    /// Pick the event a message is about: the named one, or the only list if just one exists
+    pub async fn resolve_event(&self, event: Option<&str>) -> Option<String> {
+        let lists = self.lists.read().await;
+        match event {
+            Some(name) => Some(name.to_lowercase()),
+            None if lists.len() == 1 => lists.keys().next().cloned(),
+            None => None,
+        }
+    }
+
+    pub async fn event_names(&self) -> Vec<String> {
+        self.lists.read().await.keys().cloned().collect()
+    }
+
+    pub async fn request_items(&self, event: &str, items: Vec<String>) {
+        let mut lists = self.lists.write().await;
+        let list = lists.entry(event.to_lowercase()).or_default();
+        for item in items {
+            if !list.requested.iter().any(|r| r.eq_ignore_ascii_case(&item)) {
+                list.requested.push(item);
+            }
+        }
+        Self::save(&lists);
+    }
+
+    pub async fn add_claim(&self, event: &str, person: String, item: String) {
+        let mut lists = self.lists.write().await;
+        lists.entry(event.to_lowercase()).or_default().claims.push(BringClaim { person, item });
+        Self::save(&lists);
+    }
+
+    pub async fn get(&self, event: &str) -> Option<BringList> {
+        self.lists.read().await.get(&event.to_lowercase()).cloned()
+    }
+
+    pub async fn clear(&self, event: &str) -> bool {
+        let mut lists = self.lists.write().await;
+        let removed = lists.remove(&event.to_lowercase()).is_some();
+        if removed {
+            Self::save(&lists);
+        }
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remaining_items() {
+        let list = BringList {
+            requested: vec!["cups".to_string(), "plates".to_string(), "drinks".to_string()],
+            claims: vec![
+                BringClaim { person: "Jane".to_string(), item: "paper cups".to_string() },
+                BringClaim { person: "Mike".to_string(), item: "cupcakes".to_string() },
+            ],
+        };
+        assert_eq!(list.remaining_items(), vec!["plates".to_string(), "drinks".to_string()]);
+    }
+}
@@ -0,0 +1,106 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+use crate::config::Config;
+use crate::models::CorrelatedEvent;
+
+/// How long a game is assumed to run when the sheet only gives a start time
+const GAME_LENGTH_HOURS: i64 = 2;
+
+/// Public URL of the season feed; None without PUBLIC_BASE_URL
+pub fn calendar_feed_url(config: &Config) -> Option<String> {
+    let base = config.public_base_url.as_ref()?;
+    Some(format!("{}/calendar.ics", base.trim_end_matches('/')))
+}
+
+/// Stable id for a game: "2025-05-03", or "2025-05-03-2" for the second game that day
+pub fn event_id(date: NaiveDate, index_on_day: usize) -> String {
+    if index_on_day == 0 {
+        date.format("%Y-%m-%d").to_string()
+    } else {
+        format!("{}-{}", date.format("%Y-%m-%d"), index_on_day + 1)
+    }
+}
+
+/// Inverse of `event_id`: the date and index of that game within the day
+pub fn parse_event_id(id: &str) -> Option<(NaiveDate, usize)> {
+    let date = NaiveDate::parse_from_str(id.get(..10)?, "%Y-%m-%d").ok()?;
+    match id.get(10..)? {
+        "" => Some((date, 0)),
+        rest => rest.strip_prefix('-')?.parse::<usize>().ok().filter(|n| *n >= 2).map(|n| (date, n - 1)),
+    }
+}
+
+/// Public URL of a one-game calendar file; None without PUBLIC_BASE_URL
+pub fn event_url(config: &Config, id: &str) -> Option<String> {
+    let base = config.public_base_url.as_ref()?;
+    Some(format!("{}/event/{}.ics", base.trim_end_matches('/'), id))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// One game as a VEVENT. Games without a usable start time become all-day events.
+pub fn render_event(team_name: &str, id: &str, event: &CorrelatedEvent, start: Option<DateTime<Utc>>) -> String {
+    let mut lines = vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}@{}", id, team_name.to_lowercase().replace(' ', "-")),
+        format!("DTSTAMP:{}", Utc::now().format("%Y%m%dT%H%M%SZ")),
+    ];
+    match start {
+        Some(start) => {
+            lines.push(format!("DTSTART:{}", start.format("%Y%m%dT%H%M%SZ")));
+            lines.push(format!("DTEND:{}", (start + Duration::hours(GAME_LENGTH_HOURS)).format("%Y%m%dT%H%M%SZ")));
+        }
+        None => {
+            lines.push(format!("DTSTART;VALUE=DATE:{}", event.event_date.format("%Y%m%d")));
+            lines.push(format!("DTEND;VALUE=DATE:{}", (event.event_date + Duration::days(1)).format("%Y%m%d")));
+        }
+    }
+    let summary = match event.opponent(team_name) {
+        Some(opponent) => format!("{} vs {}", team_name, opponent),
+        None => format!("{} game", team_name),
+    };
+    lines.push(format!("SUMMARY:{}", escape(&summary)));
+    if !event.data.location.is_empty() {
+        lines.push(format!("LOCATION:{}", escape(&event.data.location)));
+    }
+    if let Some(notes) = &event.data.notes {
+        lines.push(format!("DESCRIPTION:{}", escape(notes)));
+    }
+    lines.push("END:VEVENT".to_string());
+    lines.join("\r\n")
+}
+
+/// A calendar holding the given VEVENTs
+pub fn render_calendar(team_name: &str, events: &[String]) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//groupme-bot//schedule//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", escape(&format!("{} games", team_name))),
+    ];
+    lines.extend(events.iter().cloned());
+    lines.push("END:VCALENDAR".to_string());
+    lines.join("\r\n") + "\r\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_id_round_trip() {
+        let date = NaiveDate::from_ymd_opt(2025, 5, 3).unwrap();
+        assert_eq!(event_id(date, 0), "2025-05-03");
+        assert_eq!(event_id(date, 1), "2025-05-03-2");
+        assert_eq!(parse_event_id("2025-05-03"), Some((date, 0)));
+        assert_eq!(parse_event_id("2025-05-03-2"), Some((date, 1)));
+        assert_eq!(parse_event_id("2025-05-03-1"), None);
+        assert_eq!(parse_event_id("nonsense"), None);
+    }
+}
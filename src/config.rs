@@ -5,30 +5,115 @@ use crate::error::{BotError, Result};
 pub struct Config {
     pub groupme_bot_id: String,
     pub groupme_bot_name: String,
+    // Other names the bot answers to: comma-separated names or /regex/ (see mentions.rs)
+    pub bot_aliases: Option<String>,
     pub sheet_id: String,
     pub google_api_key: String,
     pub port: u16,
+    // Webhook routing: primary path ("auto" generates one), extra bot routes, and the public URL to print
+    pub webhook_path: String,
+    pub callback_routes: Option<String>,
+    pub public_base_url: Option<String>,
+    // Bot registration kept in sync with the GroupMe bots API (see the register-bot binary)
+    pub bot_avatar_url: Option<String>,
+    pub sync_bot_registration: bool,
+    pub signup_secret: Option<String>,
+    // Shared secret for POST /announce (None disables the endpoint)
+    pub announce_token: Option<String>,
+    // Outbound JSON webhooks as comma-separated `[event=]url` entries
+    pub outbound_webhooks: Option<String>,
+    // Directory of *.rhai scripts loaded at startup
+    pub plugins_dir: String,
     pub reminder_start_hour: u32,
     pub reminder_end_hour: u32,
+    // Game-day post at REMINDER_START_HOUR with the forecast chart
+    pub morning_briefing: bool,
+    // Game mode ends this long after first pitch, asking for the score every INNING_PROMPT_MINUTES (0 = never)
+    pub game_length_minutes: u64,
+    pub inning_prompt_minutes: u64,
     pub admin_user_id: String,
+    // Let admins/moderators assign volunteers to games that already happened
+    pub allow_moderator_backfill: bool,
+    // Ask before writing volunteer names that don't match anyone known
+    pub validate_volunteer_names: bool,
+    // What to do with names and notes containing a blocked word: "reject", "mask" or "off" (see content_filter.rs)
+    pub content_filter: String,
+    // Optional league standings range in the schedule spreadsheet, e.g. "Standings!A2:D"
+    pub standings_range: Option<String>,
+    // Optional special events range (picture day, fundraisers...), e.g. "Events!A2:E"
+    pub special_events_range: Option<String>,
+    // Minutes between roster checks for join/leave notices to the admin (0 disables)
+    pub membership_poll_minutes: u64,
+    // Weekly moderator digest by DM (None disables)
+    pub digest_day: Option<chrono::Weekday>,
+    pub digest_hour: u32,
+    pub calendar_url: Option<String>,
+    pub conflict_check_minutes: u64,
+    // Where the schedule sanity report goes at boot: "admin" (DM), "group" or "off"
+    pub startup_report: String,
     // GroupMe API access for message management
     pub groupme_access_token: Option<String>,
     pub groupme_group_id: Option<String>,
     // Team customization
     pub team_name: String,
     pub team_emoji: String,
-    pub enable_team_facts: bool,
+    // Where "today" is for relative dates in replies ("in 3 days"); None = the server's zone
+    pub team_timezone: Option<chrono_tz::Tz>,
     pub team_facts_file: Option<String>,
+    // JSON of per-group team name/emoji/facts overrides (see group_profiles.rs)
+    pub group_profiles_file: Option<String>,
+    // Features off unless an admin turns them on (see features.rs); ENABLE_TEAM_FACTS=false adds team_facts
+    pub disabled_features: Vec<String>,
+    // Answer volunteer messages that don't @mention the bot at all (false = mentions only)
+    pub contextual_volunteering: bool,
+    // Misunderstood messages in a row before the bot DMs that user help and goes quiet (0 = never)
+    pub unknown_reply_limit: u32,
+    // How long it stays quiet to that user afterwards
+    pub unknown_cooldown_minutes: u64,
+    // API roots, overridable so a local mock can stand in for GroupMe and Google (see stress_bot)
+    pub groupme_api_url: String,
+    pub sheets_api_url: String,
 }
 
 impl Config {
+    /// Today's date where the team is
+    pub fn today(&self) -> chrono::NaiveDate {
+        self.now().date()
+    }
+
+    /// The current wall-clock time where the team is
+    pub fn now(&self) -> chrono::NaiveDateTime {
+        self.team_time(chrono::Utc::now())
+    }
+
+    /// The wall-clock time an instant shows where the team is
+    pub fn team_time(&self, at: chrono::DateTime<chrono::Utc>) -> chrono::NaiveDateTime {
+        match self.team_timezone {
+            Some(tz) => at.with_timezone(&tz).naive_local(),
+            None => at.with_timezone(&chrono::Local).naive_local(),
+        }
+    }
+
+    /// The instant a team wall-clock time falls on, or None if a clock change skips it
+    pub fn team_instant(&self, at: chrono::NaiveDateTime) -> Option<chrono::DateTime<chrono::Utc>> {
+        use chrono::TimeZone;
+        match self.team_timezone {
+            Some(tz) => tz.from_local_datetime(&at).earliest().map(|t| t.with_timezone(&chrono::Utc)),
+            None => chrono::Local.from_local_datetime(&at).earliest().map(|t| t.with_timezone(&chrono::Utc)),
+        }
+    }
+
     pub fn from_env() -> Result<Self> {
         let groupme_bot_id = env::var("GROUPME_BOT_ID")
             .map_err(|_| BotError::EnvVar("GROUPME_BOT_ID".to_string()))?;
         
         let groupme_bot_name = env::var("GROUPME_BOT_NAME")
             .map_err(|_| BotError::EnvVar("GROUPME_BOT_NAME".to_string()))?;
+        // A rename from chat sticks, so the registration sync doesn't rename the bot back
+        let groupme_bot_name = crate::mentions::saved_bot_name().unwrap_or(groupme_bot_name);
         
+        let bot_aliases = env::var("BOT_ALIASES").ok().filter(|a| !a.trim().is_empty());
+
         let sheet_id = env::var("SHEET_ID")
             .map_err(|_| BotError::EnvVar("SHEET_ID".to_string()))?;
         
@@ -40,6 +125,40 @@ impl Config {
             .parse()
             .map_err(|_| BotError::EnvVar("PORT must be a valid number".to_string()))?;
 
+        let webhook_path = env::var("WEBHOOK_PATH")
+            .ok()
+            .filter(|p| !p.trim().is_empty())
+            .unwrap_or_else(|| "/webhook".to_string());
+
+        let callback_routes = env::var("CALLBACK_ROUTES").ok().filter(|r| !r.trim().is_empty());
+
+        // Fall back to the Traefik deployment hostname when no explicit public URL is given
+        let public_base_url = env::var("PUBLIC_BASE_URL").ok().filter(|u| !u.trim().is_empty()).or_else(|| {
+            match (env::var("BOT_SUBDOMAIN"), env::var("BASE_DOMAIN")) {
+                (Ok(sub), Ok(base)) if !sub.is_empty() && !base.is_empty() => Some(format!("https://{}bot.{}", sub, base)),
+                _ => None,
+            }
+        });
+
+        let bot_avatar_url = env::var("BOT_AVATAR_URL").ok().filter(|u| !u.trim().is_empty());
+
+        let sync_bot_registration = env::var("SYNC_BOT_REGISTRATION")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false);
+
+        // Signs the /signup links; the pages are off without it
+        let signup_secret = env::var("SIGNUP_SECRET").ok().filter(|s| !s.trim().is_empty());
+
+        let announce_token = env::var("ANNOUNCE_TOKEN").ok().filter(|t| !t.trim().is_empty());
+
+        let outbound_webhooks = env::var("OUTBOUND_WEBHOOKS").ok().filter(|w| !w.trim().is_empty());
+
+        let plugins_dir = env::var("PLUGINS_DIR")
+            .ok()
+            .filter(|d| !d.trim().is_empty())
+            .unwrap_or_else(|| "plugins".to_string());
+
         let reminder_start_hour = env::var("REMINDER_START_HOUR")
             .unwrap_or_else(|_| "9".to_string())
             .parse()
@@ -50,6 +169,21 @@ impl Config {
             .parse()
             .map_err(|_| BotError::EnvVar("REMINDER_END_HOUR must be a valid number (0-23)".to_string()))?;
 
+        let morning_briefing = env::var("MORNING_BRIEFING")
+            .unwrap_or_else(|_| "true".to_string())
+            .parse()
+            .unwrap_or(true);
+
+        let game_length_minutes = env::var("GAME_LENGTH_MINUTES")
+            .unwrap_or_else(|_| "120".to_string())
+            .parse()
+            .map_err(|_| BotError::EnvVar("GAME_LENGTH_MINUTES must be a valid number".to_string()))?;
+
+        let inning_prompt_minutes = env::var("INNING_PROMPT_MINUTES")
+            .unwrap_or_else(|_| "20".to_string())
+            .parse()
+            .map_err(|_| BotError::EnvVar("INNING_PROMPT_MINUTES must be a valid number".to_string()))?;
+
         // Basic validation
         if groupme_bot_id.is_empty() {
             return Err(BotError::EnvVar("GROUPME_BOT_ID cannot be empty".to_string()));
@@ -74,6 +208,55 @@ impl Config {
         let admin_user_id = env::var("ADMIN_USER_ID")
             .map_err(|_| BotError::EnvVar("ADMIN_USER_ID".to_string()))?;
 
+        let allow_moderator_backfill = env::var("ALLOW_MODERATOR_BACKFILL")
+            .unwrap_or_else(|_| "true".to_string())
+            .parse()
+            .unwrap_or(true);
+
+        let validate_volunteer_names = env::var("VALIDATE_VOLUNTEER_NAMES")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false);
+
+        let content_filter = env::var("CONTENT_FILTER").unwrap_or_else(|_| "reject".to_string()).trim().to_lowercase();
+        if !["reject", "mask", "off"].contains(&content_filter.as_str()) {
+            return Err(BotError::EnvVar("CONTENT_FILTER must be 'reject', 'mask' or 'off'".to_string()));
+        }
+
+        let standings_range = env::var("STANDINGS_RANGE").ok().filter(|r| !r.trim().is_empty());
+        let special_events_range = env::var("SPECIAL_EVENTS_RANGE").ok().filter(|r| !r.trim().is_empty());
+
+        let membership_poll_minutes = env::var("MEMBERSHIP_POLL_MINUTES")
+            .unwrap_or_else(|_| "15".to_string())
+            .parse()
+            .map_err(|_| BotError::EnvVar("MEMBERSHIP_POLL_MINUTES must be a valid number".to_string()))?;
+
+        let digest_day = match env::var("DIGEST_DAY").unwrap_or_else(|_| "sunday".to_string()).trim().to_lowercase().as_str() {
+            "" | "off" | "none" => None,
+            day => Some(day.parse::<chrono::Weekday>()
+                .map_err(|_| BotError::EnvVar("DIGEST_DAY must be a weekday name or 'off'".to_string()))?),
+        };
+
+        let digest_hour = env::var("DIGEST_HOUR")
+            .unwrap_or_else(|_| "18".to_string())
+            .parse()
+            .ok()
+            .filter(|hour| *hour < 24)
+            .ok_or_else(|| BotError::EnvVar("DIGEST_HOUR must be a valid number (0-23)".to_string()))?;
+
+        // League calendar (iCal or CSV) to cross-check the sheet against (optional)
+        let calendar_url = env::var("CALENDAR_URL").ok().filter(|u| !u.trim().is_empty());
+
+        let conflict_check_minutes = env::var("CONFLICT_CHECK_MINUTES")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse()
+            .map_err(|_| BotError::EnvVar("CONFLICT_CHECK_MINUTES must be a valid number".to_string()))?;
+
+        let startup_report = env::var("STARTUP_REPORT").unwrap_or_else(|_| "admin".to_string()).trim().to_lowercase();
+        if !["admin", "group", "off"].contains(&startup_report.as_str()) {
+            return Err(BotError::EnvVar("STARTUP_REPORT must be 'admin', 'group' or 'off'".to_string()));
+        }
+
         // GroupMe API credentials for message management (optional)
         let groupme_access_token = env::var("GROUPME_ACCESS_TOKEN").ok();
         let groupme_group_id = env::var("GROUPME_GROUP_ID").ok();
@@ -85,28 +268,99 @@ impl Config {
         let team_emoji = env::var("TEAM_EMOJI")
             .unwrap_or_else(|_| "⚾".to_string());
         
+        let team_timezone = match env::var("TEAM_TIMEZONE").ok().filter(|tz| !tz.trim().is_empty()) {
+            Some(tz) => Some(tz.trim().parse::<chrono_tz::Tz>()
+                .map_err(|_| BotError::EnvVar("TEAM_TIMEZONE must be an IANA time zone like America/New_York".to_string()))?),
+            None => None,
+        };
+        let team_facts_file = env::var("TEAM_FACTS_FILE").ok();
+        let group_profiles_file = env::var("GROUP_PROFILES_FILE").ok().filter(|f| !f.trim().is_empty());
+
+        let mut disabled_features: Vec<String> = env::var("FEATURES_DISABLED")
+            .unwrap_or_default()
+            .split(',')
+            .map(|f| f.trim().to_lowercase())
+            .filter(|f| !f.is_empty())
+            .collect();
         let enable_team_facts = env::var("ENABLE_TEAM_FACTS")
             .unwrap_or_else(|_| "true".to_string())
             .parse()
             .unwrap_or(true);
-        
-        let team_facts_file = env::var("TEAM_FACTS_FILE").ok();
+        if !enable_team_facts {
+            disabled_features.push("team_facts".to_string());
+        }
+
+        let contextual_volunteering = env::var("CONTEXTUAL_VOLUNTEERING")
+            .unwrap_or_else(|_| "true".to_string())
+            .parse()
+            .unwrap_or(true);
+
+        let unknown_reply_limit = env::var("UNKNOWN_REPLY_LIMIT")
+            .unwrap_or_else(|_| "3".to_string())
+            .parse()
+            .map_err(|_| BotError::EnvVar("UNKNOWN_REPLY_LIMIT must be a valid number".to_string()))?;
+        let unknown_cooldown_minutes = env::var("UNKNOWN_COOLDOWN_MINUTES")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse()
+            .map_err(|_| BotError::EnvVar("UNKNOWN_COOLDOWN_MINUTES must be a valid number".to_string()))?;
+
+        let groupme_api_url = env::var("GROUPME_API_URL")
+            .ok()
+            .filter(|u| !u.trim().is_empty())
+            .map(|u| u.trim().trim_end_matches('/').to_string())
+            .unwrap_or_else(|| "https://api.groupme.com/v3".to_string());
+        let sheets_api_url = env::var("SHEETS_API_URL")
+            .ok()
+            .filter(|u| !u.trim().is_empty())
+            .map(|u| u.trim().trim_end_matches('/').to_string())
+            .unwrap_or_else(|| "https://sheets.googleapis.com/v4".to_string());
 
         Ok(Config {
             groupme_bot_id,
             groupme_bot_name,
+            bot_aliases,
             sheet_id,
             google_api_key,
             port,
+            webhook_path,
+            callback_routes,
+            public_base_url,
+            bot_avatar_url,
+            sync_bot_registration,
+            signup_secret,
+            announce_token,
+            outbound_webhooks,
+            plugins_dir,
             reminder_start_hour,
             reminder_end_hour,
+            morning_briefing,
+            game_length_minutes,
+            inning_prompt_minutes,
             admin_user_id,
+            allow_moderator_backfill,
+            validate_volunteer_names,
+            content_filter,
+            standings_range,
+            special_events_range,
+            membership_poll_minutes,
+            digest_day,
+            digest_hour,
+            calendar_url,
+            conflict_check_minutes,
+            startup_report,
             groupme_access_token,
             groupme_group_id,
             team_name,
             team_emoji,
-            enable_team_facts,
+            team_timezone,
             team_facts_file,
+            group_profiles_file,
+            disabled_features,
+            contextual_volunteering,
+            unknown_reply_limit,
+            unknown_cooldown_minutes,
+            groupme_api_url,
+            sheets_api_url,
         })
     }
 }
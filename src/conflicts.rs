@@ -0,0 +1,228 @@
+use chrono::NaiveDate;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use tokio::time::{sleep, Duration as TokioDuration};
+use tracing::{info, error};
+
+use crate::config::Config;
+use crate::error::Result;
+use crate::models::{teams_match, CorrelatedEvent};
+use crate::moderators::ModeratorsStore;
+use crate::service::{parse_time_string, BotService};
+
+const NOTIFIED_FILE: &str = "data/schedule_conflicts.json";
+
+/// A game the sheet and the league calendar disagree on
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduleConflict {
+    pub date: NaiveDate,
+    pub opponent: Option<String>,
+    /// (field, sheet value, calendar value)
+    pub differences: Vec<(&'static str, String, String)>,
+}
+
+impl ScheduleConflict {
+    /// "5/3 vs Chaos - time: sheet 10:00 AM, calendar 11:00 AM"
+    pub fn describe(&self) -> String {
+        let mut line = self.date.format("%-m/%-d").to_string();
+        if let Some(opponent) = &self.opponent {
+            line.push_str(&format!(" vs {}", opponent));
+        }
+        let diffs: Vec<String> = self.differences
+            .iter()
+            .map(|(field, sheet, calendar)| format!("{}: sheet {}, calendar {}", field, sheet, calendar))
+            .collect();
+        format!("{} - {}", line, diffs.join("; "))
+    }
+}
+
+/// "10am" and "10:00 AM - 11:30 AM" start at the same time
+pub(crate) fn same_time(a: &str, b: &str) -> bool {
+    let start = |s: &str| {
+        let s = s.split('-').next().unwrap_or_default().trim().to_string();
+        parse_time_string(&s).map(|t| t.to_string()).unwrap_or_else(|| s.to_lowercase())
+    };
+    start(a) == start(b)
+}
+
+/// "Hall Field" and "hall field #2" are the same place; "Hall" and "Rec Park" aren't
+fn same_location(a: &str, b: &str) -> bool {
+    let normalize = |s: &str| -> String {
+        s.to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect()
+    };
+    let (a, b) = (normalize(a), normalize(b));
+    a.contains(&b) || b.contains(&a)
+}
+
+/// Pair calendar games with sheet games on the same date (by opponent when a day has more than
+/// one) and report time or location mismatches. Blank values on either side aren't conflicts,
+/// and games only one source knows about are left alone.
+pub fn find_conflicts(sheet: &[CorrelatedEvent], calendar: &[CorrelatedEvent], my_team: &str) -> Vec<ScheduleConflict> {
+    let mut conflicts = Vec::new();
+
+    for cal in calendar {
+        let same_day: Vec<&CorrelatedEvent> = sheet.iter().filter(|e| e.event_date == cal.event_date).collect();
+        let cal_on_day = calendar.iter().filter(|e| e.event_date == cal.event_date).count();
+        let opponent = cal.opponent(my_team);
+
+        let paired = if same_day.len() == 1 && cal_on_day == 1 {
+            Some(same_day[0])
+        } else {
+            opponent.as_ref().and_then(|opp| {
+                same_day.iter().copied().find(|e| e.opponent(my_team).is_some_and(|o| teams_match(&o, opp)))
+            })
+        };
+        let Some(sheet_event) = paired else { continue };
+
+        let mut differences = Vec::new();
+        let (sheet_time, cal_time) = (sheet_event.data.time.trim(), cal.data.time.trim());
+        if !sheet_time.is_empty() && !cal_time.is_empty() && !same_time(sheet_time, cal_time) {
+            differences.push(("time", sheet_time.to_string(), cal_time.to_string()));
+        }
+        let (sheet_loc, cal_loc) = (sheet_event.data.location.trim(), cal.data.location.trim());
+        if !sheet_loc.is_empty() && !cal_loc.is_empty() && !same_location(sheet_loc, cal_loc) {
+            differences.push(("location", sheet_loc.to_string(), cal_loc.to_string()));
+        }
+
+        if !differences.is_empty() {
+            conflicts.push(ScheduleConflict {
+                date: cal.event_date,
+                opponent: opponent.or_else(|| sheet_event.opponent(my_team)),
+                differences,
+            });
+        }
+    }
+
+    conflicts
+}
+
+/// Periodically compares the sheet with CALENDAR_URL and DMs moderators about new mismatches
+pub struct ConflictMonitor {
+    bot_service: BotService,
+    moderators_store: ModeratorsStore,
+    config: Config,
+}
+
+impl ConflictMonitor {
+    pub fn new(config: Config, bot_service: BotService, moderators_store: ModeratorsStore) -> Self {
+        Self {
+            bot_service,
+            moderators_store,
+            config,
+        }
+    }
+
+    /// Start checking in the background; needs CALENDAR_URL, a check interval and GROUPME_ACCESS_TOKEN for DMs
+    pub fn start(self: Arc<Self>) {
+        if self.config.calendar_url.is_none()
+            || self.config.conflict_check_minutes == 0
+            || self.config.groupme_access_token.is_none()
+        {
+            info!("Schedule conflict monitor disabled");
+            return;
+        }
+
+        tokio::spawn(async move {
+            info!("Schedule conflict monitor started (every {} minutes)", self.config.conflict_check_minutes);
+
+            loop {
+                if let Err(e) = self.check().await {
+                    error!("Error comparing schedule sources: {}", e);
+                }
+                sleep(TokioDuration::from_secs(self.config.conflict_check_minutes * 60)).await;
+            }
+        });
+    }
+
+    fn load_notified() -> BTreeSet<String> {
+        std::fs::read_to_string(NOTIFIED_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    fn save_notified(notified: &BTreeSet<String>) {
+        if let Err(e) = std::fs::create_dir_all("data") { error!("Failed to create data dir: {}", e); }
+        if let Err(e) = std::fs::write(NOTIFIED_FILE, serde_json::to_string_pretty(notified).unwrap_or_default()) {
+            error!("Failed to save notified schedule conflicts: {}", e);
+        }
+    }
+
+    /// Alert once per distinct conflict; a conflict that's fixed and comes back is alerted again
+    async fn check(&self) -> Result<()> {
+        let current: BTreeSet<String> = self.bot_service.schedule_conflicts().await?
+            .iter()
+            .map(|c| c.describe())
+            .collect();
+        let notified = Self::load_notified();
+
+        let new: Vec<&String> = current.difference(&notified).collect();
+        if !new.is_empty() {
+            let mut message = "📅 The sheet and the league calendar disagree:".to_string();
+            for line in &new {
+                message.push_str(&format!("\n• {}", line));
+            }
+            message.push_str("\nFix whichever one is wrong.");
+            self.bot_service.notify_moderators(&self.moderators_store, &message, true).await;
+        }
+
+        if current != notified {
+            Self::save_notified(&current);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EventData;
+
+    fn event(date: NaiveDate, time: &str, location: &str, summary: &str) -> CorrelatedEvent {
+        CorrelatedEvent {
+            event_date: date,
+            event_summary: summary.to_string(),
+            data: EventData::new(date, time.to_string(), location.to_string(), String::new(),
+                String::new(), String::new(), String::new(), String::new(), String::new()),
+        }
+    }
+
+    #[test]
+    fn test_find_conflicts() {
+        let day = NaiveDate::from_ymd_opt(2025, 5, 3).unwrap();
+        let other = NaiveDate::from_ymd_opt(2025, 5, 10).unwrap();
+        let sheet = vec![
+            event(day, "10:00 AM", "Hall Field", ""),
+            event(other, "9am", "Rec Park", ""),
+        ];
+        let calendar = vec![
+            event(day, "11:00 AM", "hall field", "Pirates vs Chaos"),
+            event(other, "9:00 AM", "Rec Park #2", "Pirates vs Storm"),
+        ];
+
+        let conflicts = find_conflicts(&sheet, &calendar, "Pirates");
+        assert_eq!(conflicts, vec![ScheduleConflict {
+            date: day,
+            opponent: Some("Chaos".to_string()),
+            differences: vec![("time", "10:00 AM".to_string(), "11:00 AM".to_string())],
+        }]);
+        assert_eq!(conflicts[0].describe(), "5/3 vs Chaos - time: sheet 10:00 AM, calendar 11:00 AM");
+    }
+
+    #[test]
+    fn test_utc_calendar_times_are_not_conflicts() {
+        let day = NaiveDate::from_ymd_opt(2025, 5, 3).unwrap();
+        let sheet = vec![event(day, "6:00 PM", "Hall Field", "")];
+        // 23:00 UTC is the sheet's 6 PM for a team in Chicago
+        let ics = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nDTSTART:20250503T230000Z\r\nSUMMARY:Pirates vs Chaos\r\nLOCATION:Hall Field\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+        let calendar: Vec<CorrelatedEvent> = crate::schedule_import::parse_ics(ics, |utc| {
+            utc.with_timezone(&chrono_tz::America::Chicago).naive_local()
+        })
+        .iter()
+        .map(|game| game.to_event())
+        .collect();
+
+        assert_eq!(calendar.len(), 1);
+        assert_eq!(find_conflicts(&sheet, &calendar, "Pirates"), Vec::new());
+    }
+}
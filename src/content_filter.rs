@@ -0,0 +1,184 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+const BLOCKED_WORDS_FILE: &str = "data/blocked_words.json";
+
+/// What the group sees when a name or note is turned away
+pub const FILTERED_REPLY: &str = "🙏 Let's keep it family-friendly, that one goes on the shared sheet. Please try again with different wording.";
+
+/// Word endings that still count as the blocked word ("jerk" catches "jerks")
+const SUFFIXES: [&str; 6] = ["s", "es", "ed", "er", "ers", "ing"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FilterMode {
+    Reject,
+    Mask,
+    Off,
+}
+
+/// Lowercase, with the usual digit-for-letter swaps undone ("j3rk" -> "jerk")
+fn normalize(word: &str) -> String {
+    word.chars()
+        .map(|c| match c {
+            '0' => 'o',
+            '1' => 'i',
+            '3' => 'e',
+            '4' => 'a',
+            '5' => 's',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect()
+}
+
+/// Byte ranges of the words in `text`
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (c.is_alphanumeric() || c == '\'', start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                spans.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        spans.push((s, text.len()));
+    }
+    spans
+}
+
+fn word_matches(word: &str, blocked: &str) -> bool {
+    word == blocked || word.strip_prefix(blocked).is_some_and(|rest| SUFFIXES.contains(&rest))
+}
+
+/// Byte ranges in `text` covered by a blocked word or phrase. Whole words only, so a blocked
+/// word inside an innocent one (a surname, say) is left alone.
+fn find_blocked(text: &str, blocked: &BTreeSet<String>) -> Vec<(usize, usize)> {
+    let spans = word_spans(text);
+    let words: Vec<String> = spans.iter().map(|&(s, e)| normalize(&text[s..e])).collect();
+    let mut found = Vec::new();
+    for entry in blocked {
+        let parts: Vec<&str> = entry.split_whitespace().collect();
+        if parts.is_empty() || parts.len() > words.len() {
+            continue;
+        }
+        for i in 0..=words.len() - parts.len() {
+            let hit = parts.iter().enumerate().all(|(j, part)| {
+                let word = &words[i + j];
+                if j + 1 == parts.len() { word_matches(word, part) } else { word == part }
+            });
+            if hit {
+                found.push((spans[i].0, spans[i + parts.len() - 1].1));
+            }
+        }
+    }
+    found
+}
+
+/// "jerk" -> "j***"
+fn mask(text: &str, ranges: &[(usize, usize)]) -> String {
+    text.char_indices()
+        .map(|(i, c)| {
+            let hidden = ranges.iter().any(|&(s, e)| i > s && i < e) && c.is_alphanumeric();
+            if hidden { '*' } else { c }
+        })
+        .collect()
+}
+
+/// Moderator-maintained word list, checked before volunteer names and notes are written to the
+/// sheet and echoed back. CONTENT_FILTER picks whether a hit is rejected or masked.
+/// The list is kept in data/blocked_words.json.
+#[derive(Clone)]
+pub struct ContentFilter {
+    mode: FilterMode,
+    words: Arc<RwLock<BTreeSet<String>>>,
+}
+
+impl ContentFilter {
+    /// `mode` is CONTENT_FILTER: "reject", "mask" or "off"
+    pub fn new(mode: &str) -> Self {
+        let mode = match mode {
+            "mask" => FilterMode::Mask,
+            "off" => FilterMode::Off,
+            _ => FilterMode::Reject,
+        };
+        let words = std::fs::read_to_string(BLOCKED_WORDS_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self { mode, words: Arc::new(RwLock::new(words)) }
+    }
+
+    fn save(words: &BTreeSet<String>) {
+        if let Err(e) = std::fs::create_dir_all("data") { tracing::error!("Failed to create data dir: {}", e); }
+        if let Err(e) = std::fs::write(BLOCKED_WORDS_FILE, serde_json::to_string_pretty(words).unwrap_or_default()) {
+            tracing::error!("Failed to save blocked words: {}", e);
+        }
+    }
+
+    /// The text to use, masked if need be, or None when it should be turned away
+    pub async fn screen(&self, text: &str) -> Option<String> {
+        if self.mode == FilterMode::Off {
+            return Some(text.to_string());
+        }
+        let found = find_blocked(text, &*self.words.read().await);
+        match (found.is_empty(), self.mode) {
+            (true, _) => Some(text.to_string()),
+            (false, FilterMode::Mask) => Some(mask(text, &found)),
+            (false, _) => None,
+        }
+    }
+
+    /// False if it was already on the list
+    pub async fn block(&self, word: &str) -> bool {
+        let word = normalize(word.trim());
+        let mut words = self.words.write().await;
+        let added = words.insert(word);
+        if added {
+            Self::save(&words);
+        }
+        added
+    }
+
+    /// False if it wasn't on the list
+    pub async fn unblock(&self, word: &str) -> bool {
+        let mut words = self.words.write().await;
+        let removed = words.remove(&normalize(word.trim()));
+        if removed {
+            Self::save(&words);
+        }
+        removed
+    }
+
+    pub async fn list(&self) -> Vec<String> {
+        self.words.read().await.iter().cloned().collect()
+    }
+
+    pub fn is_off(&self) -> bool {
+        self.mode == FilterMode::Off
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_and_mask_blocked_words() {
+        let blocked: BTreeSet<String> = ["jerk", "dirt bag"].iter().map(|w| w.to_string()).collect();
+
+        let text = "Coach is a J3RK";
+        let found = find_blocked(text, &blocked);
+        assert_eq!(found, vec![(11, 15)]);
+        assert_eq!(mask(text, &found), "Coach is a J***");
+
+        assert_eq!(mask("jerks and dirt bags", &find_blocked("jerks and dirt bags", &blocked)), "j**** and d*** ****");
+        // Whole words only
+        assert!(find_blocked("Jerkins family", &blocked).is_empty());
+        assert!(find_blocked("dirt road", &blocked).is_empty());
+    }
+}
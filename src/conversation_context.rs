@@ -3,6 +3,24 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use chrono::{DateTime, Local, Duration};
 
+use crate::models::BotCommand;
+
+/// How long a destructive command waits for the user to reply "confirm"
+pub const CONFIRMATION_WINDOW_SECONDS: i64 = 60;
+
+/// A destructive command parked until the user confirms it
+#[derive(Clone, Debug)]
+pub struct PendingAction {
+    pub command: BotCommand,
+    pub expires_at: DateTime<Local>,
+}
+
+impl PendingAction {
+    pub fn is_expired(&self) -> bool {
+        Local::now() > self.expires_at
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct ConversationContext {
     pub user_id: String,
@@ -11,6 +29,7 @@ pub struct ConversationContext {
     pub last_activity: DateTime<Local>,
     pub volunteer_intent: bool,
     pub mentioned_bot: bool,
+    pub pending_action: Option<PendingAction>,
 }
 
 pub struct ConversationContextStore {
@@ -26,10 +45,21 @@ impl ConversationContextStore {
         }
     }
 
+    /// Start a session or refresh an existing one; a parked "confirm" action survives the update
     pub async fn create_or_update_context(&self, user_id: String, user_name: String, volunteer_intent: bool, mentioned_bot: bool) {
         let now = Local::now();
         let mut contexts = self.contexts.write().await;
-        contexts.insert(user_id.clone(), ConversationContext { user_id, user_name, session_start: now, last_activity: now, volunteer_intent, mentioned_bot });
+        contexts
+            .entry(user_id.clone())
+            .and_modify(|context| {
+                context.user_name = user_name.clone();
+                context.last_activity = now;
+                context.volunteer_intent = volunteer_intent;
+                context.mentioned_bot = mentioned_bot;
+            })
+            .or_insert_with(|| ConversationContext {
+                user_id, user_name, session_start: now, last_activity: now, volunteer_intent, mentioned_bot, pending_action: None,
+            });
     }
 
     pub async fn get_active_context(&self, user_id: &str) -> Option<ConversationContext> {
@@ -43,6 +73,25 @@ impl ConversationContextStore {
         if let Some(context) = contexts.get_mut(user_id) { context.last_activity = Local::now(); }
     }
 
+    /// Park a command until the user replies "confirm", replacing any earlier pending action
+    pub async fn set_pending_action(&self, user_id: String, user_name: String, command: BotCommand) {
+        let now = Local::now();
+        let pending = PendingAction { command, expires_at: now + Duration::seconds(CONFIRMATION_WINDOW_SECONDS) };
+        let mut contexts = self.contexts.write().await;
+        let context = contexts.entry(user_id.clone()).or_insert_with(|| ConversationContext {
+            user_id, user_name, session_start: now, last_activity: now, volunteer_intent: false, mentioned_bot: true, pending_action: None,
+        });
+        context.last_activity = now;
+        context.pending_action = Some(pending);
+    }
+
+    /// Remove and return the user's pending action, expired or not
+    pub async fn take_pending_action(&self, user_id: &str) -> Option<PendingAction> {
+        self.cleanup_expired_contexts().await;
+        let mut contexts = self.contexts.write().await;
+        contexts.get_mut(user_id).and_then(|context| context.pending_action.take())
+    }
+
     async fn cleanup_expired_contexts(&self) {
         let now = Local::now();
         let timeout = Duration::minutes(self.session_timeout_minutes);
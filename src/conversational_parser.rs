@@ -1,16 +1,55 @@
-use chrono::{NaiveDate, Utc, Datelike, Duration};
+use chrono::{NaiveDate, NaiveTime, Utc, Datelike, Duration};
+use chrono_tz::Tz;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
+use std::sync::{Arc, RwLock};
+
+use crate::mentions::{BotName, MentionMatcher};
+use crate::synonyms;
+
+/// First words that make a message a question: "who has snacks saturday" asks, "Jane has snacks saturday" signs up
+const QUESTION_STARTS: [&str; 21] = [
+    "who", "who's", "whos", "what", "what's", "whats", "when", "when's", "where", "where's", "which", "how",
+    "is", "are", "does", "do", "did", "has", "anyone", "anybody", "any",
+];
+/// Verbs that commit someone to a role ("Hobbs has snacks", "I'll take scoreboard")
+const AFFIRMATIVE_VERBS: [&str; 15] = [
+    "have", "has", "got", "take", "taking", "bring", "bringing", "do", "doing", "cover", "covering", "handle", "handling", "run", "running",
+];
+/// Phrases that mean someone is volunteering even before they've named a role
+const VOLUNTEER_PHRASES: [&str; 18] = [
+    "i've got", "i have", "i'll bring", "i can do", "i can bring",
+    "put me down", "sign me up", "i'll do", "i'll take",
+    "count me in", "i got", "i'm doing", "volunteer", "i can",
+    "have got", "has got", "will bring", "will do",
+];
+/// Words that turn a sentence with a role into anything but a sign-up ("I can't do snacks", "nobody has scoreboard");
+/// any word ending in "n't" counts too
+const NOT_VOLUNTEERING_WORDS: [&str; 5] = ["not", "cannot", "nobody", "anyone", "anybody"];
+/// Asking for help or thanking someone for a spot they already did
+const NOT_VOLUNTEERING_PHRASES: [&str; 7] = [
+    "no one", "need someone", "needs someone", "need somebody", "needs somebody", "thanks for", "thank you for",
+];
 
 /// Conversational parser that understands natural language
 pub struct ConversationalParser {
-    bot_name: String,
+    mentions: MentionMatcher,
+    today: Today,
+}
+
+/// Where the parser's "today" comes from
+#[derive(Debug, Clone, Copy)]
+enum Today {
+    /// The current date in the team's timezone, or the server's when it isn't set
+    In(Option<Tz>),
+    /// A set date, so tests don't depend on the day they run
+    Fixed(NaiveDate),
 }
 
 #[derive(Debug, Clone)]
 pub enum ParsedIntent {
     Volunteer { roles: Vec<String>, date: Option<NaiveDate>, person: Option<String>, relative_game: Option<usize> },
-    GameQuery { category: Option<String>, count: Option<usize>, relative: Option<String> },
+    GameQuery { categories: Vec<String>, count: Option<usize>, relative: Option<String>, date: Option<NaiveDate> },
     VolunteerQuery { date: Option<NaiveDate> },
     TeamSpirit,
     Help,
@@ -21,38 +60,187 @@ pub enum ParsedIntent {
     RemoveModerator { user_id: String },
     ListModerators,
     ListBotMessages { count: usize },
+    WhoIs { user_id: String },
+    SetNote { date: Option<NaiveDate>, note: String },
+    ClearNote { date: Option<NaiveDate> },
+    CancelGame { date: Option<NaiveDate>, reason: Option<String> },
+    RequestBringItems { event: String, items: Vec<String> },
+    BringItem { event: Option<String>, item: String, person: Option<String> },
+    BringListStatus { event: Option<String> },
+    ClearBringList { event: String },
+    RecordResult { date: Option<NaiveDate>, opponent: Option<String>, our_score: u32, their_score: u32 },
+    HeadToHead { opponent: String },
+    LogStats { date: Option<NaiveDate>, player: String, counts: crate::stats::StatCounts },
+    PlayerStats { player: String },
+    TeamStats,
+    RenameBot { name: String },
+    SetAvatar { url: String },
+    ClearAssignments { person: String },
+    ClearVolunteers { date: NaiveDate },
+    SetGameMode { on: bool },
+    ScheduleMessage { date: Option<NaiveDate>, time: NaiveTime, text: String },
+    ListScheduledMessages,
+    CancelScheduledMessage { id: u32 },
+    RecordAbsence { who: String, start: NaiveDate, end: NaiveDate },
+    ListAbsences,
+    RemoveAbsence { id: u32 },
+    BlockWord { word: String },
+    UnblockWord { word: String },
+    ListBlockedWords,
+    VolunteerAnalytics,
+    Version,
+    AddSpecialEvent { title: String, date: NaiveDate, time: Option<String>, location: Option<String> },
+    ListSpecialEvents,
+    RemoveSpecialEvent { id: u32 },
+    SetEventReminder { kind: String, lead_hours: u32, text: String },
+    ShowEventReminders { kind: String },
+    ResetEventReminders { kind: String },
+    GamePhotos { date: Option<NaiveDate> },
+    CopyVolunteers { from: NaiveDate, to: NaiveDate },
+    ModeratorDigest,
+    ImportSchedule { url: String },
+    SetVenueTimezone { venue: String, timezone: Option<String> },
+    ListVenues,
+    StartTournament { name: String },
+    SetBracketGame {
+        number: u32,
+        date: Option<NaiveDate>,
+        time: String,
+        location: String,
+        opponent: Option<String>,
+        condition: Option<(u32, crate::tournament::Outcome)>,
+    },
+    BracketResult { number: u32, outcome: crate::tournament::Outcome },
+    ShowBracket,
+    ClearTournament,
+    SetTournamentDates { start: Option<NaiveDate>, end: Option<NaiveDate> },
+    SetTournamentLogistics { label: String, value: Option<String> },
+    TournamentInfo,
+    SignupLink { date: Option<NaiveDate> },
+    QrCode { target: crate::models::QrTarget },
+    AddToCalendar { date: Option<NaiveDate> },
+    Weather { date: Option<NaiveDate> },
+    SetFeature { feature: crate::features::Feature, enabled: bool, all_groups: bool },
+    ListFeatures,
     ConversationalResponse { message: String },
 }
 
+/// First clock time in `text` ("6 pm", "6:30pm", "18:00") and the text without it.
+/// Bare numbers don't count, so dates and scores are left alone.
+fn find_clock_time(text: &str) -> Option<(NaiveTime, String)> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    for (idx, word) in words.iter().enumerate() {
+        if !word.starts_with(|c: char| c.is_ascii_digit()) || word.contains('/') {
+            continue;
+        }
+        let (candidate, used) = match words.get(idx + 1) {
+            Some(next) if matches!(*next, "am" | "pm") => (format!("{} {}", word, next), 2),
+            _ => (word.to_string(), 1),
+        };
+        let looks_like_time = candidate.contains(':') || candidate.ends_with("am") || candidate.ends_with("pm");
+        if let Some(parsed) = looks_like_time.then(|| crate::service::parse_time_string(&candidate)).flatten() {
+            let rest: Vec<&str> = words[..idx].iter().chain(&words[idx + used..]).copied().collect();
+            return Some((parsed, rest.join(" ")));
+        }
+    }
+    None
+}
+
 impl ConversationalParser {
     pub fn new(bot_name: String) -> Self {
-        Self { bot_name }
+        Self::with_shared_name(Arc::new(RwLock::new(BotName::new(bot_name))))
     }
 
-    /// Parse a message and extract intent
-    pub fn parse_message(&self, text: &str, sender_name: Option<&str>, attachments: &[crate::models::Attachment]) -> Option<ParsedIntent> {
-        let text = text.trim();
-        let text_lower = text.to_lowercase();
-        
-        // Check if message is directed at the bot
-        let bot_mention = format!("@{}", self.bot_name).to_lowercase();
-        if !text_lower.contains(&bot_mention) {
-            return None;
+    /// Follow a bot name that can change at runtime ("rename to ...")
+    pub fn with_shared_name(bot_name: Arc<RwLock<BotName>>) -> Self {
+        Self::with_mentions(MentionMatcher::new(bot_name))
+    }
+
+    /// Recognize the bot under aliases too (BOT_ALIASES)
+    pub fn with_mentions(mentions: MentionMatcher) -> Self {
+        Self { mentions, today: Today::In(None) }
+    }
+
+    /// Swap in a matcher that knows more names, keeping the rest of the setup
+    pub fn with_mention_matcher(mut self, mentions: MentionMatcher) -> Self {
+        self.mentions = mentions;
+        self
+    }
+
+    /// Read "today", "saturday" and "3/14" as the team would (TEAM_TIMEZONE)
+    pub fn with_timezone(mut self, timezone: Option<Tz>) -> Self {
+        self.today = Today::In(timezone);
+        self
+    }
+
+    /// Resolve dates as if it were `today`
+    pub fn as_of(mut self, today: NaiveDate) -> Self {
+        self.today = Today::Fixed(today);
+        self
+    }
+
+    fn today(&self) -> NaiveDate {
+        match self.today {
+            Today::In(Some(tz)) => Utc::now().with_timezone(&tz).date_naive(),
+            Today::In(None) => chrono::Local::now().date_naive(),
+            Today::Fixed(date) => date,
         }
+    }
 
-        // Remove bot mention for easier parsing
-        let cleaned_text = text_lower.replace(&bot_mention, "").trim().to_string();
+    /// Parse a message and extract intent
+    pub fn parse_message(&self, text: &str, sender_name: Option<&str>, attachments: &[crate::models::Attachment]) -> Option<ParsedIntent> {
+        // Check if message is directed at the bot, and remove the mention for easier parsing
+        let cleaned = self.mentions.strip(text.trim())?;
+        let cleaned_text = cleaned.to_lowercase();
         
         if cleaned_text.is_empty() {
             return Some(ParsedIntent::Help);
         }
 
         // Detect intent based on keywords and patterns
-        let intent = self.detect_intent(&cleaned_text, text, sender_name, attachments);
+        let intent = self.detect_intent(&cleaned_text, &cleaned, sender_name, attachments);
         Some(intent)
     }
 
+    /// A sign-up in a message that doesn't mention the bot ("I'll do snacks saturday");
+    /// nothing else in an unaddressed message is meant for the bot
+    pub fn parse_volunteer_statement(&self, text: &str, sender_name: Option<&str>) -> Option<ParsedIntent> {
+        let text = text.trim();
+        let text_lower = text.to_lowercase();
+        self.is_volunteer_statement(&text_lower)
+            .then(|| self.parse_volunteer_intent(&text_lower, text, sender_name))
+    }
+
     fn detect_intent(&self, text_lower: &str, original_text: &str, sender_name: Option<&str>, attachments: &[crate::models::Attachment]) -> ParsedIntent {
+        // Game notes first - the note body is free text that would trip every other intent
+        if let Some(intent) = self.parse_game_note(text_lower, original_text) {
+            return intent;
+        }
+        if let Some(intent) = self.parse_scheduled_message(text_lower, original_text) {
+            return intent;
+        }
+        if let Some(intent) = self.parse_batch_volunteers(text_lower) {
+            return intent;
+        }
+        if let Some(intent) = self.parse_game_mode(text_lower) {
+            return intent;
+        }
+        if let Some(intent) = self.parse_absence(text_lower, original_text, sender_name) {
+            return intent;
+        }
+        if let Some(intent) = self.parse_word_filter(text_lower) {
+            return intent;
+        }
+        if let Some(intent) = self.parse_special_event(text_lower, original_text) {
+            return intent;
+        }
+        if ["volunteer analytics", "volunteer metrics", "volunteer stats", "fill rate"].iter().any(|kw| text_lower.starts_with(kw)) {
+            return ParsedIntent::VolunteerAnalytics;
+        }
+        if matches!(text_lower.trim_end_matches(['?', '!', '.']), "version" | "what's new" | "whats new" | "changelog" | "what version") {
+            return ParsedIntent::Version;
+        }
+
         // Volunteer intent detection
         // Admin command detection (check first, before volunteer)
         if text_lower.contains("remove") && text_lower.contains("from") {
@@ -70,6 +258,83 @@ impl ConversationalParser {
         if text_lower.contains("list moderator") || text_lower.contains("show moderator") {
             return ParsedIntent::ListModerators;
         }
+        if let Some(intent) = self.parse_who_is(text_lower, attachments) {
+            return intent;
+        }
+        if let Some(intent) = self.parse_bot_profile(text_lower, original_text) {
+            return intent;
+        }
+        if let Some(intent) = self.parse_clear_assignments(text_lower, original_text) {
+            return intent;
+        }
+        if let Some(intent) = self.parse_venue_timezone(text_lower, original_text) {
+            return intent;
+        }
+        if let Some(intent) = self.parse_feature_toggle(text_lower) {
+            return intent;
+        }
+        if let Some(intent) = self.parse_tournament(text_lower, original_text) {
+            return intent;
+        }
+        if text_lower == "venues" || text_lower.starts_with("list venues") || text_lower.starts_with("venue time zones") {
+            return ParsedIntent::ListVenues;
+        }
+        if text_lower.starts_with("import schedule") || text_lower.starts_with("import games") {
+            return match original_text.split_whitespace().find(|w| w.starts_with("http") || w.starts_with("webcal")) {
+                Some(url) => ParsedIntent::ImportSchedule { url: url.to_string() },
+                None => ParsedIntent::ConversationalResponse {
+                    message: "📥 Send the link too, e.g. \"import schedule https://league.example.com/schedule.csv\"".to_string(),
+                },
+            };
+        }
+        if ["to my calendar", "to calendar", "calendar invite", "calendar file"].iter().any(|kw| text_lower.contains(kw)) {
+            return ParsedIntent::AddToCalendar { date: self.extract_date(text_lower) };
+        }
+        if text_lower.split_whitespace().any(|w| w == "qr") {
+            let target = if ["signup", "sign up", "volunteer"].iter().any(|kw| text_lower.contains(kw)) {
+                crate::models::QrTarget::Signup(self.extract_date(text_lower))
+            } else {
+                crate::models::QrTarget::Calendar
+            };
+            return ParsedIntent::QrCode { target };
+        }
+        if ["signup link", "sign up link", "sign-up link", "signup page"].iter().any(|kw| text_lower.contains(kw)) {
+            return ParsedIntent::SignupLink { date: self.extract_date(text_lower) };
+        }
+        if text_lower == "digest" || text_lower.contains("moderator digest") || text_lower.contains("mod digest") {
+            return ParsedIntent::ModeratorDigest;
+        }
+
+        // Party bring-lists (before volunteer parsing, which also listens for "i'll bring")
+        if let Some(intent) = self.parse_bring_list(text_lower, original_text, sender_name) {
+            return intent;
+        }
+
+        // Player stats ("stat jake 2-3, 1 rbi") - "2-3" would otherwise read as a date or a score
+        if let Some(intent) = self.parse_player_stats(text_lower, original_text) {
+            return intent;
+        }
+
+        // Final scores ("we won 7-3 vs chaos") - the score would otherwise read as a date
+        if let Some(intent) = self.parse_game_result(text_lower, original_text) {
+            return intent;
+        }
+
+        // "history vs chaos" - before game queries, which would grab "how have we done"
+        if let Some(intent) = self.parse_head_to_head(text_lower, original_text) {
+            return intent;
+        }
+
+        // "I'll bring snacks for next game" signs up even though it mentions the next game;
+        // questions about roles go on to the game and volunteer queries below
+        if self.is_volunteer_statement(text_lower) {
+            return self.parse_volunteer_intent(text_lower, original_text, sender_name);
+        }
+
+        // "what's the weather like saturday" - before game queries so the day isn't lost
+        if synonyms::is_weather_question(text_lower) {
+            return self.parse_weather_intent(text_lower);
+        }
 
         // Message management commands
         if text_lower.contains("list") && (text_lower.contains("message") || text_lower.contains("bot message")) {
@@ -91,6 +356,13 @@ impl ConversationalParser {
             return self.parse_volunteer_intent(text_lower, original_text, sender_name);
         }
 
+        // A bare role ("@bot snacks saturday") is asking who has it, never signing the sender up
+        let roles = synonyms::roles_in(text_lower);
+        if !roles.is_empty() {
+            let categories = roles.into_iter().map(String::from).collect();
+            return ParsedIntent::GameQuery { categories, count: None, relative: None, date: self.extract_date(text_lower) };
+        }
+
         // Team spirit intent detection
         if self.is_team_spirit_intent(text_lower) {
             return ParsedIntent::TeamSpirit;
@@ -111,21 +383,39 @@ impl ConversationalParser {
         ParsedIntent::Unknown
     }
 
-    // Volunteer intent detection
+    /// Whether the message asks something rather than stating it, ignoring @mentions
+    fn is_question(&self, text: &str) -> bool {
+        let first_word = text
+            .split_whitespace()
+            .find(|w| !w.starts_with('@'))
+            .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric() && c != '\''))
+            .unwrap_or_default();
+        text.contains('?') || QUESTION_STARTS.contains(&first_word)
+    }
+
+    /// Turning a spot down, asking for help or thanking someone: "I don't have snacks this week",
+    /// "we still need someone to run the stream", "thanks for bringing snacks"
+    fn is_declining(&self, text: &str) -> bool {
+        let text = text.replace('’', "'");
+        NOT_VOLUNTEERING_PHRASES.iter().any(|phrase| text.contains(phrase))
+            || text
+                .split(|c: char| !c.is_alphanumeric() && c != '\'')
+                .any(|word| word.ends_with("n't") || NOT_VOLUNTEERING_WORDS.contains(&word))
+    }
+
+    fn has_affirmative_verb(&self, text: &str) -> bool {
+        VOLUNTEER_PHRASES.iter().any(|phrase| text.contains(phrase))
+            || text.split(|c: char| !c.is_alphanumeric()).any(|word| AFFIRMATIVE_VERBS.contains(&word))
+    }
+
+    /// A statement that names a role and commits to it: "Hobbs have snacks for the next game"
+    fn is_volunteer_statement(&self, text: &str) -> bool {
+        !self.is_question(text) && !self.is_declining(text) && self.has_affirmative_verb(text) && !synonyms::roles_in(text).is_empty()
+    }
+
+    // Volunteer intent detection: a statement with a volunteering phrase, even without a role yet
     fn is_volunteer_intent(&self, text: &str) -> bool {
-        let volunteer_keywords = [
-            "i've got", "i have", "i'll bring", "i can do", "i can bring",
-            "put me down", "sign me up", "i'll do", "i'll take",
-            "count me in", "i got", "i'm doing", "volunteer", "i can",
-            "have got", "has got", "will bring", "will do"
-        ];
-        
-        let role_keywords = ["snacks", "snack", "livestream", "stream", "scoreboard", "score", "pitchcount", "pitch count", "gamechanger", "game changer"];
-        
-        let has_volunteer_keyword = volunteer_keywords.iter().any(|kw| text.contains(kw));
-        let has_role_keyword = role_keywords.iter().any(|kw| text.contains(kw));
-        
-        has_volunteer_keyword || has_role_keyword
+        !self.is_question(text) && !self.is_declining(text) && VOLUNTEER_PHRASES.iter().any(|phrase| text.contains(phrase))
     }
 
     fn parse_volunteer_intent(&self, text_lower: &str, original_text: &str, sender_name: Option<&str>) -> ParsedIntent {
@@ -141,24 +431,7 @@ impl ConversationalParser {
     }
 
     fn extract_volunteer_roless(&self, text: &str) -> Vec<String> {
-        let role_mappings = [
-            (vec!["snacks", "snack", "food", "treats"], "snacks"),
-            (vec!["livestream", "stream", "streaming", "live"], "livestream"),
-            (vec!["scoreboard", "score", "scoring"], "scoreboard"),
-            (vec!["pitchcount", "pitch count", "pitch", "pitches"], "pitchcount"),
-            (vec!["gamechanger", "game changer", "gc", "scorebook"], "gamechanger"),
-        ];
-
-        let mut found_roles = Vec::new();
-
-        for (keywords, role) in &role_mappings {
-
-            if keywords.iter().any(|kw| text.contains(kw)) {
-                found_roles.push(role.to_string());
-            }
-        }
-
-        found_roles
+        synonyms::roles_in(text).into_iter().map(String::from).collect()
     }
 
 fn extract_person_name(&self, text: &str) -> Option<String> {
@@ -226,7 +499,7 @@ fn extract_person_name(&self, text: &str) -> Option<String> {
 }
 
     fn extract_date(&self, text: &str) -> Option<NaiveDate> {
-        let today = Utc::now().date_naive();
+        let today = self.today();
         
         // Relative date keywords
         if text.contains("today") {
@@ -320,6 +593,12 @@ fn extract_person_name(&self, text: &str) -> Option<String> {
         None
     }
 
+    /// Whether a message points at a particular game: "saturday", "5/3", "next game", ...
+    pub fn mentions_game_or_date(&self, text: &str) -> bool {
+        let text = text.to_lowercase();
+        text.contains("game") || self.extract_date(&text).is_some() || self.extract_relative_game(&text).is_some()
+    }
+
     fn extract_relative_game(&self, text: &str) -> Option<usize> {
         // "next game" or just "next" = game 0 (next)
         if text.contains("next game") || (text.contains("next") && !text.contains("after")) {
@@ -354,26 +633,20 @@ fn extract_person_name(&self, text: &str) -> Option<String> {
     }
 
     fn parse_game_query_intent(&self, text: &str) -> ParsedIntent {
-        let category = self.extract_game_category(text);
+        let categories = self.extract_game_categories(text);
         let count = self.extract_game_count(text);
         let relative = self.extract_relative_time(text);
 
-        ParsedIntent::GameQuery { category, count, relative }
+        ParsedIntent::GameQuery { categories, count, relative, date: None }
     }
 
-    fn extract_game_category(&self, text: &str) -> Option<String> {
-        let categories = [
-            "time", "location", "where", "home", "snacks", 
-            "livestream", "scoreboard", "pitchcount", "pitch count"
-        ];
-
-        for category in &categories {
-            if text.contains(category) {
-                return Some(category.to_string());
-            }
-        }
+    /// Game date for a weather question; None means the next game
+    fn parse_weather_intent(&self, text: &str) -> ParsedIntent {
+        ParsedIntent::Weather { date: self.extract_date(&synonyms::without_weather_words(text)) }
+    }
 
-        None
+    fn extract_game_categories(&self, text: &str) -> Vec<String> {
+        synonyms::categories_in(text).into_iter().map(String::from).collect()
     }
 
     fn extract_game_count(&self, text: &str) -> Option<usize> {
@@ -420,16 +693,20 @@ fn extract_person_name(&self, text: &str) -> Option<String> {
             "available", "open", "assignments"
         ];
         
-        let context_keywords = ["snacks", "livestream", "scoreboard", "pitchcount", "volunteer"];
-        
         let has_query = query_keywords.iter().any(|kw| text.contains(kw));
-        let has_context = context_keywords.iter().any(|kw| text.contains(kw));
+        let has_context = text.contains("volunteer") || !synonyms::roles_in(text).is_empty();
         
         has_query && has_context
     }
 
     fn parse_volunteer_query_intent(&self, text: &str) -> ParsedIntent {
         let date = self.extract_date(text);
+        // "who's got snacks and scoreboard saturday" asks about those roles, not the whole list
+        let roles = synonyms::roles_in(text);
+        if text.contains("who") && !roles.is_empty() {
+            let categories = roles.into_iter().map(String::from).collect();
+            return ParsedIntent::GameQuery { categories, count: None, relative: None, date };
+        }
         ParsedIntent::VolunteerQuery { date }
     }
 
@@ -517,11 +794,11 @@ fn extract_person_name(&self, text: &str) -> Option<String> {
                            suggestions.join(" and "))
                 }
             }
-            ParsedIntent::GameQuery { category, .. } => {
-                if category.is_none() {
+            ParsedIntent::GameQuery { categories, .. } => {
+                if categories.is_empty() {
                     "⚾ Looking for game info? Let me show you what's coming up!".to_string()
                 } else {
-                    format!("⚾ Looking for {} info? Here's what I found!", category.as_ref().unwrap())
+                    format!("⚾ Looking for {} info? Here's what I found!", categories.join(" and "))
                 }
             }
             _ => "🏴‍☠️ Let me show you what I can help with!".to_string()
@@ -573,6 +850,675 @@ fn extract_person_name(&self, text: &str) -> Option<String> {
         ParsedIntent::RemoveModerator { user_id }
     }
 
+    /// "who is 87654321" or "who is @Jane" - only matches when there's actually an id to look up,
+    /// so "who is on snacks" still falls through to the volunteer query
+    fn parse_who_is(&self, text: &str, attachments: &[crate::models::Attachment]) -> Option<ParsedIntent> {
+        if !(text.contains("who is") || text.contains("whois")) {
+            return None;
+        }
+        attachments
+            .iter()
+            .find(|a| a.attachment_type == "mentions")
+            .and_then(|a| a.user_ids.first())
+            .cloned()
+            .or_else(|| text.split_whitespace()
+                .find(|w| w.len() >= 5 && w.chars().all(|c| c.is_ascii_digit()))
+                .map(|w| w.to_string()))
+            .map(|user_id| ParsedIntent::WhoIs { user_id })
+    }
+
+    /// "rename to ScheduleBuddy" / "set avatar https://i.groupme.com/..." - name and url keep their case
+    fn parse_bot_profile(&self, text: &str, original_text: &str) -> Option<ParsedIntent> {
+        if text.starts_with("set avatar") || text.starts_with("change avatar") {
+            let url = original_text.split_whitespace().find(|w| w.starts_with("http"))?;
+            return Some(ParsedIntent::SetAvatar { url: url.to_string() });
+        }
+
+        let marker = ["rename yourself to ", "rename to ", "change your name to "]
+            .iter()
+            .find(|m| text.starts_with(*m))?;
+        let lower_original = original_text.to_lowercase();
+        let start = lower_original.find(marker)? + marker.len();
+        let name = original_text.get(start..)?.trim().trim_start_matches('@').trim_end_matches(['.', '!']);
+        (!name.is_empty()).then(|| ParsedIntent::RenameBot { name: name.to_string() })
+    }
+
+    /// "at 6 PM say: practice moved to Field 2" / "friday 8am say: ..." / "scheduled messages" /
+    /// "cancel scheduled message 2". The message keeps its original case.
+    fn parse_scheduled_message(&self, text: &str, original_text: &str) -> Option<ParsedIntent> {
+        if matches!(text, "scheduled messages" | "scheduled" | "list scheduled messages" | "show scheduled messages") {
+            return Some(ParsedIntent::ListScheduledMessages);
+        }
+        if let Some(rest) = ["cancel scheduled message", "cancel scheduled", "unschedule"]
+            .iter()
+            .find_map(|m| text.strip_prefix(m))
+        {
+            let id = rest.trim().trim_start_matches('#').parse().ok()?;
+            return Some(ParsedIntent::CancelScheduledMessage { id });
+        }
+
+        let marker = text.find(" say:").map(|i| (i, i + 5)).or_else(|| text.find(" say ").map(|i| (i, i + 5)))?;
+        let (time, date_text) = find_clock_time(&text[..marker.0])?;
+        let message = original_text.get(marker.1..)?.trim();
+        if message.is_empty() {
+            return None;
+        }
+        Some(ParsedIntent::ScheduleMessage {
+            date: self.extract_date(&date_text),
+            time,
+            text: message.to_string(),
+        })
+    }
+
+    /// "clear all volunteers for 5/10" / "copy volunteers from 5/10 to 5/17" - a whole game at once
+    fn parse_batch_volunteers(&self, text: &str) -> Option<ParsedIntent> {
+        if let Some(rest) = ["clear all volunteers", "clear volunteers", "clear all roles", "clear all spots"]
+            .iter()
+            .find_map(|m| text.strip_prefix(m))
+        {
+            return self.extract_date(rest).map(|date| ParsedIntent::ClearVolunteers { date });
+        }
+
+        let rest = ["copy volunteers from ", "copy all volunteers from ", "copy signups from ", "copy assignments from "]
+            .iter()
+            .find_map(|m| text.strip_prefix(m))?;
+        let (from, to) = rest.split_once(" to ")?;
+        Some(ParsedIntent::CopyVolunteers { from: self.extract_date(from)?, to: self.extract_date(to)? })
+    }
+
+    /// "game mode on" / "end game mode" / "game photos" / "photos from saturday"
+    fn parse_game_mode(&self, text: &str) -> Option<ParsedIntent> {
+        let text = text.trim_end_matches(['.', '!']);
+        if ["game mode on", "start game mode", "turn on game mode"].contains(&text) {
+            return Some(ParsedIntent::SetGameMode { on: true });
+        }
+        if ["game mode off", "end game mode", "stop game mode", "turn off game mode"].contains(&text) {
+            return Some(ParsedIntent::SetGameMode { on: false });
+        }
+        if text.starts_with("game photos") || text.starts_with("photos from") || text == "photos" {
+            return Some(ParsedIntent::GamePhotos { date: self.extract_date(text) });
+        }
+        None
+    }
+
+    /// "the Smiths are out June 1-14" / "I'm away 7/4" / "absences" / "remove absence 2".
+    /// Only a date range that parses makes it an absence, so "Jake is out at second" is left alone.
+    fn parse_absence(&self, text: &str, original_text: &str, sender_name: Option<&str>) -> Option<ParsedIntent> {
+        let text = text.trim_end_matches(['.', '!']);
+        if matches!(text, "absences" | "list absences" | "show absences" | "who's out" | "who is out" | "who's away" | "who is away") {
+            return Some(ParsedIntent::ListAbsences);
+        }
+        if let Some(rest) = ["remove absence", "cancel absence", "delete absence", "clear absence"]
+            .iter()
+            .find_map(|m| text.strip_prefix(m))
+        {
+            let id = rest.trim().trim_start_matches('#').parse().ok()?;
+            return Some(ParsedIntent::RemoveAbsence { id });
+        }
+
+        let (who_end, range_start) = [
+            " are out of town ", " is out of town ", " will be out of town ", " are on vacation ", " is on vacation ",
+            " are out ", " is out ", " will be out ", " are away ", " is away ", " will be away ",
+        ]
+        .iter()
+        .find_map(|m| text.find(m).map(|i| (i, i + m.len())))
+        .or_else(|| {
+            ["i'm out ", "i'm away ", "we're out ", "we're away ", "im out ", "were out "]
+                .iter()
+                .find(|m| text.starts_with(*m))
+                .map(|m| (m.find(['\'', ' ']).unwrap_or(0), m.len()))
+        })?;
+        let range = &text[range_start..];
+        let range = range.strip_prefix("of town ").unwrap_or(range);
+        let (start, end) = crate::absences::parse_date_range(range, self.today())?;
+
+        let who = original_text.get(..who_end).unwrap_or(&text[..who_end]).trim().trim_start_matches('@');
+        let who = match who.to_lowercase().as_str() {
+            "i" | "im" | "we" | "were" | "" => sender_name?.to_string(),
+            _ => who.to_string(),
+        };
+        Some(ParsedIntent::RecordAbsence { who, start, end })
+    }
+
+    /// "clear assignments for Jane Doe" - every upcoming volunteer spot held by someone
+    fn parse_clear_assignments(&self, text: &str, original_text: &str) -> Option<ParsedIntent> {
+        let marker = ["clear assignments for ", "clear signups for ", "clear all assignments for "]
+            .iter()
+            .find(|m| text.starts_with(*m))?;
+        let start = original_text.to_lowercase().find(marker)? + marker.len();
+        let person = original_text.get(start..)?.trim().trim_start_matches('@').trim_end_matches(['.', '!']);
+        (!person.is_empty()).then(|| ParsedIntent::ClearAssignments { person: person.to_string() })
+    }
+
+    /// "block word jerk" / "unblock word jerk" / "blocked words". A quoted phrase works too.
+    fn parse_word_filter(&self, text: &str) -> Option<ParsedIntent> {
+        if matches!(text, "blocked words" | "list blocked words" | "show blocked words" | "word filter") {
+            return Some(ParsedIntent::ListBlockedWords);
+        }
+        let (block, rest) = [("block word ", true), ("block phrase ", true), ("unblock word ", false), ("unblock phrase ", false)]
+            .iter()
+            .find_map(|(prefix, block)| text.strip_prefix(prefix).map(|rest| (*block, rest)))?;
+        let word = rest.trim().trim_matches(['"', '\'', '“', '”']).trim().to_string();
+        if word.is_empty() {
+            return None;
+        }
+        Some(if block { ParsedIntent::BlockWord { word } } else { ParsedIntent::UnblockWord { word } })
+    }
+
+    /// "add event picture day 5/9 9am at Hall Field" / "events" / "remove event 2" /
+    /// "picture day reminder 2d: ..." / "picture day reminders" / "reset picture day reminders".
+    /// Reminder commands only match a known event type, so "disable reminders" is left alone.
+    fn parse_special_event(&self, text: &str, original_text: &str) -> Option<ParsedIntent> {
+        let text = text.trim_end_matches(['.', '!']);
+        if matches!(text, "events" | "special events" | "upcoming events" | "list events" | "show events") {
+            return Some(ParsedIntent::ListSpecialEvents);
+        }
+        if let Some(rest) = ["remove event", "cancel event", "delete event"].iter().find_map(|m| text.strip_prefix(m)) {
+            let id = rest.trim().trim_start_matches('#').parse().ok()?;
+            return Some(ParsedIntent::RemoveSpecialEvent { id });
+        }
+        if let Some(marker) = ["add event ", "new event ", "add special event "].iter().find(|m| text.starts_with(*m)) {
+            let words = original_text.trim().trim_end_matches(['.', '!']).get(marker.len()..)?;
+            return Some(self.parse_new_event(words));
+        }
+        if let Some(kind) = text.strip_prefix("reset ").and_then(|rest| rest.strip_suffix(" reminders")) {
+            return crate::special_events::event_type(kind).map(|t| ParsedIntent::ResetEventReminders { kind: t.key.to_string() });
+        }
+        if let Some(kind) = text.strip_suffix(" reminders") {
+            return crate::special_events::event_type(kind).map(|t| ParsedIntent::ShowEventReminders { kind: t.key.to_string() });
+        }
+
+        // "<type> reminder 3d: <text>" / "<type> reminder 18 hours before: <text>"
+        let (head, _) = text.split_once(':')?;
+        let (kind, lead) = head.split_once(" reminder ")?;
+        let kind = crate::special_events::event_type(kind)?;
+        let lead = lead.trim().trim_end_matches("before").trim();
+        let digits = lead.find(|c: char| !c.is_ascii_digit()).unwrap_or(lead.len());
+        let amount: u32 = lead[..digits].parse().ok().filter(|n| *n > 0)?;
+        let lead_hours = match lead[digits..].trim() {
+            "d" | "day" | "days" => amount * 24,
+            "w" | "wk" | "week" | "weeks" => amount * 24 * 7,
+            "h" | "hr" | "hrs" | "hour" | "hours" => amount,
+            _ => return None,
+        };
+        let text = original_text.split_once(':')?.1.trim();
+        (!text.is_empty()).then(|| ParsedIntent::SetEventReminder {
+            kind: kind.key.to_string(),
+            lead_hours,
+            text: text.to_string(),
+        })
+    }
+
+    /// "Spring Pictures 5/9 at 9am at Hall Field": the date, an optional clock time, and
+    /// whatever follows the last leftover "at" as the place. The title keeps its case.
+    fn parse_new_event(&self, words: &str) -> ParsedIntent {
+        const WEEKDAYS: [&str; 9] = ["monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday", "today", "tomorrow"];
+        let mut words: Vec<&str> = words.split_whitespace().collect();
+        let lower = |word: &str| word.to_lowercase().trim_end_matches(',').to_string();
+
+        let mut time = None;
+        for idx in 0..words.len() {
+            if !words[idx].starts_with(|c: char| c.is_ascii_digit()) || words[idx].contains('/') {
+                continue;
+            }
+            let with_meridiem = words.get(idx + 1).map(|next| format!("{} {}", lower(words[idx]), lower(next)));
+            let found = with_meridiem
+                .and_then(|candidate| find_clock_time(&candidate).filter(|(_, rest)| rest.is_empty()).map(|(t, _)| (t, 2)))
+                .or_else(|| find_clock_time(&lower(words[idx])).map(|(t, _)| (t, 1)));
+            if let Some((parsed, used)) = found {
+                time = Some(parsed.format("%-I:%M %p").to_string());
+                words.drain(idx..idx + used);
+                break;
+            }
+        }
+
+        let mut date = None;
+        if let Some(idx) = words.iter().position(|w| {
+            let w = lower(w);
+            (w.contains(|c: char| c.is_ascii_digit()) || WEEKDAYS.contains(&w.as_str())) && self.extract_date(&w).is_some()
+        }) {
+            let preceded_by_next = idx > 0 && lower(words[idx - 1]) == "next";
+            date = self.extract_date(&if preceded_by_next { format!("next {}", lower(words[idx])) } else { lower(words[idx]) });
+            let first = if preceded_by_next { idx - 1 } else { idx };
+            words.drain(first..=idx);
+        }
+        let Some(date) = date else {
+            return ParsedIntent::ConversationalResponse {
+                message: "📅 When is it? e.g. \"add event picture day 5/9 9am at Hall Field\"".to_string(),
+            };
+        };
+
+        // "on"/"at" left dangling by the date or time coming out
+        let text = words.join(" ");
+        let text = text.replace(" at at ", " at ").replace(" on at ", " at ");
+        let text = text.trim_end_matches(" on").trim_end_matches(" at");
+        let (title, location) = match text.to_lowercase().rfind(" at ") {
+            Some(idx) => (text[..idx].trim(), Some(text[idx + 4..].trim().to_string()).filter(|l| !l.is_empty())),
+            None => (text.trim(), None),
+        };
+        let title = title.trim_end_matches([' ', ',']).trim_end_matches(" on");
+        let title = if title.is_empty() { "Team Event".to_string() } else { title.to_string() };
+        ParsedIntent::AddSpecialEvent { title, date, time, location }
+    }
+
+    /// "disable weather" / "turn on team facts everywhere" / "features".
+    /// Only claims the message when the rest names a known feature.
+    fn parse_feature_toggle(&self, text: &str) -> Option<ParsedIntent> {
+        if text == "features" || text == "feature flags" || text.starts_with("list features") {
+            return Some(ParsedIntent::ListFeatures);
+        }
+
+        let (enabled, rest) = [("enable ", true), ("turn on ", true), ("disable ", false), ("turn off ", false)]
+            .iter()
+            .find_map(|(prefix, enabled)| text.strip_prefix(prefix).map(|rest| (*enabled, rest)))?;
+        let rest = rest.trim().trim_end_matches(|c: char| !c.is_alphanumeric());
+        let (rest, all_groups) = ["everywhere", "for all groups", "for every group"]
+            .iter()
+            .find_map(|suffix| rest.strip_suffix(suffix).map(|r| (r.trim(), true)))
+            .unwrap_or((rest, false));
+        let feature = crate::features::Feature::parse(rest)?;
+        Some(ParsedIntent::SetFeature { feature, enabled, all_groups })
+    }
+
+    /// "timezone for Tournament Park is America/Chicago" / "clear timezone for Tournament Park".
+    /// The venue keeps its case; the time zone is checked when the command runs.
+    fn parse_venue_timezone(&self, text: &str, original_text: &str) -> Option<ParsedIntent> {
+        let clear = text.starts_with("clear timezone for ") || text.starts_with("clear time zone for ");
+        let marker = ["set timezone for ", "set time zone for ", "timezone for ", "time zone for ",
+                      "clear timezone for ", "clear time zone for "]
+            .iter()
+            .find(|m| text.starts_with(*m))?;
+        let start = original_text.to_lowercase().find(marker)? + marker.len();
+        let rest = original_text.get(start..)?.trim().trim_end_matches(['.', '!']);
+
+        if clear {
+            return (!rest.is_empty()).then(|| ParsedIntent::SetVenueTimezone { venue: rest.to_string(), timezone: None });
+        }
+        let rest_lower = rest.to_lowercase();
+        let (split, sep_len) = [" is ", " to "]
+            .iter()
+            .filter_map(|sep| rest_lower.rfind(sep).map(|pos| (pos, sep.len())))
+            .max_by_key(|(pos, _)| *pos)?;
+        let venue = rest[..split].trim();
+        let timezone = rest[split + sep_len..].trim();
+        (!venue.is_empty() && !timezone.is_empty()).then(|| ParsedIntent::SetVenueTimezone {
+            venue: venue.to_string(),
+            timezone: Some(timezone.to_string()),
+        })
+    }
+
+    /// "start tournament Summer Slam" / "bracket" / "clear tournament" /
+    /// "bracket game 3 6/14 3pm at Tournament Park vs Storm if we win game 2" / "we won bracket game 2".
+    /// A result with a score ("we won bracket game 2 7-3") is left for the game-result parser.
+    fn parse_tournament(&self, text: &str, original_text: &str) -> Option<ParsedIntent> {
+        use crate::tournament::Outcome;
+
+        for marker in ["start tournament ", "new tournament "] {
+            if text.starts_with(marker) {
+                let name = original_text.get(original_text.to_lowercase().find(marker)? + marker.len()..)?.trim();
+                return (!name.is_empty()).then(|| ParsedIntent::StartTournament { name: name.to_string() });
+            }
+        }
+        if matches!(text, "bracket" | "show bracket" | "tournament" | "tournament bracket" | "tournament schedule") {
+            return Some(ParsedIntent::ShowBracket);
+        }
+        if matches!(text, "clear tournament" | "clear bracket" | "end tournament") {
+            return Some(ParsedIntent::ClearTournament);
+        }
+        if let Some(intent) = self.parse_tournament_logistics(text, original_text) {
+            return Some(intent);
+        }
+
+        let (pos, marker) = ["bracket game ", "tournament game ", "pool game "]
+            .iter()
+            .find_map(|m| text.find(m).map(|pos| (pos, *m)))?;
+        let after = &text[pos + marker.len()..];
+        let number_word = after.split_whitespace().next()?;
+        let number: u32 = number_word.trim_end_matches([':', ',']).parse().ok()?;
+        let rest_start = pos + marker.len() + after.find(number_word)? + number_word.len();
+        let mut rest = text.get(rest_start..)?.to_string();
+        let mut rest_original = original_text.get(original_text.len() - text.len() + rest_start..)
+            .filter(|r| r.len() == rest.len() && r.to_lowercase() == rest)
+            .unwrap_or(&rest)
+            .to_string();
+
+        let outcome_of = |word: &str| match word.trim_matches(|c: char| !c.is_alphanumeric()) {
+            "win" | "won" | "beat" | "w" => Some(Outcome::Won),
+            "lose" | "lost" | "loss" | "l" => Some(Outcome::Lost),
+            _ => None,
+        };
+
+        // "if we win game 2" -> condition, cut out of the rest of the message
+        let mut condition = None;
+        if let Some(if_pos) = rest.find("if we ") {
+            let words: Vec<&str> = rest[if_pos + 6..].split_whitespace().take(3).collect();
+            if let [outcome, "game", game] = words.as_slice() {
+                if let (Some(outcome), Ok(number)) = (outcome_of(outcome), game.trim_matches(|c: char| !c.is_ascii_digit()).parse()) {
+                    condition = Some((number, outcome));
+                    let end = rest[if_pos..].find(game).map(|p| if_pos + p + game.len()).unwrap_or(rest.len());
+                    rest.replace_range(if_pos..end, "");
+                    rest_original.replace_range(if_pos..end, "");
+                }
+            }
+        }
+
+        let has_score = rest.split_whitespace()
+            .any(|w| matches!(w.split_once('-'), Some((a, b)) if a.parse::<u32>().is_ok() && b.parse::<u32>().is_ok()));
+        let result = text[..pos].split_whitespace().chain(rest.split_whitespace()).find_map(outcome_of);
+        if condition.is_none() && !rest.contains(" at ") {
+            if let Some(outcome) = result {
+                return (!has_score).then_some(ParsedIntent::BracketResult { number, outcome });
+            }
+        }
+
+        // "at Tournament Park" up to the opponent
+        let location = match rest.find(" at ") {
+            Some(at) => {
+                let end = rest[at + 4..].find(" vs").map(|p| at + 4 + p).unwrap_or(rest.len());
+                rest_original.get(at + 4..end).unwrap_or_default().trim().trim_end_matches([',', '.']).to_string()
+            }
+            None => String::new(),
+        };
+        let opponent = self.extract_opponent(&rest_original);
+
+        let head_end = [" at ", " vs"].iter().filter_map(|m| rest.find(m)).min().unwrap_or(rest.len());
+        let head = &rest[..head_end];
+        let (time, date_text) = match find_clock_time(head) {
+            Some((parsed, rest)) => (parsed.format("%-I:%M %p").to_string(), rest),
+            None => (String::new(), head.to_string()),
+        };
+        let date = self.extract_date(&date_text);
+
+        Some(ParsedIntent::SetBracketGame { number, date, time, location, opponent, condition })
+    }
+
+    /// "tournament info" / "tournament dates 6/13-6/15" / "tournament hotel: https://..." /
+    /// "clear tournament hotel". Values keep their original case.
+    fn parse_tournament_logistics(&self, text: &str, original_text: &str) -> Option<ParsedIntent> {
+        if matches!(text, "tournament info" | "tournament details" | "travel info") {
+            return Some(ParsedIntent::TournamentInfo);
+        }
+        if let Some(range) = text.strip_prefix("tournament dates ") {
+            let (first, last) = ["-", " to ", " through ", " thru "]
+                .iter()
+                .find_map(|sep| range.split_once(sep))
+                .unwrap_or((range, range));
+            let start = self.extract_date(first.trim());
+            // "6/13-15" means the 15th of the same month
+            let end = match (start, last.trim().parse::<u32>()) {
+                (Some(start), Ok(day)) => start.with_day(day),
+                _ => self.extract_date(last.trim()),
+            };
+            return Some(ParsedIntent::SetTournamentDates { start, end });
+        }
+        if let Some(label) = text.strip_prefix("clear tournament ") {
+            return Some(ParsedIntent::SetTournamentLogistics { label: label.trim().to_string(), value: None });
+        }
+
+        let rest = text.strip_prefix("tournament ")?;
+        let (label, _) = rest.split_once(':')?;
+        let label = label.trim();
+        if label.is_empty() || label.starts_with("game") {
+            return None;
+        }
+        let (_, value) = original_text.split_once(':')?;
+        let value = value.trim();
+        (!value.is_empty()).then(|| ParsedIntent::SetTournamentLogistics {
+            label: label.to_string(),
+            value: Some(value.to_string()),
+        })
+    }
+
+    /// "note for saturday: team photos after the game" / "clear note for saturday" /
+    /// "cancel game saturday: field is flooded".
+    /// The note keeps the original capitalization; no date means the next game.
+    fn parse_game_note(&self, text: &str, original_text: &str) -> Option<ParsedIntent> {
+        if ["cancel game", "cancel the game", "cancel next game", "cancel the next game"].iter().any(|p| text.starts_with(p)) {
+            let head = text.split(':').next().unwrap_or(text);
+            let reason = original_text.split_once(':').map(|(_, r)| r.trim().to_string()).filter(|r| !r.is_empty());
+            return Some(ParsedIntent::CancelGame { date: self.extract_date(head), reason });
+        }
+        if ["clear note", "remove note", "delete note"].iter().any(|p| text.starts_with(p)) {
+            return Some(ParsedIntent::ClearNote { date: self.extract_date(text) });
+        }
+        if !["note", "add note", "set note"].iter().any(|p| text.starts_with(p)) {
+            return None;
+        }
+
+        let head = &text[..text.find(':')?];
+        let (_, body) = original_text.split_once(':')?;
+        let note = body.trim();
+        if note.is_empty() {
+            return None;
+        }
+        Some(ParsedIntent::SetNote { date: self.extract_date(head), note: note.to_string() })
+    }
+
+    /// Non-game event named by "for the party" / "for our potluck" - None for games and dates
+    fn extract_event_name(&self, text: &str) -> Option<String> {
+        let game_words = ["game", "games", "next", "practice", "today", "tomorrow"];
+        ["for the ", "for our "].iter().find_map(|marker| {
+            let start = text.find(marker)? + marker.len();
+            let name = text[start..]
+                .split(|c: char| !c.is_alphanumeric() && c != '-' && c != '\'')
+                .next()?
+                .to_string();
+            let is_event = !name.is_empty() && !game_words.contains(&name.as_str()) && self.extract_date(&name).is_none();
+            is_event.then_some(name)
+        })
+    }
+
+    /// "party needs: plates, cups" / "I'll bring cupcakes for the party" /
+    /// "what's still needed for the party" / "clear the party list"
+    fn parse_bring_list(&self, text: &str, original_text: &str, sender_name: Option<&str>) -> Option<ParsedIntent> {
+        let event = self.extract_event_name(text);
+
+        if let Some(needs_idx) = text.find(" needs:") {
+            let event = text[..needs_idx].trim().trim_start_matches("the ").to_string();
+            let items: Vec<String> = text[needs_idx + " needs:".len()..]
+                .split([',', ';'])
+                .map(|item| item.trim().trim_start_matches("and ").trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect();
+            if !event.is_empty() && !items.is_empty() {
+                return Some(ParsedIntent::RequestBringItems { event, items });
+            }
+        }
+
+        if text.starts_with("clear") && text.ends_with(" list") {
+            let event = text.trim_start_matches("clear").trim_end_matches(" list").trim().trim_start_matches("the ").to_string();
+            if !event.is_empty() {
+                return Some(ParsedIntent::ClearBringList { event });
+            }
+        }
+
+        let asks_status = ["still needed", "still need", "what's needed", "what is needed", "bring list"].iter().any(|kw| text.contains(kw));
+        if asks_status && (event.is_some() || text.contains("bring list")) {
+            return Some(ParsedIntent::BringListStatus { event });
+        }
+
+        // "who's bringing drinks?" asks; it doesn't put the asker down for drinks
+        if self.is_question(text) {
+            return None;
+        }
+        let bring_verbs = ["i'll bring", "i will bring", "i can bring", "i'm bringing", "we'll bring", "bringing"];
+        let verb = bring_verbs.iter().find(|v| text.contains(*v))?;
+        let after_verb = &text[text.find(verb)? + verb.len()..];
+        let item = after_verb
+            .split(" for the ")
+            .next()
+            .unwrap_or_default()
+            .split(" for our ")
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches(|c: char| c.is_ascii_punctuation() || c.is_whitespace())
+            .trim()
+            .to_string();
+
+        // "I'll bring snacks" without a party is still the regular snacks volunteer role
+        if item.is_empty() || (event.is_none() && !self.extract_volunteer_roless(&item).is_empty()) {
+            return None;
+        }
+
+        let person = self.extract_person_name(original_text).or_else(|| sender_name.map(|s| s.to_string()));
+        Some(ParsedIntent::BringItem { event, item, person })
+    }
+
+    /// "final: we won 7-3 vs Chaos" / "result vs chaos 4-5 on 4/12" / "we lost 4-5 to the Chaos".
+    /// Scores are from our side; "won"/"lost" fix the order if it was written winner-first.
+    fn parse_game_result(&self, text: &str, original_text: &str) -> Option<ParsedIntent> {
+        let reports_result = ["we won", "we lost", "we tied", "we beat"].iter().any(|kw| text.contains(kw))
+            || ["result", "final"].iter().any(|kw| text.starts_with(kw));
+        if !reports_result {
+            return None;
+        }
+
+        let score = text
+            .split_whitespace()
+            .map(|w| w.trim_matches(|c: char| !c.is_ascii_digit()))
+            .find(|w| matches!(w.split_once('-'), Some((a, b)) if a.parse::<u32>().is_ok() && b.parse::<u32>().is_ok()))?;
+        let (a, b) = score.split_once('-')?;
+        let (mut our_score, mut their_score) = (a.parse::<u32>().ok()?, b.parse::<u32>().ok()?);
+        let won = text.contains("won") || text.contains("beat");
+        if (text.contains("lost") && our_score > their_score) || (won && our_score < their_score) {
+            std::mem::swap(&mut our_score, &mut their_score);
+        }
+
+        // The score looks like a MM-DD date, so only look for a date in the rest of the message
+        let date = self.extract_date(&text.replace(score, "")).map(|date| self.most_recent_occurrence(date));
+        let opponent = self.extract_opponent(original_text);
+        Some(ParsedIntent::RecordResult { date, opponent, our_score, their_score })
+    }
+
+    /// "history vs Chaos" / "record against the Chaos" / "head to head vs Chaos"
+    fn parse_head_to_head(&self, text: &str, original_text: &str) -> Option<ParsedIntent> {
+        let asks_history = ["history", "head to head", "head-to-head", "record", "how have we done", "how did we do"]
+            .iter()
+            .any(|kw| text.contains(kw));
+        if !asks_history {
+            return None;
+        }
+        self.extract_opponent(original_text).map(|opponent| ParsedIntent::HeadToHead { opponent })
+    }
+
+    /// "stat Jake 2-3, 1 RBI" / "stats Jake" / "team stats"
+    fn parse_player_stats(&self, text: &str, original_text: &str) -> Option<ParsedIntent> {
+        if text == "stats" || ["team stats", "stat leaders", "stats leaders", "leaderboard"].iter().any(|kw| text.contains(kw)) {
+            return Some(ParsedIntent::TeamStats);
+        }
+
+        let (logging, rest) = match (text.strip_prefix("stat "), text.strip_prefix("stats ")) {
+            (Some(rest), _) => (true, rest),
+            (_, Some(rest)) => (false, rest.trim_start_matches("for ")),
+            _ => return None,
+        };
+        let words: Vec<&str> = rest.split_whitespace().collect();
+        let name_len = words
+            .iter()
+            .position(|w| w.starts_with(|c: char| c.is_ascii_digit()))
+            .unwrap_or(words.len());
+        if name_len == 0 {
+            return None;
+        }
+        let name = words[..name_len].join(" ");
+        let name = name.trim_matches(|c: char| !c.is_alphanumeric());
+        let player = Self::original_case(original_text, name);
+
+        if !logging {
+            return Some(ParsedIntent::PlayerStats { player });
+        }
+        let stats_text = words[name_len..].join(" ");
+        let counts = Self::extract_stat_counts(&stats_text);
+        let hits_token = stats_text.split([' ', ',']).find(|w| w.contains('-')).unwrap_or_default();
+        let date = self.extract_date(&stats_text.replace(hits_token, "")).map(|date| self.most_recent_occurrence(date));
+        Some(ParsedIntent::LogStats { date, player, counts })
+    }
+
+    /// "2-3, 1 RBI, 2 runs" / "2 for 3 with a homer" -> counting stats
+    fn extract_stat_counts(text: &str) -> crate::stats::StatCounts {
+        let mut counts = crate::stats::StatCounts::default();
+        let words: Vec<&str> = text.split([' ', ',', ';']).filter(|w| !w.is_empty()).collect();
+
+        for (idx, word) in words.iter().enumerate() {
+            if let Some((hits, at_bats)) = word.split_once('-') {
+                if let (Ok(hits), Ok(at_bats)) = (hits.parse(), at_bats.parse()) {
+                    counts.hits = hits;
+                    counts.at_bats = at_bats;
+                }
+                continue;
+            }
+            let Ok(n) = word.parse::<u32>() else {
+                if matches!(*word, "homer" | "homerun" | "hr") && idx > 0 && matches!(words[idx - 1], "a" | "one") {
+                    counts.home_runs += 1;
+                }
+                continue;
+            };
+            match words.get(idx + 1).copied().unwrap_or_default() {
+                "for" => {
+                    if let Some(Ok(at_bats)) = words.get(idx + 2).map(|w| w.parse()) {
+                        counts.hits = n;
+                        counts.at_bats = at_bats;
+                    }
+                }
+                "rbi" | "rbis" => counts.rbi += n,
+                "r" | "run" | "runs" => counts.runs += n,
+                "hr" | "hrs" | "homer" | "homers" | "homeruns" | "home" => counts.home_runs += n,
+                "sb" | "steal" | "steals" | "stolen" => counts.stolen_bases += n,
+                "bb" | "walk" | "walks" => counts.walks += n,
+                "h" | "hit" | "hits" => counts.hits += n,
+                "ab" | "abs" => counts.at_bats += n,
+                _ => {}
+            }
+        }
+        counts
+    }
+
+    /// Recover the original capitalization of a phrase found in the lowercased text
+    fn original_case(original_text: &str, phrase: &str) -> String {
+        original_text
+            .to_lowercase()
+            .find(phrase)
+            .and_then(|idx| original_text.get(idx..idx + phrase.len()))
+            .unwrap_or(phrase)
+            .to_string()
+    }
+
+    /// extract_date looks forward ("saturday" is the coming one); results are about the past
+    fn most_recent_occurrence(&self, date: NaiveDate) -> NaiveDate {
+        let today = self.today();
+        if date <= today {
+            date
+        } else if date - today <= Duration::days(14) {
+            // A weekday name: step back whole weeks to the last one on or before today
+            let weeks_ahead = ((date - today).num_days() + 6) / 7;
+            date - Duration::weeks(weeks_ahead)
+        } else {
+            date.with_year(date.year() - 1).unwrap_or(date)
+        }
+    }
+
+    /// Team named after "vs"/"against"/"to"/"beat", keeping its original capitalization
+    fn extract_opponent(&self, original_text: &str) -> Option<String> {
+        let lower = original_text.to_lowercase();
+        let start = [" vs. ", " vs ", " against ", " to ", " beat "]
+            .iter()
+            .filter_map(|marker| lower.find(marker).map(|idx| idx + marker.len()))
+            .min()?;
+        let stop_words = ["on", "at", "today", "yesterday", "last", "this"];
+        let name: Vec<&str> = original_text
+            .get(start..)?
+            .split_whitespace()
+            .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()))
+            .skip_while(|w| w.eq_ignore_ascii_case("the"))
+            .take_while(|w| {
+                !w.is_empty()
+                    && !stop_words.contains(&w.to_lowercase().as_str())
+                    && !w.starts_with(|c: char| c.is_ascii_digit())
+            })
+            .collect();
+        (!name.is_empty()).then(|| name.join(" "))
+    }
+
     fn parse_list_messages(&self, text: &str) -> ParsedIntent {
         // Extract count if specified (e.g., "list 10 messages")
         let count = text.split_whitespace()
@@ -624,6 +1570,73 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_questions_never_sign_up() {
+        let parser = create_parser();
+
+        let not_sign_ups = vec![
+            "@PirateBot who has snacks Saturday?",
+            "@PirateBot who has snacks saturday",
+            "@PirateBot does anyone have scoreboard for the next game",
+            "@PirateBot is livestream covered?",
+            "@PirateBot snacks saturday",
+            "@PirateBot I can't do snacks saturday",
+            "@PirateBot I don't have snacks this week, sorry",
+            "@PirateBot nobody has scoreboard saturday",
+            "@PirateBot we still need someone to run the stream saturday",
+            "@PirateBot thanks for bringing snacks last week",
+        ];
+        for case in not_sign_ups {
+            let intent = parser.parse_message(case, None, &[]);
+            assert!(!matches!(intent, Some(ParsedIntent::Volunteer { .. })), "{} => {:?}", case, intent);
+        }
+
+        let statements = vec![
+            "@PirateBot Hobbs has snacks Saturday",
+            "@PirateBot I'll take scoreboard for the next game",
+            "@PirateBot we've got the stream covered",
+        ];
+        for case in statements {
+            let intent = parser.parse_message(case, None, &[]);
+            assert!(matches!(intent, Some(ParsedIntent::Volunteer { .. })), "{} => {:?}", case, intent);
+        }
+    }
+
+    #[test]
+    fn test_multiple_category_query() {
+        let parser = create_parser();
+
+        let intent = parser.parse_message("@PirateBot who's got snacks and scoreboard Saturday?", None, &[]);
+        match intent {
+            Some(ParsedIntent::GameQuery { categories, date, .. }) => {
+                assert_eq!(categories, vec!["snacks".to_string(), "scoreboard".to_string()]);
+                assert!(date.is_some_and(|d| d.weekday() == chrono::Weekday::Sat));
+            }
+            other => panic!("expected a game query, got {:?}", other),
+        }
+
+        let intent = parser.parse_message("@PirateBot next game food and stream", None, &[]);
+        assert!(matches!(intent, Some(ParsedIntent::GameQuery { ref categories, .. }) if categories == &["snacks", "livestream"]));
+    }
+
+    #[test]
+    fn test_weather_detection() {
+        let parser = create_parser();
+
+        let intent = parser.parse_message("@PirateBot what's the weather like Saturday?", None, &[]);
+        assert!(matches!(intent, Some(ParsedIntent::Weather { date: Some(d) }) if d.weekday() == chrono::Weekday::Sat));
+
+        for case in ["@PirateBot will it rain at the next game?", "@PirateBot is it going to be sunny", "@PirateBot how cold will it be"] {
+            let intent = parser.parse_message(case, None, &[]);
+            assert!(matches!(intent, Some(ParsedIntent::Weather { date: None })), "{}", case);
+        }
+
+        assert_eq!(parser.extract_game_categories("next game forecast"), vec!["weather".to_string()]);
+        // The Storm are a team too
+        let intent = parser.parse_message("@PirateBot when do we play the Storm?", None, &[]);
+        assert!(!matches!(intent, Some(ParsedIntent::Weather { .. })), "{:?}", intent);
+    }
+
     #[test]
     fn test_date_extraction() {
         let parser = create_parser();
@@ -651,4 +1664,382 @@ mod tests {
         assert_eq!(parser.extract_relative_game("game after next"), Some(1));
         assert_eq!(parser.extract_relative_game("two games from now"), Some(1));
     }
+
+    #[test]
+    fn test_who_is_detection() {
+        let parser = create_parser();
+
+        let intent = parser.parse_message("@PirateBot who is 87654321", None, &[]);
+        assert!(matches!(intent, Some(ParsedIntent::WhoIs { ref user_id }) if user_id == "87654321"));
+
+        // Without an id this is still a volunteer question
+        let intent = parser.parse_message("@PirateBot who is on snacks?", None, &[]);
+        assert!(!matches!(intent, Some(ParsedIntent::WhoIs { .. })));
+    }
+
+    #[test]
+    fn test_game_note_detection() {
+        let parser = create_parser();
+
+        let intent = parser.parse_message("@PirateBot note for Saturday: Team photos after the game", None, &[]);
+        match intent {
+            Some(ParsedIntent::SetNote { date, note }) => {
+                assert!(date.is_some());
+                assert_eq!(note, "Team photos after the game");
+            }
+            other => panic!("Expected SetNote, got {:?}", other),
+        }
+
+        let intent = parser.parse_message("@PirateBot clear note", None, &[]);
+        assert!(matches!(intent, Some(ParsedIntent::ClearNote { date: None })));
+
+        let intent = parser.parse_message("@PirateBot cancel game Saturday: Field is flooded", None, &[]);
+        match intent {
+            Some(ParsedIntent::CancelGame { date, reason }) => {
+                assert!(date.is_some());
+                assert_eq!(reason.as_deref(), Some("Field is flooded"));
+            }
+            other => panic!("Expected CancelGame, got {:?}", other),
+        }
+        let intent = parser.parse_message("@PirateBot cancel the game", None, &[]);
+        assert!(matches!(intent, Some(ParsedIntent::CancelGame { date: None, reason: None })));
+
+        // A note that only mentions cancelling is just a note
+        let intent = parser.parse_message("@PirateBot note for Saturday: not cancelled, just delayed", None, &[]);
+        assert!(matches!(intent, Some(ParsedIntent::SetNote { .. })));
+    }
+
+    #[test]
+    fn test_bring_list_detection() {
+        let parser = create_parser();
+
+        let intent = parser.parse_message("@PirateBot party needs: plates, cups, and drinks", None, &[]);
+        match intent {
+            Some(ParsedIntent::RequestBringItems { event, items }) => {
+                assert_eq!(event, "party");
+                assert_eq!(items, vec!["plates", "cups", "drinks"]);
+            }
+            other => panic!("Expected RequestBringItems, got {:?}", other),
+        }
+
+        let intent = parser.parse_message("@PirateBot I'll bring cupcakes for the party", Some("Jane"), &[]);
+        match intent {
+            Some(ParsedIntent::BringItem { event, item, person }) => {
+                assert_eq!(event.as_deref(), Some("party"));
+                assert_eq!(item, "cupcakes");
+                assert_eq!(person.as_deref(), Some("Jane"));
+            }
+            other => panic!("Expected BringItem, got {:?}", other),
+        }
+
+        let intent = parser.parse_message("@PirateBot what's still needed for the party?", None, &[]);
+        assert!(matches!(intent, Some(ParsedIntent::BringListStatus { event: Some(_) })));
+
+        // Game snacks stay a volunteer signup
+        let intent = parser.parse_message("@PirateBot I'll bring snacks", None, &[]);
+        assert!(!matches!(intent, Some(ParsedIntent::BringItem { .. })));
+
+        // Asking who's bringing something doesn't sign the asker up
+        for case in ["@PirateBot who's bringing drinks?", "@PirateBot who is bringing plates for the party"] {
+            let intent = parser.parse_message(case, Some("Jane"), &[]);
+            assert!(!matches!(intent, Some(ParsedIntent::BringItem { .. })), "{} => {:?}", case, intent);
+        }
+        match parser.parse_message("@PirateBot I'm bringing drinks!!", Some("Jane"), &[]) {
+            Some(ParsedIntent::BringItem { item, .. }) => assert_eq!(item, "drinks"),
+            other => panic!("Expected BringItem, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_game_result_detection() {
+        let parser = create_parser();
+
+        match parser.parse_message("@PirateBot we lost 5-4 to the Chaos", None, &[]) {
+            Some(ParsedIntent::RecordResult { opponent, our_score, their_score, date }) => {
+                assert_eq!(opponent.as_deref(), Some("Chaos"));
+                assert_eq!((our_score, their_score), (4, 5));
+                assert_eq!(date, None);
+            }
+            other => panic!("Expected RecordResult, got {:?}", other),
+        }
+
+        match parser.parse_message("@PirateBot final: 7-3 vs Blue Sox 9U", None, &[]) {
+            Some(ParsedIntent::RecordResult { opponent, our_score, their_score, .. }) => {
+                assert_eq!(opponent.as_deref(), Some("Blue Sox"));
+                assert_eq!((our_score, their_score), (7, 3));
+            }
+            other => panic!("Expected RecordResult, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_head_to_head_detection() {
+        let parser = create_parser();
+
+        match parser.parse_message("@PirateBot history vs Chaos 8U", None, &[]) {
+            Some(ParsedIntent::HeadToHead { opponent }) => assert_eq!(opponent, "Chaos"),
+            other => panic!("Expected HeadToHead, got {:?}", other),
+        }
+
+        let intent = parser.parse_message("@PirateBot how have we done against the Blue Sox?", None, &[]);
+        assert!(matches!(intent, Some(ParsedIntent::HeadToHead { .. })));
+    }
+
+    #[test]
+    fn test_player_stats_detection() {
+        let parser = create_parser();
+
+        match parser.parse_message("@PirateBot stat Jake 2-3, 1 RBI", None, &[]) {
+            Some(ParsedIntent::LogStats { player, counts, date }) => {
+                assert_eq!(player, "Jake");
+                assert_eq!((counts.hits, counts.at_bats, counts.rbi), (2, 3, 1));
+                assert_eq!(date, None);
+            }
+            other => panic!("Expected LogStats, got {:?}", other),
+        }
+
+        match parser.parse_message("@PirateBot stat Mia Lopez 1 for 2, 2 runs, 1 sb", None, &[]) {
+            Some(ParsedIntent::LogStats { player, counts, .. }) => {
+                assert_eq!(player, "Mia Lopez");
+                assert_eq!((counts.hits, counts.at_bats, counts.runs, counts.stolen_bases), (1, 2, 2, 1));
+            }
+            other => panic!("Expected LogStats, got {:?}", other),
+        }
+
+        assert!(matches!(parser.parse_message("@PirateBot stats Jake", None, &[]), Some(ParsedIntent::PlayerStats { .. })));
+        assert!(matches!(parser.parse_message("@PirateBot team stats", None, &[]), Some(ParsedIntent::TeamStats)));
+    }
+
+    #[test]
+    fn test_bot_profile_detection() {
+        let parser = create_parser();
+
+        match parser.parse_message("@PirateBot rename to ScheduleBuddy", None, &[]) {
+            Some(ParsedIntent::RenameBot { name }) => assert_eq!(name, "ScheduleBuddy"),
+            other => panic!("Expected RenameBot, got {:?}", other),
+        }
+        match parser.parse_message("@PirateBot set avatar https://i.groupme.com/512x512.png.AbC", None, &[]) {
+            Some(ParsedIntent::SetAvatar { url }) => assert_eq!(url, "https://i.groupme.com/512x512.png.AbC"),
+            other => panic!("Expected SetAvatar, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_venue_timezone_detection() {
+        let parser = create_parser();
+        match parser.parse_message("@PirateBot timezone for Tournament Park is America/Chicago", None, &[]) {
+            Some(ParsedIntent::SetVenueTimezone { venue, timezone }) => {
+                assert_eq!(venue, "Tournament Park");
+                assert_eq!(timezone.as_deref(), Some("America/Chicago"));
+            }
+            other => panic!("Expected SetVenueTimezone, got {:?}", other),
+        }
+        match parser.parse_message("@PirateBot clear timezone for Tournament Park", None, &[]) {
+            Some(ParsedIntent::SetVenueTimezone { venue, timezone: None }) => assert_eq!(venue, "Tournament Park"),
+            other => panic!("Expected SetVenueTimezone, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_feature_toggle_detection() {
+        use crate::features::Feature;
+
+        let parser = create_parser();
+        assert!(matches!(
+            parser.parse_message("@PirateBot disable weather", None, &[]),
+            Some(ParsedIntent::SetFeature { feature: Feature::Weather, enabled: false, all_groups: false })
+        ));
+        assert!(matches!(
+            parser.parse_message("@PirateBot turn on team facts everywhere", None, &[]),
+            Some(ParsedIntent::SetFeature { feature: Feature::TeamFacts, enabled: true, all_groups: true })
+        ));
+        assert!(matches!(parser.parse_message("@PirateBot features", None, &[]), Some(ParsedIntent::ListFeatures)));
+    }
+
+    #[test]
+    fn test_batch_volunteer_detection() {
+        let parser = create_parser();
+        match parser.parse_message("@PirateBot clear all volunteers for 5/10", None, &[]) {
+            Some(ParsedIntent::ClearVolunteers { date }) => assert_eq!(date.format("%m/%d").to_string(), "05/10"),
+            other => panic!("Expected ClearVolunteers, got {:?}", other),
+        }
+        match parser.parse_message("@PirateBot copy volunteers from 5/10 to 5/17", None, &[]) {
+            Some(ParsedIntent::CopyVolunteers { from, to }) => {
+                assert_eq!(from.format("%m/%d").to_string(), "05/10");
+                assert_eq!(to.format("%m/%d").to_string(), "05/17");
+            }
+            other => panic!("Expected CopyVolunteers, got {:?}", other),
+        }
+        // A person's spots are still cleared by name
+        assert!(matches!(
+            parser.parse_message("@PirateBot clear assignments for Jane Doe", None, &[]),
+            Some(ParsedIntent::ClearAssignments { .. })
+        ));
+    }
+
+    #[test]
+    fn test_scheduled_message_detection() {
+        let parser = create_parser();
+        match parser.parse_message("@PirateBot at 6 PM say: Practice moved to Field 2", None, &[]) {
+            Some(ParsedIntent::ScheduleMessage { date, time, text }) => {
+                assert_eq!(date, None);
+                assert_eq!(time, NaiveTime::from_hms_opt(18, 0, 0).unwrap());
+                assert_eq!(text, "Practice moved to Field 2");
+            }
+            other => panic!("Expected ScheduleMessage, got {:?}", other),
+        }
+        match parser.parse_message("@PirateBot 5/2 at 7:30am say: Pictures at 8:15, wear jerseys", None, &[]) {
+            Some(ParsedIntent::ScheduleMessage { date, time, text }) => {
+                assert_eq!(date.map(|d| d.format("%m/%d").to_string()).as_deref(), Some("05/02"));
+                assert_eq!(time, NaiveTime::from_hms_opt(7, 30, 0).unwrap());
+                assert_eq!(text, "Pictures at 8:15, wear jerseys");
+            }
+            other => panic!("Expected ScheduleMessage, got {:?}", other),
+        }
+        assert!(matches!(parser.parse_message("@PirateBot scheduled messages", None, &[]), Some(ParsedIntent::ListScheduledMessages)));
+        assert!(matches!(
+            parser.parse_message("@PirateBot cancel scheduled message #2", None, &[]),
+            Some(ParsedIntent::CancelScheduledMessage { id: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_absence_detection() {
+        let parser = create_parser();
+        match parser.parse_message("@PirateBot the Smiths are out June 1-14", None, &[]) {
+            Some(ParsedIntent::RecordAbsence { who, start, end }) => {
+                assert_eq!(who, "the Smiths");
+                assert_eq!(start.format("%m/%d").to_string(), "06/01");
+                assert_eq!(end.format("%m/%d").to_string(), "06/14");
+            }
+            other => panic!("Expected RecordAbsence, got {:?}", other),
+        }
+        match parser.parse_message("@PirateBot we're out of town 7/3 - 7/6", Some("Jane Doe"), &[]) {
+            Some(ParsedIntent::RecordAbsence { who, .. }) => assert_eq!(who, "Jane Doe"),
+            other => panic!("Expected RecordAbsence, got {:?}", other),
+        }
+        assert!(matches!(parser.parse_message("@PirateBot absences", None, &[]), Some(ParsedIntent::ListAbsences)));
+        assert!(matches!(parser.parse_message("@PirateBot remove absence 3", None, &[]), Some(ParsedIntent::RemoveAbsence { id: 3 })));
+        assert!(!matches!(parser.parse_message("@PirateBot Jake is out at second", None, &[]), Some(ParsedIntent::RecordAbsence { .. })));
+    }
+
+    #[test]
+    fn test_absence_dates_follow_the_parser_today() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let parser = create_parser().as_of(date(2025, 6, 20));
+        let range = |text: &str| match parser.parse_message(text, Some("Jane Doe"), &[]) {
+            Some(ParsedIntent::RecordAbsence { start, end, .. }) => (start, end),
+            other => panic!("Expected RecordAbsence, got {:?}", other),
+        };
+        // Still going on, so this year's
+        assert_eq!(range("@PirateBot I'm out 6/18 - 6/25"), (date(2025, 6, 18), date(2025, 6, 25)));
+        // Already over, so next year's
+        assert_eq!(range("@PirateBot the Smiths are out June 1-14"), (date(2026, 6, 1), date(2026, 6, 14)));
+        assert_eq!(range("@PirateBot we're away 12/28-1/3"), (date(2025, 12, 28), date(2026, 1, 3)));
+    }
+
+    #[test]
+    fn test_word_filter_detection() {
+        let parser = create_parser();
+        assert!(matches!(
+            parser.parse_message("@PirateBot block word \"Dirt Bag\"", None, &[]),
+            Some(ParsedIntent::BlockWord { word }) if word == "dirt bag"
+        ));
+        assert!(matches!(
+            parser.parse_message("@PirateBot unblock word jerk", None, &[]),
+            Some(ParsedIntent::UnblockWord { word }) if word == "jerk"
+        ));
+        assert!(matches!(parser.parse_message("@PirateBot blocked words", None, &[]), Some(ParsedIntent::ListBlockedWords)));
+    }
+
+    #[test]
+    fn test_special_event_detection() {
+        let parser = create_parser();
+        match parser.parse_message("@PirateBot add event Spring Pictures 5/9 at 9am at Hall Field", None, &[]) {
+            Some(ParsedIntent::AddSpecialEvent { title, date, time, location }) => {
+                assert_eq!(title, "Spring Pictures");
+                assert_eq!((date.month(), date.day()), (5, 9));
+                assert_eq!(time.as_deref(), Some("9:00 AM"));
+                assert_eq!(location.as_deref(), Some("Hall Field"));
+            }
+            other => panic!("expected AddSpecialEvent, got {:?}", other),
+        }
+        assert!(matches!(
+            parser.parse_message("@PirateBot add event Fundraiser night at Chipotle friday 5 pm", None, &[]),
+            Some(ParsedIntent::AddSpecialEvent { title, time: Some(time), location: Some(location), .. })
+                if title == "Fundraiser night" && time == "5:00 PM" && location == "Chipotle"
+        ));
+        assert!(matches!(parser.parse_message("@PirateBot events", None, &[]), Some(ParsedIntent::ListSpecialEvents)));
+        assert!(matches!(parser.parse_message("@PirateBot remove event #2", None, &[]), Some(ParsedIntent::RemoveSpecialEvent { id: 2 })));
+        assert!(matches!(
+            parser.parse_message("@PirateBot photo day reminder 2d: Picture day {when}, bring Forms!", None, &[]),
+            Some(ParsedIntent::SetEventReminder { kind, lead_hours: 48, text })
+                if kind == "picture_day" && text == "Picture day {when}, bring Forms!"
+        ));
+        assert!(matches!(
+            parser.parse_message("@PirateBot reset fundraiser reminders", None, &[]),
+            Some(ParsedIntent::ResetEventReminders { kind }) if kind == "fundraiser"
+        ));
+        assert!(!matches!(parser.parse_message("@PirateBot disable reminders", None, &[]), Some(ParsedIntent::ShowEventReminders { .. })));
+    }
+
+    #[test]
+    fn test_volunteer_analytics_detection() {
+        let parser = create_parser();
+        assert!(matches!(parser.parse_message("@PirateBot volunteer analytics", None, &[]), Some(ParsedIntent::VolunteerAnalytics)));
+        assert!(matches!(parser.parse_message("@PirateBot fill rate", None, &[]), Some(ParsedIntent::VolunteerAnalytics)));
+        assert!(matches!(parser.parse_message("@PirateBot what's new?", None, &[]), Some(ParsedIntent::Version)));
+    }
+
+    #[test]
+    fn test_game_mode_detection() {
+        let parser = create_parser();
+        assert!(matches!(parser.parse_message("@PirateBot game mode on", None, &[]), Some(ParsedIntent::SetGameMode { on: true })));
+        assert!(matches!(parser.parse_message("@PirateBot end game mode", None, &[]), Some(ParsedIntent::SetGameMode { on: false })));
+        assert!(matches!(parser.parse_message("@PirateBot game photos", None, &[]), Some(ParsedIntent::GamePhotos { date: None })));
+        assert!(matches!(parser.parse_message("@PirateBot photos from 5/3", None, &[]), Some(ParsedIntent::GamePhotos { date: Some(_) })));
+    }
+
+    #[test]
+    fn test_bracket_detection() {
+        use crate::tournament::Outcome;
+
+        let parser = create_parser();
+        match parser.parse_message("@PirateBot bracket game 3 6/14 3pm at Tournament Park vs Storm if we win game 2", None, &[]) {
+            Some(ParsedIntent::SetBracketGame { number, date, time, location, opponent, condition }) => {
+                assert_eq!(number, 3);
+                assert_eq!(date.map(|d| d.format("%m/%d").to_string()).as_deref(), Some("06/14"));
+                assert_eq!(time, "3:00 PM");
+                assert_eq!(location, "Tournament Park");
+                assert_eq!(opponent.as_deref(), Some("Storm"));
+                assert_eq!(condition, Some((2, Outcome::Won)));
+            }
+            other => panic!("Expected SetBracketGame, got {:?}", other),
+        }
+        assert!(matches!(
+            parser.parse_message("@PirateBot we lost bracket game 2", None, &[]),
+            Some(ParsedIntent::BracketResult { number: 2, outcome: Outcome::Lost })
+        ));
+        assert!(matches!(parser.parse_message("@PirateBot bracket", None, &[]), Some(ParsedIntent::ShowBracket)));
+    }
+
+    #[test]
+    fn test_tournament_logistics_detection() {
+        let parser = create_parser();
+        match parser.parse_message("@PirateBot tournament hotel: https://Hotels.example.com/Block", None, &[]) {
+            Some(ParsedIntent::SetTournamentLogistics { label, value }) => {
+                assert_eq!(label, "hotel");
+                assert_eq!(value.as_deref(), Some("https://Hotels.example.com/Block"));
+            }
+            other => panic!("Expected SetTournamentLogistics, got {:?}", other),
+        }
+        match parser.parse_message("@PirateBot tournament dates 6/13-15", None, &[]) {
+            Some(ParsedIntent::SetTournamentDates { start: Some(start), end: Some(end) }) => {
+                assert_eq!((start.month(), start.day()), (6, 13));
+                assert_eq!((end.month(), end.day()), (6, 15));
+            }
+            other => panic!("Expected SetTournamentDates, got {:?}", other),
+        }
+        assert!(matches!(parser.parse_message("@PirateBot tournament info", None, &[]), Some(ParsedIntent::TournamentInfo)));
+    }
 }
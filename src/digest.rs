@@ -0,0 +1,77 @@
+use chrono::{Datelike, NaiveDate, Timelike};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tokio::time::{sleep, Duration as TokioDuration};
+use tracing::{info, error};
+
+use crate::config::Config;
+use crate::moderators::ModeratorsStore;
+use crate::service::BotService;
+
+/// DMs the moderator digest to the admin and every moderator once a week
+pub struct DigestScheduler {
+    bot_service: BotService,
+    moderators_store: ModeratorsStore,
+    config: Config,
+    last_sent: RwLock<Option<NaiveDate>>,
+}
+
+impl DigestScheduler {
+    pub fn new(config: Config, bot_service: BotService, moderators_store: ModeratorsStore) -> Self {
+        Self {
+            bot_service,
+            moderators_store,
+            config,
+            last_sent: RwLock::new(None),
+        }
+    }
+
+    /// Start the weekly digest in the background; DMs need GROUPME_ACCESS_TOKEN
+    pub fn start(self: Arc<Self>) {
+        let Some(day) = self.config.digest_day else {
+            info!("Moderator digest disabled");
+            return;
+        };
+        if self.config.groupme_access_token.is_none() {
+            info!("Moderator digest disabled (no GROUPME_ACCESS_TOKEN for DMs)");
+            return;
+        }
+
+        tokio::spawn(async move {
+            info!("Moderator digest scheduled for {} at {}:00", day, self.config.digest_hour);
+
+            loop {
+                // Check every 10 minutes
+                sleep(TokioDuration::from_secs(600)).await;
+
+                if self.is_due().await {
+                    self.send_digest().await;
+                }
+            }
+        });
+    }
+
+    /// Due on the configured day at or after the configured hour, once per day
+    async fn is_due(&self) -> bool {
+        let now = self.config.now();
+        let today = now.date();
+        Some(now.weekday()) == self.config.digest_day
+            && now.hour() >= self.config.digest_hour
+            && *self.last_sent.read().await != Some(today)
+    }
+
+    async fn send_digest(&self) {
+        *self.last_sent.write().await = Some(self.config.today());
+
+        let digest = match self.bot_service.moderator_digest().await {
+            Ok(digest) => digest,
+            Err(e) => {
+                error!("Failed to build moderator digest: {}", e);
+                return;
+            }
+        };
+
+        self.bot_service.notify_moderators(&self.moderators_store, &digest, false).await;
+        info!("Sent weekly moderator digest");
+    }
+}
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::models::BotCommand;
+
 #[derive(Error, Debug)]
 pub enum BotError {
     #[error("HTTP request failed: {0}")]
@@ -28,6 +30,10 @@ pub enum BotError {
     
     #[error("{0}")]
     InvalidCommand(String),
+
+    /// The command can run, but only after the user replies "confirm" to `prompt`
+    #[error("{prompt}")]
+    ConfirmationRequired { prompt: String, command: Box<BotCommand> },
 }
 
 pub type Result<T> = std::result::Result<T, BotError>;
@@ -0,0 +1,182 @@
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::config::Config;
+
+const FEATURE_FLAGS_FILE: &str = "data/feature_flags.json";
+/// Override key for settings that apply to every group
+const ALL_GROUPS: &str = "*";
+
+/// Optional subsystems admins can switch on and off
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Feature {
+    Weather,
+    TeamFacts,
+    WittyResponses,
+    /// Signing up from context without mentioning the bot ("I'll do snacks")
+    NlpVolunteering,
+    Reminders,
+}
+
+impl Feature {
+    pub const ALL: [Feature; 5] = [
+        Feature::Weather,
+        Feature::TeamFacts,
+        Feature::WittyResponses,
+        Feature::NlpVolunteering,
+        Feature::Reminders,
+    ];
+
+    /// Name used in FEATURES_DISABLED and the data file
+    pub fn key(&self) -> &'static str {
+        match self {
+            Feature::Weather => "weather",
+            Feature::TeamFacts => "team_facts",
+            Feature::WittyResponses => "witty_responses",
+            Feature::NlpVolunteering => "nlp_volunteering",
+            Feature::Reminders => "reminders",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Feature::Weather => "weather",
+            Feature::TeamFacts => "team facts",
+            Feature::WittyResponses => "witty responses",
+            Feature::NlpVolunteering => "casual volunteering",
+            Feature::Reminders => "reminders",
+        }
+    }
+
+    /// "team facts", "team_facts", "facts", "jokes", ...
+    pub fn parse(text: &str) -> Option<Feature> {
+        let text = text.trim().trim_end_matches(|c: char| !c.is_alphanumeric()).to_lowercase().replace(['_', '-'], " ");
+        match text.as_str() {
+            "weather" | "forecast" | "forecasts" => Some(Feature::Weather),
+            "team facts" | "facts" | "fun facts" => Some(Feature::TeamFacts),
+            "witty responses" | "witty replies" | "witty" | "jokes" => Some(Feature::WittyResponses),
+            "nlp volunteering" | "casual volunteering" | "volunteering without mention" => Some(Feature::NlpVolunteering),
+            "reminders" | "game reminders" => Some(Feature::Reminders),
+            _ => None,
+        }
+    }
+}
+
+/// Feature switches: FEATURES_DISABLED sets the defaults, and admin commands store
+/// overrides per group (or for every group) in data/feature_flags.json
+#[derive(Clone)]
+pub struct FeatureFlags {
+    disabled_by_config: Arc<BTreeSet<Feature>>,
+    overrides: Arc<RwLock<BTreeMap<String, BTreeMap<Feature, bool>>>>,
+}
+
+impl Default for FeatureFlags {
+    fn default() -> Self {
+        Self::with_disabled(BTreeSet::new())
+    }
+}
+
+impl FeatureFlags {
+    pub fn new(config: &Config) -> Self {
+        let disabled = config.disabled_features
+            .iter()
+            .filter_map(|name| {
+                let feature = Feature::parse(name);
+                if feature.is_none() {
+                    tracing::warn!("Unknown feature in FEATURES_DISABLED: {}", name);
+                }
+                feature
+            })
+            .collect();
+        Self::with_disabled(disabled)
+    }
+
+    fn with_disabled(disabled_by_config: BTreeSet<Feature>) -> Self {
+        let overrides = std::fs::read_to_string(FEATURE_FLAGS_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self {
+            disabled_by_config: Arc::new(disabled_by_config),
+            overrides: Arc::new(RwLock::new(overrides)),
+        }
+    }
+
+    fn save(overrides: &BTreeMap<String, BTreeMap<Feature, bool>>) {
+        if let Err(e) = std::fs::create_dir_all("data") { tracing::error!("Failed to create data dir: {}", e); }
+        if let Err(e) = std::fs::write(FEATURE_FLAGS_FILE, serde_json::to_string_pretty(overrides).unwrap_or_default()) {
+            tracing::error!("Failed to save feature flags: {}", e);
+        }
+    }
+
+    /// The group's own setting wins, then the every-group setting, then the config default
+    pub async fn is_enabled(&self, feature: Feature, group_id: Option<&str>) -> bool {
+        let overrides = self.overrides.read().await;
+        group_id
+            .and_then(|group| overrides.get(group))
+            .and_then(|flags| flags.get(&feature))
+            .or_else(|| overrides.get(ALL_GROUPS).and_then(|flags| flags.get(&feature)))
+            .copied()
+            .unwrap_or(!self.disabled_by_config.contains(&feature))
+    }
+
+    /// Switch a feature for one group, or for every group when `group_id` is None.
+    /// Setting it for every group drops the per-group overrides so the change takes effect everywhere.
+    pub async fn set(&self, feature: Feature, group_id: Option<&str>, enabled: bool) {
+        let mut overrides = self.overrides.write().await;
+        match group_id {
+            Some(group) => {
+                overrides.entry(group.to_string()).or_default().insert(feature, enabled);
+            }
+            None => {
+                for flags in overrides.values_mut() {
+                    flags.remove(&feature);
+                }
+                overrides.entry(ALL_GROUPS.to_string()).or_default().insert(feature, enabled);
+            }
+        }
+        overrides.retain(|_, flags| !flags.is_empty());
+        Self::save(&overrides);
+    }
+
+    /// Every feature and whether it's on for the group
+    pub async fn summary(&self, group_id: Option<&str>) -> Vec<(Feature, bool)> {
+        let mut summary = Vec::new();
+        for feature in Feature::ALL {
+            summary.push((feature, self.is_enabled(feature, group_id).await));
+        }
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_override_precedence() {
+        let flags = FeatureFlags {
+            disabled_by_config: Arc::new([Feature::WittyResponses].into_iter().collect()),
+            overrides: Arc::new(RwLock::new(BTreeMap::new())),
+        };
+        assert!(!flags.is_enabled(Feature::WittyResponses, Some("g1")).await);
+        assert!(flags.is_enabled(Feature::Weather, Some("g1")).await);
+
+        {
+            // Flip without touching the data file
+            let mut overrides = flags.overrides.write().await;
+            overrides.entry(ALL_GROUPS.to_string()).or_default().insert(Feature::Weather, false);
+            overrides.entry("g1".to_string()).or_default().insert(Feature::Weather, true);
+        }
+        assert!(flags.is_enabled(Feature::Weather, Some("g1")).await);
+        assert!(!flags.is_enabled(Feature::Weather, Some("g2")).await);
+        assert!(!flags.is_enabled(Feature::Weather, None).await);
+
+        assert_eq!(Feature::parse("Team Facts"), Some(Feature::TeamFacts));
+        assert_eq!(Feature::parse("nlp_volunteering"), Some(Feature::NlpVolunteering));
+        assert_eq!(Feature::parse("snacks"), None);
+    }
+}
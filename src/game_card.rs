@@ -0,0 +1,88 @@
+use crate::config::Config;
+use crate::models::CorrelatedEvent;
+use crate::signup::{escape, SIGNUP_ROLES};
+
+/// Public link for a game's card page; `id` is a `calendar_feed::event_id`
+pub fn game_url(config: &Config, id: &str) -> Option<String> {
+    let base = config.public_base_url.as_ref()?;
+    Some(format!("{}/game/{}", base.trim_end_matches('/'), id))
+}
+
+/// Phone-sized page with everything a family needs for one game, for sharing outside GroupMe.
+/// The page is public and its id is just the date, so it never carries a signed signup link.
+pub fn render_game_card(team_name: &str, event: &CorrelatedEvent, calendar_url: Option<&str>) -> String {
+    let data = &event.data;
+    let mut body = format!(
+        "<h1>{}</h1>\n<p class=\"when\">{}<br>{}</p>\n",
+        escape(&event.format_matchup()),
+        event.event_date.format("%A, %B %-d"),
+        escape(if data.time.is_empty() { "Time TBD" } else { &data.time })
+    );
+
+    if data.location.is_empty() {
+        body.push_str("<p>Location TBD</p>\n");
+    } else {
+        body.push_str(&format!(
+            "<p><a href=\"https://maps.google.com/?q={}\">📍 {}</a></p>\n",
+            urlencoding::encode(&data.location),
+            escape(&data.location)
+        ));
+    }
+    if !data.home_team.is_empty() {
+        body.push_str(&format!("<p>🏠 {}</p>\n", escape(&data.home_team)));
+    }
+
+    body.push_str("<h2>Volunteers</h2>\n<ul>\n");
+    for (role, label) in SIGNUP_ROLES {
+        let person = data.get_field(role).map(|p| escape(p));
+        if person.is_none() && !data.is_role_available(role, team_name) {
+            continue;
+        }
+        body.push_str(&format!(
+            "<li>{}: {}</li>\n",
+            label,
+            person.unwrap_or_else(|| "<em>open</em>".to_string())
+        ));
+    }
+    body.push_str("</ul>\n");
+
+    if let Some(url) = calendar_url {
+        body.push_str(&format!("<p><a href=\"{}\">📅 Add to calendar</a></p>\n", escape(url)));
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\
+         <title>{} - {}</title>\
+         <style>body{{font-family:sans-serif;max-width:32em;margin:1em auto;padding:0 1em;font-size:1.2em}}\
+         .when{{font-size:1.3em}}a{{color:#036}}</style></head>\n<body>\n{}</body></html>\n",
+        escape(team_name),
+        event.event_date.format("%-m/%-d"),
+        body
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EventData;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_render_game_card() {
+        let date = NaiveDate::from_ymd_opt(2025, 5, 3).unwrap();
+        let mut data = EventData::new(date, "10:00 AM".to_string(), "Hall Field".to_string(), "Home".to_string(),
+            String::new(), String::new(), String::new(), String::new(), String::new());
+        data.assign_volunteer("snacks", "Sam & Alex");
+        let event = CorrelatedEvent { event_date: date, event_summary: "Pirates vs Chaos".to_string(), data };
+
+        let page = render_game_card("Pirates", &event, Some("https://bot.example.com/event/2025-05-03.ics"));
+        assert!(page.contains("Saturday, May 3"));
+        assert!(page.contains("https://maps.google.com/?q=Hall%20Field"));
+        assert!(page.contains("<li>Snacks: Sam &amp; Alex</li>"));
+        assert!(page.contains("<li>Livestream: <em>open</em></li>"));
+        // Home games don't need a scoreboard volunteer
+        assert!(!page.contains("Scoreboard"));
+        assert!(page.contains("Add to calendar"));
+        assert!(!page.contains("Volunteer</a>"));
+    }
+}
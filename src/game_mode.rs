@@ -0,0 +1,239 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tokio::time::{sleep, Duration as TokioDuration};
+use tracing::{info, warn};
+
+use crate::config::Config;
+use crate::service::BotService;
+
+const GAME_PHOTOS_FILE: &str = "data/game_photos.json";
+
+/// Words that make "4-2" a score rather than a pitch count or a date
+const SCORE_CUES: [&str; 9] = ["score", "up", "down", "lead", "leading", "trail", "trailing", "behind", "tied"];
+
+/// "4-2", "we're up 5-3", "down 2-4", "tied 3-3" as (our runs, their runs).
+/// A bare score puts us first; "down"/"behind" puts the bigger number on their side.
+pub fn parse_score_update(text: &str) -> Option<(u32, u32)> {
+    static SCORE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(\d{1,2})\s*-\s*(\d{1,2})\b").expect("valid score pattern"));
+
+    let lower = text.trim().to_lowercase();
+    let found = SCORE.captures(&lower)?;
+    let (a, b): (u32, u32) = (found[1].parse().ok()?, found[2].parse().ok()?);
+    // Youth games don't get past the 30s; anything bigger is something else
+    if a > 40 || b > 40 {
+        return None;
+    }
+
+    let rest = SCORE.replace(&lower, " ");
+    let words: Vec<&str> = rest.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()).collect();
+    if words.iter().any(|w| matches!(*w, "count" | "balls" | "strikes")) {
+        return None;
+    }
+    if !words.is_empty() && !words.iter().any(|w| SCORE_CUES.contains(w)) {
+        return None;
+    }
+
+    let behind = words.iter().any(|w| matches!(*w, "down" | "trail" | "trailing" | "behind"));
+    let ahead = words.iter().any(|w| matches!(*w, "up" | "lead" | "leading"));
+    Some(if (behind && a > b) || (ahead && a < b) { (b, a) } else { (a, b) })
+}
+
+/// The game being followed in game mode
+#[derive(Debug, Clone)]
+pub struct GameSession {
+    pub date: NaiveDate,
+    pub opponent: Option<String>,
+    pub ends_at: DateTime<Utc>,
+    pub inning: u32,
+    pub score: Option<(u32, u32)>,
+    pub photos: Vec<String>,
+    next_prompt_at: Option<DateTime<Utc>>,
+}
+
+impl GameSession {
+    /// "Pirates 4, Storm 2"
+    pub fn describe_score(&self, team_name: &str) -> Option<String> {
+        let (ours, theirs) = self.score?;
+        Some(format!("{} {}, {} {}", team_name, ours, self.opponent.as_deref().unwrap_or("Them"), theirs))
+    }
+}
+
+/// "@bot game mode on": while a game is on, score updates and photos don't need a mention,
+/// the bot asks for the score every few innings and signs off when the game should be over.
+/// Photos are kept per game date in data/game_photos.json.
+#[derive(Clone)]
+pub struct GameMode {
+    session: Arc<RwLock<Option<GameSession>>>,
+    albums: Arc<RwLock<BTreeMap<NaiveDate, Vec<String>>>>,
+}
+
+impl Default for GameMode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameMode {
+    pub fn new() -> Self {
+        let albums = std::fs::read_to_string(GAME_PHOTOS_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self { session: Arc::new(RwLock::new(None)), albums: Arc::new(RwLock::new(albums)) }
+    }
+
+    fn save(albums: &BTreeMap<NaiveDate, Vec<String>>) {
+        if let Err(e) = std::fs::create_dir_all("data") { tracing::error!("Failed to create data dir: {}", e); }
+        if let Err(e) = std::fs::write(GAME_PHOTOS_FILE, serde_json::to_string_pretty(albums).unwrap_or_default()) {
+            tracing::error!("Failed to save game photos: {}", e);
+        }
+    }
+
+    /// Start following a game; `prompt_every` of None means no score prompts
+    pub async fn start(&self, date: NaiveDate, opponent: Option<String>, ends_at: DateTime<Utc>, prompt_every: Option<Duration>) {
+        let photos = self.albums.read().await.get(&date).cloned().unwrap_or_default();
+        *self.session.write().await = Some(GameSession {
+            date,
+            opponent,
+            ends_at,
+            inning: 0,
+            score: None,
+            photos,
+            next_prompt_at: prompt_every.map(|every| Utc::now() + every),
+        });
+    }
+
+    /// Stop following the game, returning what was collected
+    pub async fn stop(&self) -> Option<GameSession> {
+        self.session.write().await.take()
+    }
+
+    pub async fn current(&self) -> Option<GameSession> {
+        self.session.read().await.clone()
+    }
+
+    pub async fn is_active(&self) -> bool {
+        self.session.read().await.is_some()
+    }
+
+    pub async fn record_score(&self, ours: u32, theirs: u32) -> Option<GameSession> {
+        let mut session = self.session.write().await;
+        let game = session.as_mut()?;
+        game.score = Some((ours, theirs));
+        Some(game.clone())
+    }
+
+    /// Add photos to the current game's album; returns how many it now holds
+    pub async fn add_photos(&self, urls: &[String]) -> Option<usize> {
+        let mut session = self.session.write().await;
+        let game = session.as_mut()?;
+        game.photos.extend(urls.iter().cloned());
+
+        let mut albums = self.albums.write().await;
+        albums.insert(game.date, game.photos.clone());
+        Self::save(&albums);
+        Some(game.photos.len())
+    }
+
+    /// Photos from a game, or from the most recent game with any when `date` is None
+    pub async fn photos(&self, date: Option<NaiveDate>) -> Option<(NaiveDate, Vec<String>)> {
+        let albums = self.albums.read().await;
+        match date {
+            Some(date) => albums.get(&date).map(|photos| (date, photos.clone())),
+            None => albums.iter().next_back().map(|(date, photos)| (*date, photos.clone())),
+        }
+    }
+
+    /// The inning to ask about when a score prompt is due, moving the next prompt along
+    async fn due_prompt(&self, now: DateTime<Utc>, every: Duration) -> Option<GameSession> {
+        let mut session = self.session.write().await;
+        let game = session.as_mut()?;
+        if game.next_prompt_at.is_none_or(|due| now < due) {
+            return None;
+        }
+        game.inning += 1;
+        game.next_prompt_at = Some(now + every);
+        Some(game.clone())
+    }
+}
+
+/// Posts the score prompts and ends game mode once the game is over
+pub struct GameModeTicker {
+    bot_service: BotService,
+    game_mode: GameMode,
+    config: Config,
+}
+
+impl GameModeTicker {
+    pub fn new(config: Config, bot_service: BotService, game_mode: GameMode) -> Self {
+        Self { bot_service, game_mode, config }
+    }
+
+    pub fn start(self: Arc<Self>) {
+        tokio::spawn(async move {
+            loop {
+                // Check every minute
+                sleep(TokioDuration::from_secs(60)).await;
+                self.tick().await;
+            }
+        });
+    }
+
+    async fn tick(&self) {
+        let Some(game) = self.game_mode.current().await else {
+            return;
+        };
+        let now = Utc::now();
+
+        if now >= game.ends_at {
+            info!("Game mode ended for {}", game.date);
+            if let Some(game) = self.game_mode.stop().await {
+                self.post(&self.bot_service.game_mode_summary(&game)).await;
+            }
+            return;
+        }
+
+        if self.config.inning_prompt_minutes == 0 {
+            return;
+        }
+        let every = Duration::minutes(self.config.inning_prompt_minutes as i64);
+        if let Some(game) = self.game_mode.due_prompt(now, every).await {
+            let mut prompt = format!("⚾ Around inning {}: what's the score? Just post it like \"4-2\" (us first).", game.inning);
+            if let Some(score) = game.describe_score(&self.config.team_name) {
+                prompt.push_str(&format!("\nLast update: {}", score));
+            }
+            self.post(&prompt).await;
+        }
+    }
+
+    async fn post(&self, message: &str) {
+        if let Err(e) = self.bot_service.send_response(message).await {
+            warn!("Failed to send game mode message: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_score_update() {
+        assert_eq!(parse_score_update("4-2"), Some((4, 2)));
+        assert_eq!(parse_score_update("We're up 5-3!"), Some((5, 3)));
+        assert_eq!(parse_score_update("up 3-5 after two"), Some((5, 3)));
+        assert_eq!(parse_score_update("down 4-2 in the 3rd"), Some((2, 4)));
+        assert_eq!(parse_score_update("tied 3-3"), Some((3, 3)));
+        assert_eq!(parse_score_update("score is 6 - 1"), Some((6, 1)));
+
+        // Chatter that happens to have numbers in it
+        assert_eq!(parse_score_update("he's 2-3 at the plate today"), None);
+        assert_eq!(parse_score_update("full count 3-2"), None);
+        assert_eq!(parse_score_update("up to bat, 3-2 count"), None);
+        assert_eq!(parse_score_update("game on 5-10"), None);
+    }
+}
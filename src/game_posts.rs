@@ -0,0 +1,95 @@
+use chrono::NaiveDate;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// How many of the bot's game posts to remember
+const REMEMBERED_POSTS: usize = 50;
+
+/// Raised-hand emoji that claim a spot when sent as a reply
+const HAND_RAISES: [char; 3] = ['🙋', '✋', '🖐'];
+
+/// "🙋", "✋🏽", "🙋‍♀️" - a reply made of nothing but raised hands
+pub fn is_hand_raise(text: &str) -> bool {
+    let text = text.trim();
+    !text.is_empty()
+        && text.chars().any(|c| HAND_RAISES.contains(&c))
+        && text.chars().all(|c| {
+            HAND_RAISES.contains(&c)
+                || c.is_whitespace()
+                // skin tones, gender signs, zero-width joiner and variation selectors
+                || ('\u{1F3FB}'..='\u{1F3FF}').contains(&c)
+                || ['\u{200D}', '\u{FE0F}', '♀', '♂'].contains(&c)
+        })
+}
+
+#[derive(Default)]
+struct Posts {
+    /// Texts about to be posted, waiting for GroupMe to echo them back with an id
+    pending: VecDeque<(String, NaiveDate)>,
+    /// Message id of a bot post and the game it's about
+    posted: VecDeque<(String, NaiveDate)>,
+}
+
+/// Ties the bot's own posts to the game they're about, so a 🙋 reply signs up for that game.
+/// Bot posts don't return an id, so the text is matched when the callback echoes it back.
+#[derive(Clone, Default)]
+pub struct GamePosts {
+    posts: Arc<Mutex<Posts>>,
+}
+
+impl GamePosts {
+    /// Call before posting `text` about the game on `date`
+    pub fn expect(&self, text: &str, date: NaiveDate) {
+        if let Ok(mut posts) = self.posts.lock() {
+            posts.pending.push_back((text.trim().to_string(), date));
+            while posts.pending.len() > REMEMBERED_POSTS {
+                posts.pending.pop_front();
+            }
+        }
+    }
+
+    /// A bot post came back through the callback; keep its id if it's one we expected
+    pub fn on_bot_post(&self, message_id: &str, text: &str) {
+        let Ok(mut posts) = self.posts.lock() else {
+            return;
+        };
+        let text = text.trim();
+        if let Some(index) = posts.pending.iter().position(|(pending, _)| pending == text) {
+            if let Some((_, date)) = posts.pending.remove(index) {
+                posts.posted.push_back((message_id.to_string(), date));
+                while posts.posted.len() > REMEMBERED_POSTS {
+                    posts.posted.pop_front();
+                }
+            }
+        }
+    }
+
+    /// The game a bot post is about
+    pub fn game_for(&self, message_id: &str) -> Option<NaiveDate> {
+        let posts = self.posts.lock().ok()?;
+        posts.posted.iter().find(|(id, _)| id == message_id).map(|(_, date)| *date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_post_tracking() {
+        let posts = GamePosts::default();
+        let date = NaiveDate::from_ymd_opt(2025, 5, 3).unwrap();
+        posts.expect("⏰ Game Reminder!\n⚠️ Still needed: snacks\n", date);
+        posts.on_bot_post("m1", "🏴‍☠️ unrelated post");
+        posts.on_bot_post("m2", "⏰ Game Reminder!\n⚠️ Still needed: snacks");
+
+        assert_eq!(posts.game_for("m2"), Some(date));
+        assert_eq!(posts.game_for("m1"), None);
+
+        assert!(is_hand_raise("🙋"));
+        assert!(is_hand_raise(" ✋🏽 "));
+        assert!(is_hand_raise("🙋\u{200D}♀\u{FE0F}"));
+        assert!(!is_hand_raise("🙋 snacks"));
+        assert!(!is_hand_raise("👍"));
+    }
+}
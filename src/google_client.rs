@@ -13,6 +13,8 @@ pub struct GoogleClient {
     client: Client,
     config: Config,
     service_auth: Option<Arc<Mutex<ServiceAccountAuth>>>,
+    /// Rows the last sheet read had to skip, e.g. "row 7: date '5/3' isn't YYYY-MM-DD"
+    parse_warnings: Arc<std::sync::RwLock<Vec<String>>>,
 }
 
 impl GoogleClient {
@@ -37,21 +39,75 @@ impl GoogleClient {
             client: Client::new(),
             config,
             service_auth,
+            parse_warnings: Arc::new(std::sync::RwLock::new(Vec::new())),
         }
     }
 
-    pub async fn get_sheets_data(&self) -> Result<Vec<(NaiveDate, String, String, String, String, String, String, String, String)>> {
+    pub async fn get_sheets_data(&self) -> Result<Vec<(NaiveDate, String, String, String, String, String, String, String, String, String)>> {
+        let values = self.get_range_values("A2:J").await?;
+
+        info!("Sheet data retrieved: {} rows", values.len());
+
+        // Common parsing logic for both methods
+        let mut parsed_data = Vec::new();
+        let mut warnings = Vec::new();
+        
+        for (row_idx, row) in values.iter().enumerate() {
+            if row.len() < 4 && row.first().is_some_and(|date| !date.trim().is_empty()) {
+                warnings.push(format!("row {}: only {} column(s) filled in", row_idx + 2, row.len()));
+            }
+            if row.len() >= 4 && !row[0].trim().is_empty() {
+                match NaiveDate::parse_from_str(&row[0], "%Y-%m-%d") {
+                    Ok(date) => {
+                        let time = row.get(1).cloned().unwrap_or_default();
+                        let location = row.get(2).cloned().unwrap_or_default();
+                        let home_team = row.get(3).cloned().unwrap_or_default();
+                        let snacks = row.get(4).cloned().unwrap_or_default();
+                        let livestream = row.get(5).cloned().unwrap_or_default();
+                        let scoreboard = row.get(6).cloned().unwrap_or_default();
+                        let pitch_count = row.get(7).cloned().unwrap_or_default();
+                        let gamechanger = row.get(8).cloned().unwrap_or_default();
+                        let notes = row.get(9).cloned().unwrap_or_default();
+                        
+                        parsed_data.push((date, time, location, home_team, snacks, livestream, scoreboard, pitch_count, gamechanger, notes));
+                    }
+                    Err(e) => {
+                        warn!("Failed to parse date in row {}: {} - {}", row_idx + 2, row[0], e);
+                        warnings.push(format!("row {}: date '{}' isn't YYYY-MM-DD", row_idx + 2, row[0]));
+                    }
+                }
+            }
+        }
+        
+        parsed_data.sort_by(|a, b| a.0.cmp(&b.0));
+        if let Ok(mut last) = self.parse_warnings.write() {
+            *last = warnings;
+        }
+        
+        info!("Parsed {} sheet rows", parsed_data.len());
+        Ok(parsed_data)
+    }
+
+    /// Rows skipped by the most recent get_sheets_data call
+    pub fn parse_warnings(&self) -> Vec<String> {
+        self.parse_warnings.read().map(|w| w.clone()).unwrap_or_default()
+    }
+
+    /// Read the raw cell values of an A1 range (e.g. "Standings!A2:E") from the configured spreadsheet
+    pub async fn get_range_values(&self, range: &str) -> Result<Vec<Vec<String>>> {
         let sheets_response: crate::models::SheetsResponse = if let Some(service_auth) = &self.service_auth {
             // Use service account authentication
-            let mut auth = service_auth.lock().await;
-            let access_token = auth.get_access_token().await?;
+            // The lock is only held for the token, so sheet requests don't queue behind each other
+            let access_token = service_auth.lock().await.get_access_token().await?;
             
             let url = format!(
-                "https://sheets.googleapis.com/v4/spreadsheets/{}/values/A2:I",
-                &self.config.sheet_id
+                "{}/spreadsheets/{}/values/{}",
+                &self.config.sheets_api_url,
+                &self.config.sheet_id,
+                urlencoding::encode(range)
             );
 
-            info!("Fetching sheet data from Google Sheets API (using service account)");
+            info!("Fetching {} from Google Sheets API (using service account)", range);
 
             let response = self.client
                 .get(&url)
@@ -70,12 +126,14 @@ impl GoogleClient {
         } else {
             // Fallback to API key method
             let url = format!(
-                "https://sheets.googleapis.com/v4/spreadsheets/{}/values/A2:I?key={}",
+                "{}/spreadsheets/{}/values/{}?key={}",
+                &self.config.sheets_api_url,
                 &self.config.sheet_id,
+                urlencoding::encode(range),
                 &self.config.google_api_key
             );
 
-            info!("Fetching sheet data from Google Sheets API (using API key)");
+            info!("Fetching {} from Google Sheets API (using API key)", range);
 
             let response = self.client
                 .get(&url)
@@ -92,39 +150,7 @@ impl GoogleClient {
             response.json().await?
         };
 
-        info!("Sheet data retrieved: {} rows", 
-            sheets_response.values.as_ref().map(|v| v.len()).unwrap_or(0));
-
-        // Common parsing logic for both methods
-        let values = sheets_response.values.unwrap_or_default();
-        let mut parsed_data = Vec::new();
-        
-        for (row_idx, row) in values.iter().enumerate() {
-            if row.len() >= 4 && !row[0].trim().is_empty() {
-                match NaiveDate::parse_from_str(&row[0], "%Y-%m-%d") {
-                    Ok(date) => {
-                        let time = row.get(1).cloned().unwrap_or_default();
-                        let location = row.get(2).cloned().unwrap_or_default();
-                        let home_team = row.get(3).cloned().unwrap_or_default();
-                        let snacks = row.get(4).cloned().unwrap_or_default();
-                        let livestream = row.get(5).cloned().unwrap_or_default();
-                        let scoreboard = row.get(6).cloned().unwrap_or_default();
-                        let pitch_count = row.get(7).cloned().unwrap_or_default();
-                        let gamechanger = row.get(8).cloned().unwrap_or_default();
-                        
-                        parsed_data.push((date, time, location, home_team, snacks, livestream, scoreboard, pitch_count, gamechanger));
-                    }
-                    Err(e) => {
-                        warn!("Failed to parse date in row {}: {} - {}", row_idx + 2, row[0], e);
-                    }
-                }
-            }
-        }
-        
-        parsed_data.sort_by(|a, b| a.0.cmp(&b.0));
-        
-        info!("Parsed {} sheet rows", parsed_data.len());
-        Ok(parsed_data)
+        Ok(sheets_response.values.unwrap_or_default())
     }
 
     /// Update a specific cell in the Google Sheet
@@ -133,11 +159,11 @@ impl GoogleClient {
         
         if let Some(service_auth) = &self.service_auth {
             // Use service account authentication
-            let mut auth = service_auth.lock().await;
-            let access_token = auth.get_access_token().await?;
+            let access_token = service_auth.lock().await.get_access_token().await?;
             
             let url = format!(
-                "https://sheets.googleapis.com/v4/spreadsheets/{}/values/{}?valueInputOption=RAW",
+                "{}/spreadsheets/{}/values/{}?valueInputOption=RAW",
+                &self.config.sheets_api_url,
                 &self.config.sheet_id,
                 urlencoding::encode(&range)
             );
@@ -170,11 +196,46 @@ impl GoogleClient {
             Err(BotError::GoogleApi("Write operations require service account authentication".to_string()))
         }
     }
+    /// Append rows after the last filled row of the schedule in a single request
+    pub async fn append_rows(&self, rows: &[Vec<String>]) -> Result<()> {
+        let Some(service_auth) = &self.service_auth else {
+            warn!("Write operation attempted with API key - requires service account");
+            return Err(BotError::GoogleApi("Write operations require service account authentication".to_string()));
+        };
+        let access_token = service_auth.lock().await.get_access_token().await?;
+
+        let url = format!(
+            "{}/spreadsheets/{}/values/{}:append?valueInputOption=RAW&insertDataOption=INSERT_ROWS",
+            &self.config.sheets_api_url,
+            &self.config.sheet_id,
+            urlencoding::encode("A:J")
+        );
+
+        info!("Appending {} rows to the sheet (using service account)", rows.len());
+
+        let response = self.client
+            .post(&url)
+            .bearer_auth(access_token)
+            .json(&serde_json::json!({ "values": rows }))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            error!("Sheet append failed: {} - {}", status, error_text);
+            return Err(BotError::GoogleApi(format!("Sheet append returned {}: {}", status, error_text)));
+        }
+
+        info!("Successfully appended {} rows", rows.len());
+        Ok(())
+    }
+
     /// Find the row number for a specific date in the sheet
     pub async fn find_sheet_row_by_date(&self, target_date: chrono::NaiveDate) -> Result<Option<usize>> {
         let sheets_data = self.get_sheets_data().await?;
         
-        for (index, (date, _title, _location, _home_team, _snacks, _livestream, _scoreboard, _pitch_count, _gamechanger)) in sheets_data.iter().enumerate() {
+        for (index, (date, _title, _location, _home_team, _snacks, _livestream, _scoreboard, _pitch_count, _gamechanger, _notes)) in sheets_data.iter().enumerate() {
             if *date == target_date {
                 // Row numbers are 1-indexed, and we start from row 2 (header is row 1)
                 return Ok(Some(index + 2));
@@ -189,15 +250,74 @@ impl GoogleClient {
         let row = self.find_sheet_row_by_date(date).await?
             .ok_or_else(|| BotError::InvalidCommand(format!("No event found for {}", date)))?;
             
-        let column = match role.to_lowercase().as_str() {
-            "snacks" => "E",
-            "livestream" => "F", 
-            "scoreboard" => "G",
-            "pitchcount" | "pitch_count" => "H",
-            "gamechanger" => "I",
-            _ => return Err(BotError::InvalidCommand(format!("Invalid volunteer role: {}", role))),
-        };
+        let column = Self::volunteer_column(role)?;
         
         self.update_sheet_cell(row, column, person).await
     }
+
+    /// Sheet column holding a volunteer role
+    fn volunteer_column(role: &str) -> Result<&'static str> {
+        match role.to_lowercase().as_str() {
+            "snacks" => Ok("E"),
+            "livestream" => Ok("F"),
+            "scoreboard" => Ok("G"),
+            "pitchcount" | "pitch_count" => Ok("H"),
+            "gamechanger" => Ok("I"),
+            _ => Err(BotError::InvalidCommand(format!("Invalid volunteer role: {}", role))),
+        }
+    }
+
+    /// Set several volunteer roles for one game in a single request; an empty name clears the role
+    pub async fn update_volunteer_assignments(&self, date: chrono::NaiveDate, changes: &[(&str, &str)]) -> Result<()> {
+        let Some(service_auth) = &self.service_auth else {
+            warn!("Write operation attempted with API key - requires service account");
+            return Err(BotError::GoogleApi("Write operations require service account authentication".to_string()));
+        };
+        let row = self.find_sheet_row_by_date(date).await?
+            .ok_or_else(|| BotError::InvalidCommand(format!("No event found for {}", date)))?;
+
+        let mut data = Vec::new();
+        for (role, person) in changes {
+            let column = Self::volunteer_column(role)?;
+            data.push(serde_json::json!({
+                "range": format!("{}{}", column, row),
+                "values": [[person]]
+            }));
+        }
+
+        let access_token = service_auth.lock().await.get_access_token().await?;
+
+        let url = format!(
+            "{}/spreadsheets/{}/values:batchUpdate",
+            &self.config.sheets_api_url,
+            &self.config.sheet_id
+        );
+
+        info!("Updating {} volunteer cells in row {} (using service account)", data.len(), row);
+
+        let response = self.client
+            .post(&url)
+            .bearer_auth(access_token)
+            .json(&serde_json::json!({ "valueInputOption": "RAW", "data": data }))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            error!("Sheet batch update failed: {} - {}", status, error_text);
+            return Err(BotError::GoogleApi(format!("Sheet batch update returned {}: {}", status, error_text)));
+        }
+
+        info!("Successfully updated {} volunteer cells", data.len());
+        Ok(())
+    }
+
+    /// Update the notes column (J) for a game; an empty string clears it
+    pub async fn update_game_notes(&self, date: chrono::NaiveDate, notes: &str) -> Result<()> {
+        let row = self.find_sheet_row_by_date(date).await?
+            .ok_or_else(|| BotError::InvalidCommand(format!("No event found for {}", date)))?;
+
+        self.update_sheet_cell(row, "J", notes).await
+    }
 }
@@ -0,0 +1,107 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::config::Config;
+use crate::team_facts::TeamFactsProvider;
+
+/// One GROUP_PROFILES_FILE entry; anything left out comes from the global config
+#[derive(Debug, Clone, Default, Deserialize)]
+struct ProfileSpec {
+    team_name: Option<String>,
+    team_emoji: Option<String>,
+    team_facts_file: Option<String>,
+}
+
+/// How the bot presents itself in one group: team name, emoji and facts
+pub struct GroupProfile {
+    pub team_name: String,
+    pub team_emoji: String,
+    pub team_facts: TeamFactsProvider,
+}
+
+impl GroupProfile {
+    /// `fallback` has every field set, from the global config
+    fn from_spec(spec: &ProfileSpec, fallback: &ProfileSpec) -> Self {
+        let team_name = spec.team_name.clone().or_else(|| fallback.team_name.clone()).unwrap_or_default();
+        let team_emoji = spec.team_emoji.clone().or_else(|| fallback.team_emoji.clone()).unwrap_or_default();
+        let facts_file = spec.team_facts_file.clone().or_else(|| fallback.team_facts_file.clone());
+        Self {
+            team_facts: TeamFactsProvider::new(team_name.clone(), team_emoji.clone(), facts_file),
+            team_name,
+            team_emoji,
+        }
+    }
+}
+
+/// Per-group profiles keyed by GroupMe group id, with the global config as the fallback.
+/// The sheet is still read with the global TEAM_NAME; profiles only change how replies look.
+#[derive(Clone)]
+pub struct GroupProfiles {
+    default: Arc<GroupProfile>,
+    by_group: Arc<HashMap<String, Arc<GroupProfile>>>,
+}
+
+impl GroupProfiles {
+    pub fn new(config: &Config) -> Self {
+        let specs: HashMap<String, ProfileSpec> = config.group_profiles_file
+            .as_ref()
+            .and_then(|path| match std::fs::read_to_string(path) {
+                Ok(content) => serde_json::from_str(&content)
+                    .map_err(|e| tracing::error!("Invalid group profiles file {}: {}", path, e))
+                    .ok(),
+                Err(e) => {
+                    tracing::error!("Failed to read group profiles file {}: {}", path, e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+        let fallback = ProfileSpec {
+            team_name: Some(config.team_name.clone()),
+            team_emoji: Some(config.team_emoji.clone()),
+            team_facts_file: config.team_facts_file.clone(),
+        };
+        Self::from_specs(fallback, specs)
+    }
+
+    fn from_specs(fallback: ProfileSpec, specs: HashMap<String, ProfileSpec>) -> Self {
+        let by_group = specs
+            .into_iter()
+            .map(|(group_id, spec)| (group_id, Arc::new(GroupProfile::from_spec(&spec, &fallback))))
+            .collect();
+        Self {
+            default: Arc::new(GroupProfile::from_spec(&fallback, &fallback)),
+            by_group: Arc::new(by_group),
+        }
+    }
+
+    /// The profile for a group, or the global one for unknown groups and messages without a group
+    pub fn resolve(&self, group_id: Option<&str>) -> &GroupProfile {
+        group_id
+            .and_then(|group| self.by_group.get(group))
+            .unwrap_or(&self.default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_falls_back_to_config() {
+        let fallback = ProfileSpec {
+            team_name: Some("Pirates".to_string()),
+            team_emoji: Some("🏴‍☠️".to_string()),
+            team_facts_file: None,
+        };
+        let specs = serde_json::from_str(r#"{"g2": {"team_name": "Dragons", "team_emoji": "🐉"}, "g3": {"team_emoji": "🔥"}}"#).unwrap();
+        let profiles = GroupProfiles::from_specs(fallback, specs);
+
+        assert_eq!(profiles.resolve(Some("g2")).team_name, "Dragons");
+        assert_eq!(profiles.resolve(Some("g2")).team_facts.cheer(), "🐉 Let's go team! ⚾");
+        assert_eq!(profiles.resolve(Some("g3")).team_name, "Pirates");
+        assert_eq!(profiles.resolve(Some("g3")).team_emoji, "🔥");
+        assert_eq!(profiles.resolve(Some("unknown")).team_emoji, "🏴‍☠️");
+        assert_eq!(profiles.resolve(None).team_name, "Pirates");
+    }
+}
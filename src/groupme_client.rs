@@ -3,7 +3,17 @@ use tracing::{info, error};
 
 use crate::config::Config;
 use crate::error::{BotError, Result};
-use crate::models::{GroupMePostMessage, GroupMeMessageInfo};
+use crate::models::{BotRegistration, GroupMePostMessage, GroupMeMessageInfo, GroupMember};
+
+/// Split a GroupMe image service URL on the last line of `message` off into an image attachment
+fn split_image(message: &str) -> (&str, Vec<serde_json::Value>) {
+    match message.rsplit_once('\n') {
+        Some((text, last)) if last.trim().starts_with("https://i.groupme.com/") => {
+            (text, vec![serde_json::json!({ "type": "image", "url": last.trim() })])
+        }
+        _ => (message, Vec::new()),
+    }
+}
 
 #[derive(Clone)]
 pub struct GroupMeClient {
@@ -20,17 +30,25 @@ impl GroupMeClient {
     }
 
     pub async fn send_message(&self, message: &str) -> Result<()> {
-        let url = "https://api.groupme.com/v3/bots/post";
-        
+        self.send_message_as(&self.config.groupme_bot_id, message).await
+    }
+
+    /// Post as a specific bot, for messages that arrived on another group's callback route
+    /// A GroupMe image service URL on the last line of `message` is sent as an image attachment
+    pub async fn send_message_as(&self, bot_id: &str, message: &str) -> Result<()> {
+        let url = format!("{}/bots/post", self.config.groupme_api_url);
+
+        let (text, attachments) = split_image(message);
         let payload = GroupMePostMessage {
-            bot_id: self.config.groupme_bot_id.clone(),
-            text: message.to_string(),
+            bot_id: bot_id.to_string(),
+            text: text.to_string(),
+            attachments,
         };
 
         info!("Sending message to GroupMe: '{}'", message);
 
         let response = self.client
-            .post(url)
+            .post(&url)
             .json(&payload)
             .send()
             .await?;
@@ -54,8 +72,8 @@ impl GroupMeClient {
             .ok_or_else(|| BotError::Config("GROUPME_GROUP_ID not configured".to_string()))?;
 
         let mut url = format!(
-            "https://api.groupme.com/v3/groups/{}/messages?token={}&limit={}",
-            group_id, access_token, limit
+            "{}/groups/{}/messages?token={}&limit={}",
+            self.config.groupme_api_url, group_id, access_token, limit
         );
 
         if let Some(before) = before_id {
@@ -89,4 +107,220 @@ impl GroupMeClient {
             Err(BotError::GroupMeApi(format!("GroupMe API returned {}: {}", status, error_text)))
         }
     }
+
+    /// List the current members of the group (requires access token and group ID)
+    pub async fn list_members(&self) -> Result<Vec<GroupMember>> {
+        let access_token = self.config.groupme_access_token.as_ref()
+            .ok_or_else(|| BotError::Config("GROUPME_ACCESS_TOKEN not configured".to_string()))?;
+        let group_id = self.config.groupme_group_id.as_ref()
+            .ok_or_else(|| BotError::Config("GROUPME_GROUP_ID not configured".to_string()))?;
+
+        let url = format!(
+            "{}/groups/{}?token={}",
+            self.config.groupme_api_url, group_id, access_token
+        );
+
+        info!("Fetching group members from GroupMe");
+
+        let response = self.client
+            .get(&url)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            #[derive(serde::Deserialize)]
+            struct GroupResponse {
+                response: GroupData,
+            }
+            #[derive(serde::Deserialize)]
+            struct GroupData {
+                #[serde(default)]
+                members: Vec<GroupMember>,
+            }
+
+            let data: GroupResponse = response.json().await?;
+            info!("Fetched {} group members", data.response.members.len());
+            Ok(data.response.members)
+        } else {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            error!("Failed to fetch group members. Status: {} - {}", status, error_text);
+            Err(BotError::GroupMeApi(format!("GroupMe API returned {}: {}", status, error_text)))
+        }
+    }
+
+    /// Send a direct message from the access token's user (GroupMe bots can't DM).
+    /// An image service URL on the last line is attached the same way as in group posts.
+    pub async fn send_direct_message(&self, recipient_id: &str, message: &str) -> Result<()> {
+        let (text, attachments) = split_image(message);
+        let url = format!("{}/direct_messages?token={}", self.config.groupme_api_url, self.access_token()?);
+        let source_guid: String = rand::Rng::sample_iter(rand::thread_rng(), &rand::distributions::Alphanumeric)
+            .take(32)
+            .map(char::from)
+            .collect();
+
+        info!("Sending direct message to {}", recipient_id);
+
+        let response = self.client
+            .post(&url)
+            .json(&serde_json::json!({
+                "direct_message": {
+                    "source_guid": source_guid,
+                    "recipient_id": recipient_id,
+                    "text": text,
+                    "attachments": attachments,
+                }
+            }))
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            error!("Failed to send direct message. Status: {} - {}", status, error_text);
+            Err(BotError::GroupMeApi(format!("GroupMe API returned {}: {}", status, error_text)))
+        }
+    }
+
+    /// Upload a PNG to GroupMe's image service and return its https://i.groupme.com/... URL
+    pub async fn upload_image(&self, png: Vec<u8>) -> Result<String> {
+        let response = self.client
+            .post("https://image.groupme.com/pictures")
+            .header("X-Access-Token", self.access_token()?.as_str())
+            .header("Content-Type", "image/png")
+            .body(png)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            let data: serde_json::Value = response.json().await?;
+            data["payload"]["picture_url"]
+                .as_str()
+                .map(|url| url.to_string())
+                .ok_or_else(|| BotError::GroupMeApi("Image service response had no picture_url".to_string()))
+        } else {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            error!("Failed to upload image to GroupMe. Status: {} - {}", status, error_text);
+            Err(BotError::GroupMeApi(format!("GroupMe image service returned {}: {}", status, error_text)))
+        }
+    }
+
+    /// User id of the access token's owner
+    pub async fn token_owner_id(&self) -> Result<String> {
+        let url = format!("{}/users/me?token={}", self.config.groupme_api_url, self.access_token()?);
+
+        let response = self.client
+            .get(&url)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            #[derive(serde::Deserialize)]
+            struct MeResponse {
+                response: Me,
+            }
+            #[derive(serde::Deserialize)]
+            struct Me {
+                id: String,
+            }
+
+            let data: MeResponse = response.json().await?;
+            Ok(data.response.id)
+        } else {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            error!("Failed to look up the token owner. Status: {} - {}", status, error_text);
+            Err(BotError::GroupMeApi(format!("GroupMe API returned {}: {}", status, error_text)))
+        }
+    }
+
+    fn access_token(&self) -> Result<&String> {
+        self.config.groupme_access_token.as_ref()
+            .ok_or_else(|| BotError::Config("GROUPME_ACCESS_TOKEN not configured".to_string()))
+    }
+
+    /// List the bots owned by the access token's user
+    pub async fn list_bots(&self) -> Result<Vec<BotRegistration>> {
+        let url = format!("{}/bots?token={}", self.config.groupme_api_url, self.access_token()?);
+
+        info!("Fetching bot registrations from GroupMe");
+
+        let response = self.client
+            .get(&url)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            #[derive(serde::Deserialize)]
+            struct BotsResponse {
+                response: Vec<BotRegistration>,
+            }
+
+            let data: BotsResponse = response.json().await?;
+            Ok(data.response)
+        } else {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            error!("Failed to list bots. Status: {} - {}", status, error_text);
+            Err(BotError::GroupMeApi(format!("GroupMe API returned {}: {}", status, error_text)))
+        }
+    }
+
+    /// Register a new bot in a group; the returned registration carries the new bot_id
+    pub async fn create_bot(&self, bot: &BotRegistration) -> Result<BotRegistration> {
+        let url = format!("{}/bots?token={}", self.config.groupme_api_url, self.access_token()?);
+
+        info!("Creating GroupMe bot '{}' in group {}", bot.name, bot.group_id);
+
+        let response = self.client
+            .post(&url)
+            .json(&serde_json::json!({ "bot": bot }))
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            #[derive(serde::Deserialize)]
+            struct CreateResponse {
+                response: CreatedBot,
+            }
+            #[derive(serde::Deserialize)]
+            struct CreatedBot {
+                bot: BotRegistration,
+            }
+
+            let data: CreateResponse = response.json().await?;
+            Ok(data.response.bot)
+        } else {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            error!("Failed to create bot. Status: {} - {}", status, error_text);
+            Err(BotError::GroupMeApi(format!("GroupMe API returned {}: {}", status, error_text)))
+        }
+    }
+
+    /// Change an existing bot's name, callback URL or avatar.
+    /// This is the endpoint the dev.groupme.com "Edit bot" form uses.
+    pub async fn update_bot(&self, bot: &BotRegistration) -> Result<()> {
+        let url = format!("{}/bots/update?token={}", self.config.groupme_api_url, self.access_token()?);
+
+        info!("Updating GroupMe bot '{}'", bot.name);
+
+        let response = self.client
+            .post(&url)
+            .json(&serde_json::json!({ "bot": bot }))
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            error!("Failed to update bot. Status: {} - {}", status, error_text);
+            Err(BotError::GroupMeApi(format!("GroupMe API returned {}: {}", status, error_text)))
+        }
+    }
 }
@@ -11,4 +11,40 @@ pub mod conversational_parser;
 pub mod reminder;
 pub mod conversation_context;
 pub mod moderators;
+pub mod members;
+pub mod bring_list;
+pub mod results;
+pub mod stats;
+pub mod routes;
+pub mod registration;
+pub mod monitor;
+pub mod digest;
+pub mod schedule_import;
+pub mod conflicts;
+pub mod venues;
+pub mod tournament;
+pub mod signup;
+pub mod calendar_feed;
+pub mod qr;
 pub mod team_facts;
+pub mod game_card;
+pub mod outbound_webhooks;
+pub mod plugins;
+pub mod features;
+pub mod group_profiles;
+pub mod watchdog;
+pub mod synonyms;
+pub mod mentions;
+pub mod game_mode;
+pub mod game_posts;
+pub mod raster;
+pub mod scheduled_messages;
+pub mod volunteer_grid;
+pub mod weather_chart;
+pub mod absences;
+pub mod content_filter;
+pub mod volunteer_metrics;
+pub mod relative_dates;
+pub mod special_events;
+pub mod migrations;
+pub mod version;
@@ -14,18 +14,57 @@ pub mod conversational_parser;
 pub mod reminder;
 pub mod conversation_context;
 pub mod moderators;
+pub mod members;
+pub mod bring_list;
+pub mod results;
+pub mod stats;
+pub mod routes;
+pub mod registration;
+pub mod monitor;
+pub mod digest;
+pub mod schedule_import;
+pub mod conflicts;
+pub mod venues;
+pub mod tournament;
+pub mod signup;
+pub mod calendar_feed;
+pub mod qr;
 pub mod team_facts;
+pub mod game_card;
+pub mod outbound_webhooks;
+pub mod plugins;
+pub mod features;
+pub mod group_profiles;
+pub mod watchdog;
+pub mod synonyms;
+pub mod mentions;
+pub mod game_mode;
+pub mod game_posts;
+pub mod raster;
+pub mod scheduled_messages;
+pub mod volunteer_grid;
+pub mod weather_chart;
+pub mod absences;
+pub mod content_filter;
+pub mod volunteer_metrics;
+pub mod relative_dates;
+pub mod special_events;
+pub mod migrations;
+pub mod version;
 
-use actix_web::{get, post, web, App, HttpResponse, HttpServer, Responder};
-use tracing::{info, error, warn};
-use tracing_actix_web::TracingLogger;
+use actix_web::{get, post, web, App, HttpRequest, HttpResponse, HttpServer, Responder};
+use tracing::{info, error, warn, Instrument};
+use tracing_actix_web::{RequestId, TracingLogger};
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 use crate::config::Config;
+use crate::error::BotError;
 use crate::service::BotService;
 use crate::parser::CommandParser;
 use crate::models::GroupMeMessage;
 use crate::reminder::ReminderScheduler;
+use crate::routes::CallbackRoute;
 
 // Application state
 struct AppState {
@@ -33,10 +72,44 @@ struct AppState {
     command_parser: CommandParser,
     moderators_store: moderators::ModeratorsStore,
     config: config::Config,
+    routes: Vec<CallbackRoute>,
+    reminder_heartbeat: watchdog::Heartbeat,
+    /// Ids of recently handled messages, so a callback GroupMe delivers twice is only acted on once
+    recent_message_ids: std::sync::Mutex<VecDeque<String>>,
 }
 
-#[post("/webhook")]
-async fn webhook(req_body: String, data: web::Data<AppState>) -> impl Responder {
+/// How many handled message ids are remembered for spotting repeat deliveries
+const REMEMBERED_DELIVERIES: usize = 500;
+
+impl AppState {
+    /// False if this message id was already handled; messages without an id are always new
+    fn first_delivery(&self, message_id: &str) -> bool {
+        if message_id.is_empty() {
+            return true;
+        }
+        let Ok(mut ids) = self.recent_message_ids.lock() else {
+            return true;
+        };
+        if ids.iter().any(|id| id == message_id) {
+            return false;
+        }
+        ids.push_back(message_id.to_string());
+        while ids.len() > REMEMBERED_DELIVERIES {
+            ids.pop_front();
+        }
+        true
+    }
+}
+
+/// Sent to the group whenever handling a message fails unexpectedly
+const FRIENDLY_FAILURE: &str = "🏴‍☠️ Ahoy! I ran into a problem with that request. Try again in a moment, matey! ⚾";
+
+/// Registered for every callback path; replies go out through the bot mapped to the path
+async fn webhook(req: HttpRequest, request_id: RequestId, req_body: String, data: web::Data<AppState>) -> impl Responder {
+    let Some(route) = data.routes.iter().find(|r| r.path == req.path()) else {
+        return HttpResponse::NotFound().finish();
+    };
+
     // Debug: Log raw webhook payload to see what GroupMe sends
     info!("Raw GroupMe webhook payload: {}", req_body);
     let msg: GroupMeMessage = match serde_json::from_str(&req_body) {
@@ -47,49 +120,256 @@ async fn webhook(req_body: String, data: web::Data<AppState>) -> impl Responder
         }
     };
 
-    // Ignore messages from the bot itself
+    // Ignore messages from the bot itself (but remember them, so replies to them count as talking to the bot)
     if msg.sender_type == "bot" {
+        data.command_parser.remember_bot_message(&msg.name, &msg.id);
+        data.bot_service.note_bot_post(&msg.id, &msg.text);
+        return HttpResponse::Ok().body("OK");
+    }
+
+    // A route tied to a group only answers that group
+    if let Some(group_id) = &route.group_id {
+        if !msg.group_id.is_empty() && &msg.group_id != group_id {
+            warn!("Ignoring message from group {} on callback path for group {}", msg.group_id, group_id);
+            return HttpResponse::Ok().body("OK");
+        }
+    }
+
+    if !data.first_delivery(&msg.id) {
+        info!("Ignoring repeat delivery of message {}", msg.id);
         return HttpResponse::Ok().body("OK");
     }
 
     info!("Received message from {}: '{}'", msg.name, msg.text);
 
+    // Run on its own task (in this request's span, so logs keep the request id) so a panic in
+    // parsing or a handler is caught here instead of dropping the request silently
+    let bot_id = route.bot_id.clone();
+    let task = tokio::spawn(process_message(data.clone(), bot_id.clone(), msg).instrument(tracing::Span::current()));
+    if let Err(e) = task.await {
+        let reason = if e.is_panic() {
+            let payload = e.into_panic();
+            payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string())
+        } else {
+            e.to_string()
+        };
+        error!(request_id = %request_id, "Message handling panicked: {}", reason);
+        if let Err(send_error) = data.bot_service.send_response_as(&bot_id, FRIENDLY_FAILURE).await {
+            error!(request_id = %request_id, "Failed to send error response: {}", send_error);
+        }
+    }
+
+    HttpResponse::Ok().body("OK")
+}
+
+/// Parse a group message, run the command and send the reply through `bot_id`
+async fn process_message(data: web::Data<AppState>, bot_id: String, msg: GroupMeMessage) {
+    let group_id = Some(msg.group_id.as_str()).filter(|g| !g.is_empty());
+
     // Parse the command
-    let command = match data.command_parser.parse_message(&msg.text, Some(&msg.name), Some(&msg.user_id), &msg.attachments).await {
+    let command = match data.command_parser.parse_message(&msg.text, Some(&msg.name), Some(&msg.user_id), group_id, &msg.attachments).await {
         Ok(Some(cmd)) => cmd,
         Ok(None) => {
             // Message not directed at bot, ignore
-            return HttpResponse::Ok().body("OK");
+            return;
         }
         Err(e) => {
             // Conversational error with friendly message
             warn!("Conversational parsing resulted in friendly error: {}", e);
             let error_response = format!("{}", e);
-            if let Err(send_error) = data.bot_service.send_response(&error_response).await {
+            if let Err(send_error) = data.bot_service.send_response_as(&bot_id, &error_response).await {
                 error!("Failed to send friendly response: {}", send_error);
             }
-            return HttpResponse::Ok().body("OK");
+            return;
         }
     };
 
     // Handle the command
-    match data.bot_service.handle_command(command, Some(&msg.name), Some(&msg.user_id), &data.moderators_store).await {
+    match data.bot_service.handle_command(command, Some(&msg.name), Some(&msg.user_id), group_id, &data.moderators_store).await {
+        // Nothing to reply, e.g. a plugin that only posted with send()
+        Ok(response) if response.trim().is_empty() => {}
         Ok(response) => {
-            if let Err(e) = data.bot_service.send_response(&response).await {
+            if let Err(e) = data.bot_service.send_response_as(&bot_id, &response).await {
                 error!("Failed to send response: {}", e);
             }
         }
+        Err(BotError::ConfirmationRequired { prompt, command }) => {
+            data.command_parser.park_pending_action(&msg.user_id, &msg.name, *command).await;
+            if let Err(e) = data.bot_service.send_response_as(&bot_id, &prompt).await {
+                error!("Failed to send confirmation prompt: {}", e);
+            }
+        }
         Err(e) => {
             error!("Failed to handle command: {}", e);
             // Send a friendly error instead of technical error codes
-            let error_response = "🏴‍☠️ Ahoy! I ran into a problem with that request. Try again in a moment, matey! ⚾";
-            if let Err(send_error) = data.bot_service.send_response(error_response).await {
+            if let Err(send_error) = data.bot_service.send_response_as(&bot_id, FRIENDLY_FAILURE).await {
                 error!("Failed to send error response: {}", send_error);
             }
         }
     }
+}
 
-    HttpResponse::Ok().body("OK")
+#[derive(serde::Deserialize)]
+struct SignupForm {
+    name: String,
+    role: String,
+}
+
+/// The game date a signup link is for; the routes are only registered with SIGNUP_SECRET set
+fn signup_date(data: &AppState, token: &str) -> Option<chrono::NaiveDate> {
+    signup::verify_signup_token(data.config.signup_secret.as_deref()?, token)
+}
+
+/// Fill-in form for one game, for helpers who aren't in the GroupMe group
+#[get("/signup/{token}")]
+async fn signup_page(path: web::Path<String>, data: web::Data<AppState>) -> impl Responder {
+    let Some(date) = signup_date(&data, &path) else {
+        return HttpResponse::NotFound().body("This signup link isn't valid.");
+    };
+    let events = data.bot_service.find_event_by_date(date).await.unwrap_or_default();
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(signup::render_signup_page(&data.config.team_name, date, &events, None))
+}
+
+#[post("/signup/{token}")]
+async fn signup_submit(path: web::Path<String>, form: web::Form<SignupForm>, data: web::Data<AppState>) -> impl Responder {
+    let Some(date) = signup_date(&data, &path) else {
+        return HttpResponse::NotFound().body("This signup link isn't valid.");
+    };
+    let notice = match data.bot_service.web_signup(date, &form.role, &form.name).await {
+        Ok(message) => message,
+        Err(e) => {
+            error!("Web signup failed: {}", e);
+            "❌ Something went wrong. Please try again later.".to_string()
+        }
+    };
+    let events = data.bot_service.find_event_by_date(date).await.unwrap_or_default();
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(signup::render_signup_page(&data.config.team_name, date, &events, Some(&notice)))
+}
+
+#[derive(serde::Deserialize)]
+struct AnnounceRequest {
+    text: String,
+}
+
+/// Relay a message from an outside automation (IFTTT, a mail script, ...) to the group.
+/// The token comes only from `Authorization: Bearer <token>`; a query string would end up in access logs.
+#[post("/announce")]
+async fn announce(
+    req: HttpRequest,
+    body: web::Json<AnnounceRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let Some(expected) = &data.config.announce_token else {
+        return HttpResponse::NotFound().finish();
+    };
+    let given = req
+        .headers()
+        .get("Authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+    // Compare without bailing at the first differing byte
+    let authorized = given.is_some_and(|given| {
+        given.len() == expected.len()
+            && given.bytes().zip(expected.bytes()).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+    });
+    if !authorized {
+        warn!("Rejected /announce request with a missing or wrong token");
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    let text = body.text.trim();
+    if text.is_empty() {
+        return HttpResponse::BadRequest().body("text is required");
+    }
+
+    info!("Relaying announcement: {}", text);
+    match data.bot_service.send_response(text).await {
+        Ok(()) => HttpResponse::Ok().body("OK"),
+        Err(e) => {
+            error!("Failed to relay announcement: {}", e);
+            HttpResponse::BadGateway().finish()
+        }
+    }
+}
+
+/// Season schedule as an iCal feed for calendar apps to subscribe to
+#[get("/calendar.ics")]
+async fn calendar_ics(data: web::Data<AppState>) -> impl Responder {
+    match data.bot_service.calendar_feed().await {
+        Ok(ics) => HttpResponse::Ok().content_type("text/calendar; charset=utf-8").body(ics),
+        Err(e) => {
+            error!("Failed to build calendar feed: {}", e);
+            HttpResponse::ServiceUnavailable().finish()
+        }
+    }
+}
+
+/// One game as a calendar file, e.g. /event/2025-05-03.ics
+#[get("/event/{file}")]
+async fn event_ics(path: web::Path<String>, data: web::Data<AppState>) -> impl Responder {
+    let Some(id) = path.strip_suffix(".ics") else {
+        return HttpResponse::NotFound().finish();
+    };
+    match data.bot_service.event_ics(id).await {
+        Ok(Some(ics)) => HttpResponse::Ok()
+            .content_type("text/calendar; charset=utf-8")
+            .insert_header(("Content-Disposition", format!("inline; filename=\"{}.ics\"", id)))
+            .body(ics),
+        Ok(None) => HttpResponse::NotFound().body("No game found for that link."),
+        Err(e) => {
+            error!("Failed to build event calendar file: {}", e);
+            HttpResponse::ServiceUnavailable().finish()
+        }
+    }
+}
+
+/// Mobile-friendly card for one game, e.g. /game/2025-05-03
+#[get("/game/{id}")]
+async fn game_card_page(path: web::Path<String>, data: web::Data<AppState>) -> impl Responder {
+    match data.bot_service.game_card(&path).await {
+        Ok(Some(html)) => HttpResponse::Ok().content_type("text/html; charset=utf-8").body(html),
+        Ok(None) => HttpResponse::NotFound().body("No game found for that link."),
+        Err(e) => {
+            error!("Failed to build game card: {}", e);
+            HttpResponse::ServiceUnavailable().finish()
+        }
+    }
+}
+
+fn qr_response(url: Option<String>) -> HttpResponse {
+    let Some(url) = url else {
+        return HttpResponse::NotFound().body("PUBLIC_BASE_URL is not configured");
+    };
+    match qr::qr_png(&url) {
+        Ok(png) => HttpResponse::Ok().content_type("image/png").body(png),
+        Err(e) => {
+            error!("Failed to render QR code: {}", e);
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
+
+/// Printable QR code for the season calendar feed
+#[get("/qr/calendar")]
+async fn qr_calendar(data: web::Data<AppState>) -> impl Responder {
+    qr_response(calendar_feed::calendar_feed_url(&data.config))
+}
+
+/// Printable QR code for a game's signup page
+#[get("/qr/signup/{token}")]
+async fn qr_signup(path: web::Path<String>, data: web::Data<AppState>) -> impl Responder {
+    match signup_date(&data, &path) {
+        Some(date) => qr_response(signup::signup_url(&data.config, date)),
+        None => HttpResponse::NotFound().body("This signup link isn't valid."),
+    }
 }
 
 #[get("/")]
@@ -101,6 +381,35 @@ async fn health_check() -> impl Responder {
     }))
 }
 
+/// Background loop health for uptime checks; 503 when the reminder loop has stopped ticking
+#[get("/healthz")]
+async fn healthz(data: web::Data<AppState>) -> impl Responder {
+    let heartbeat = &data.reminder_heartbeat;
+    let stalled = heartbeat.is_stalled();
+    let body = serde_json::json!({
+        "status": if stalled { "degraded" } else { "ok" },
+        "reminders": {
+            "last_tick": heartbeat.last_tick().to_rfc3339(),
+            "seconds_since_tick": heartbeat.since_last_tick().num_seconds(),
+            "restarts": heartbeat.restarts(),
+        }
+    });
+    if stalled {
+        HttpResponse::ServiceUnavailable().json(body)
+    } else {
+        HttpResponse::Ok().json(body)
+    }
+}
+
+/// Prometheus gauges for volunteer fill rate and lead time
+#[get("/metrics")]
+async fn metrics(data: web::Data<AppState>) -> impl Responder {
+    let stats = data.bot_service.volunteer_fill_stats().await;
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(volunteer_metrics::prometheus_text(&stats))
+}
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     // Load environment variables
@@ -122,6 +431,15 @@ async fn main() -> std::io::Result<()> {
         .with(file_layer)
         .init();
 
+    // Upgrade data/ before any store reads it
+    match migrations::run_startup_migrations() {
+        Ok(applied) => applied.iter().for_each(|step| info!("Data migration applied: {}", step)),
+        Err(e) => {
+            error!("{}", e);
+            std::process::exit(1);
+        }
+    }
+
     // Load configuration
     let config = match Config::from_env() {
         Ok(config) => {
@@ -134,32 +452,153 @@ async fn main() -> std::io::Result<()> {
         }
     };
 
-    info!("Starting GroupMe bot '{}' on port {}", config.groupme_bot_name, config.port);
+    info!("Starting GroupMe bot '{}' v{} on port {}", config.groupme_bot_name, version::VERSION, config.port);
+
+    // Every callback path and the bot that answers on it
+    let routes = routes::resolve_routes(&config);
+
+    // Create services
+    let plugins = plugins::PluginManager::load(&config.plugins_dir);
+    let features = features::FeatureFlags::new(&config);
+    let game_mode = game_mode::GameMode::new();
+    let scheduled_messages = scheduled_messages::ScheduledMessageStore::new();
+    let command_parser = CommandParser::new(config.groupme_bot_name.clone())
+        .with_plugins(plugins.clone())
+        .with_features(features.clone())
+        .with_game_mode(game_mode.clone())
+        .with_contextual_volunteering(config.contextual_volunteering)
+        .with_unknown_reply_limit(config.unknown_reply_limit, config.unknown_cooldown_minutes)
+        .with_bot_aliases(config.bot_aliases.as_deref().unwrap_or_default())
+        .with_timezone(config.team_timezone);
+    let bot_service = BotService::new(config.clone())
+        .with_bot_name(command_parser.bot_name_handle())
+        .with_plugins(plugins)
+        .with_features(features)
+        .with_game_mode(game_mode.clone())
+        .with_scheduled_messages(scheduled_messages.clone())
+        .with_routes(&routes);
+    // Before anything DMs the admin: a token owned by the admin can't DM them
+    bot_service.detect_token_owner().await;
 
     // Start reminder scheduler
-    let reminder_scheduler = Arc::new(ReminderScheduler::new(config.clone()));
+    let reminder_scheduler = Arc::new(ReminderScheduler::new(config.clone(), bot_service.clone()));
+    let reminder_heartbeat = reminder_scheduler.heartbeat();
     reminder_scheduler.start();
     info!("Reminder scheduler initialized");
 
-    // Create services
-    let bot_service = BotService::new(config.clone());
-    let command_parser = CommandParser::new(config.groupme_bot_name.clone());
+    // Score prompts and automatic sign-off while game mode is on
+    Arc::new(game_mode::GameModeTicker::new(config.clone(), bot_service.clone(), game_mode)).start();
+
+    // One-off "at 6 PM say: ..." messages
+    Arc::new(scheduled_messages::ScheduledMessageSender::new(bot_service.clone(), scheduled_messages)).start();
+
+    // Watch for members joining/leaving
+    Arc::new(monitor::MembershipMonitor::new(config.clone(), bot_service.clone())).start();
+
+    // Weekly moderator digest (shares the moderator list with the webhook)
+    let moderators_store = moderators::ModeratorsStore::new();
+    Arc::new(digest::DigestScheduler::new(config.clone(), bot_service.clone(), moderators_store.clone())).start();
+
+    // Cross-check the sheet against the league calendar
+    Arc::new(conflicts::ConflictMonitor::new(config.clone(), bot_service.clone(), moderators_store.clone())).start();
+
+    // Callback URLs to paste into the GroupMe bot settings
+    println!("GroupMe callback URLs:");
+    for route in &routes {
+        let group = route.group_id.as_deref().map(|g| format!(" (group {})", g)).unwrap_or_default();
+        println!("  bot {}{}: {}", route.bot_id, group, routes::callback_url(&config, route));
+    }
+
+    // Push the callback URLs (and name/avatar) to GroupMe so URL changes don't need the dev portal
+    if config.sync_bot_registration {
+        let sync_config = config.clone();
+        tokio::spawn(async move {
+            let client = groupme_client::GroupMeClient::new(sync_config.clone());
+            match registration::sync_bot_registrations(&sync_config, &client).await {
+                Ok(report) => report.iter().for_each(|line| info!("Bot registration: {}", line)),
+                Err(e) => error!("Bot registration sync failed: {}", e),
+            }
+        });
+    }
+
+    // One "I've been updated" post per new version; recorded only once it's gone out
+    if let Some(announcement) = version::update_announcement(&config.groupme_bot_name) {
+        let announce_service = bot_service.clone();
+        tokio::spawn(async move {
+            match announce_service.send_response(&announcement).await {
+                Ok(()) => version::mark_announced(),
+                Err(e) => warn!("Failed to post the update announcement: {}", e),
+            }
+        });
+    }
+
+    // Load the schedule and weather lookups now rather than on the first command after a deploy
+    let warm_service = bot_service.clone();
+    tokio::spawn(async move {
+        match warm_service.warm_caches().await {
+            Ok(summary) => info!("Caches warmed: {}", summary),
+            Err(e) => warn!("Cache warming failed: {}", e),
+        }
+    });
+
+    // Read the schedule once and report what we found, so a fresh deploy shows right away whether it's healthy
+    if config.startup_report != "off" {
+        let report_service = bot_service.clone();
+        let report_config = config.clone();
+        tokio::spawn(async move {
+            let report = match report_service.startup_report().await {
+                Ok(report) => report,
+                Err(e) => format!("🚨 {} bot started but couldn't read the schedule: {}", report_config.team_name, e),
+            };
+            info!("Startup report:\n{}", report);
+            let sent = if report_config.startup_report == "group" {
+                report_service.send_response(&report).await
+            } else if !report_config.admin_user_id.is_empty() && report_config.groupme_access_token.is_some() {
+                report_service.alert_admin(&report).await
+            } else {
+                Ok(())
+            };
+            if let Err(e) = sent {
+                warn!("Failed to send the startup report: {}", e);
+            }
+        });
+    }
 
     // Create application state
     let app_state = web::Data::new(AppState {
         bot_service,
         command_parser,
-        moderators_store: moderators::ModeratorsStore::new(),
+        moderators_store,
         config: config.clone(),
+        routes: routes.clone(),
+        reminder_heartbeat,
+        recent_message_ids: std::sync::Mutex::new(VecDeque::new()),
     });
 
+    let signup_pages = config.signup_secret.is_some();
+    if !signup_pages {
+        warn!("SIGNUP_SECRET is not set; /signup pages and signup links are disabled");
+    }
+
     // Start HTTP server
     HttpServer::new(move || {
-        App::new()
+        let mut app = App::new()
             .app_data(app_state.clone())
-            .wrap(TracingLogger::default())
-            .service(webhook)
-            .service(health_check)
+            .wrap(TracingLogger::default());
+        for route in &routes {
+            app = app.route(&route.path, web::post().to(webhook));
+        }
+        if signup_pages {
+            app = app.service(signup_page).service(signup_submit).service(qr_signup);
+        }
+        app.service(health_check)
+            .service(healthz)
+            .service(metrics)
+            .service(announce)
+            .service(calendar_ics)
+            .service(event_ics)
+            .service(game_card_page)
+            .service(qr_calendar)
     })
     .bind(("0.0.0.0", config.port))?
     .run()
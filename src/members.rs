@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use tracing::warn;
+
+use crate::groupme_client::GroupMeClient;
+use crate::models::GroupMember;
+
+/// How long a fetched member list is trusted before asking GroupMe again
+const MEMBERS_TTL: Duration = Duration::from_secs(600);
+
+#[derive(Default)]
+struct MembersSnapshot {
+    members: HashMap<String, GroupMember>,
+    fetched_at: Option<Instant>,
+}
+
+/// Cache of the group's member list, used to turn raw user ids into nicknames
+#[derive(Clone)]
+pub struct MembersCache {
+    groupme_client: GroupMeClient,
+    snapshot: Arc<RwLock<MembersSnapshot>>,
+}
+
+impl MembersCache {
+    pub fn new(groupme_client: GroupMeClient) -> Self {
+        Self {
+            groupme_client,
+            snapshot: Arc::new(RwLock::new(MembersSnapshot::default())),
+        }
+    }
+
+    /// Refresh the member list if it has never been fetched or has gone stale.
+    /// Failures are logged and remembered so an unconfigured token doesn't hit the API on every call.
+    async fn ensure_fresh(&self) {
+        let is_stale = {
+            let snapshot = self.snapshot.read().await;
+            snapshot.fetched_at.is_none_or(|at| at.elapsed() > MEMBERS_TTL)
+        };
+        if !is_stale {
+            return;
+        }
+
+        let fetched = self.groupme_client.list_members().await;
+        let mut snapshot = self.snapshot.write().await;
+        snapshot.fetched_at = Some(Instant::now());
+        match fetched {
+            Ok(members) => {
+                snapshot.members = members.into_iter().map(|m| (m.user_id.clone(), m)).collect();
+            }
+            Err(e) => warn!("Could not refresh group members: {}", e),
+        }
+    }
+
+    pub async fn nickname(&self, user_id: &str) -> Option<String> {
+        self.ensure_fresh().await;
+        let snapshot = self.snapshot.read().await;
+        snapshot.members.get(user_id).map(|m| m.nickname.clone())
+    }
+
+    pub async fn nicknames(&self) -> Vec<String> {
+        self.ensure_fresh().await;
+        let snapshot = self.snapshot.read().await;
+        snapshot.members.values().map(|m| m.nickname.clone()).collect()
+    }
+
+    /// Format a user id for admin-facing output, e.g. "Jane Doe (87654321)"
+    pub async fn display(&self, user_id: &str) -> String {
+        match self.nickname(user_id).await {
+            Some(nickname) => format!("{} ({})", nickname, user_id),
+            None => user_id.to_string(),
+        }
+    }
+}
+
+/// Loose person-name comparison: "Hobbs" matches "Mike Hobbs", "Saturday" matches nobody.
+/// Two names match when they share a word of three or more letters.
+pub fn names_match(a: &str, b: &str) -> bool {
+    let words = |s: &str| -> Vec<String> {
+        s.split(|c: char| !c.is_alphanumeric() && c != '\'')
+            .filter(|w| w.chars().count() >= 3)
+            .map(|w| w.to_lowercase())
+            .collect()
+    };
+    let b_words = words(b);
+    words(a).iter().any(|w| b_words.contains(w))
+}
+
+/// Strict person-name comparison for anything destructive: the whole name, ignoring case and spacing.
+/// "jane  doe" is "Jane Doe", but "Jane Doe" isn't "Jane Smith" or "John Doe".
+pub fn same_person(a: &str, b: &str) -> bool {
+    let normalized = |s: &str| s.split_whitespace().map(str::to_lowercase).collect::<Vec<_>>();
+    let a = normalized(a);
+    !a.is_empty() && a == normalized(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_match() {
+        assert!(names_match("Hobbs", "Mike Hobbs"));
+        assert!(names_match("jane doe", "Jane"));
+        assert!(!names_match("Saturday", "Mike Hobbs"));
+        assert!(!names_match("Al", "Al Smith"));
+    }
+
+    #[test]
+    fn test_same_person() {
+        assert!(same_person("jane  doe", "Jane Doe"));
+        assert!(!same_person("Jane Doe", "Jane Smith"));
+        assert!(!same_person("Jane Doe", "John Doe"));
+        assert!(!same_person("Jane", "Jane Doe"));
+        assert!(!same_person("", ""));
+    }
+}
@@ -0,0 +1,192 @@
+use regex::Regex;
+use serde::{Serialize, Deserialize};
+use std::sync::{Arc, RwLock};
+use tracing::warn;
+
+/// Regex for "@name" that ignores case, spacing and punctuation between words,
+/// so "PirateBot", "Pirate Bot" and "pirate-bot" all match each other
+fn name_pattern(name: &str) -> Option<Regex> {
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            prev_lower = false;
+            continue;
+        }
+        // "PirateBot" is two words
+        if c.is_uppercase() && prev_lower && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        prev_lower = c.is_lowercase();
+        current.extend(c.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    let body = if words.is_empty() {
+        regex::escape(name.trim())
+    } else {
+        let words: Vec<String> = words.iter().map(|w| regex::escape(w)).collect();
+        format!("{}\\b", words.join(r"[\s\W_]*"))
+    };
+    if body.is_empty() {
+        return None;
+    }
+    Regex::new(&format!(r"(?i)@\s*{}", body)).ok()
+}
+
+/// Comma-separated BOT_ALIASES: plain names, or `/regex/` for anything fancier.
+/// A regex only needs to match the name; the leading "@" is added here.
+pub fn parse_aliases(spec: &str) -> Vec<Regex> {
+    spec.split(',')
+        .map(str::trim)
+        .filter(|alias| !alias.is_empty())
+        .filter_map(|alias| {
+            let pattern = match alias.strip_prefix('/').and_then(|a| a.strip_suffix('/')) {
+                Some(raw) => Regex::new(&format!(r"(?i)@\s*(?:{})", raw)).ok(),
+                None => name_pattern(alias),
+            };
+            if pattern.is_none() {
+                warn!("Ignoring invalid bot alias: {}", alias);
+            }
+            pattern
+        })
+        .collect()
+}
+
+const BOT_NAME_FILE: &str = "data/bot_name.json";
+
+#[derive(Serialize, Deserialize)]
+struct SavedBotName {
+    name: String,
+}
+
+/// The name from the last "rename to ...", which outlives restarts and wins over GROUPME_BOT_NAME
+pub fn saved_bot_name() -> Option<String> {
+    std::fs::read_to_string(BOT_NAME_FILE)
+        .ok()
+        .and_then(|content| serde_json::from_str::<SavedBotName>(&content).ok())
+        .map(|saved| saved.name)
+        .filter(|name| !name.trim().is_empty())
+}
+
+/// Remember a "rename to ..." so the next startup (and its registration sync) keeps the new name
+pub fn save_bot_name(name: &str) {
+    if let Err(e) = std::fs::create_dir_all("data") { tracing::error!("Failed to create data dir: {}", e); }
+    let saved = SavedBotName { name: name.to_string() };
+    if let Err(e) = std::fs::write(BOT_NAME_FILE, serde_json::to_string_pretty(&saved).unwrap_or_default()) {
+        tracing::error!("Failed to save bot name: {}", e);
+    }
+}
+
+/// The bot's current name with its mention regex, compiled once per name rather than per message.
+/// Shared between the parsers and BotService so "rename to ..." takes effect everywhere.
+#[derive(Debug, Clone)]
+pub struct BotName {
+    name: String,
+    pattern: Option<Regex>,
+}
+
+impl BotName {
+    pub fn new(name: String) -> Self {
+        let pattern = name_pattern(&name);
+        Self { name, pattern }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Change the name and rebuild its pattern
+    pub fn rename(&mut self, name: String) {
+        *self = Self::new(name);
+    }
+}
+
+impl std::fmt::Display for BotName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// Finds @mentions of the bot under its current name or any configured alias
+#[derive(Clone)]
+pub struct MentionMatcher {
+    bot_name: Arc<RwLock<BotName>>,
+    aliases: Arc<Vec<Regex>>,
+}
+
+impl MentionMatcher {
+    pub fn new(bot_name: Arc<RwLock<BotName>>) -> Self {
+        Self { bot_name, aliases: Arc::new(Vec::new()) }
+    }
+
+    pub fn with_aliases(mut self, aliases: Vec<Regex>) -> Self {
+        self.aliases = Arc::new(aliases);
+        self
+    }
+
+    fn patterns(&self) -> Vec<Regex> {
+        let name_pattern = match self.bot_name.read() {
+            Ok(name) => name.pattern.clone(),
+            Err(e) => e.into_inner().pattern.clone(),
+        };
+        name_pattern.into_iter().chain(self.aliases.iter().cloned()).collect()
+    }
+
+    pub fn is_mentioned(&self, text: &str) -> bool {
+        self.patterns().iter().any(|p| p.is_match(text))
+    }
+
+    /// Whether a sender name is the bot's, e.g. for the bot's own posts coming back through the callback
+    pub fn is_bot_name(&self, name: &str) -> bool {
+        let at_name = format!("@{}", name.trim());
+        self.patterns().iter().any(|p| p.find(&at_name).is_some_and(|m| m.start() == 0 && m.end() == at_name.len()))
+    }
+
+    /// The message without any mentions of the bot, or None when it doesn't mention the bot
+    pub fn strip(&self, text: &str) -> Option<String> {
+        let patterns = self.patterns();
+        if !patterns.iter().any(|p| p.is_match(text)) {
+            return None;
+        }
+        let stripped = patterns
+            .iter()
+            .fold(text.to_string(), |text, p| p.replace_all(&text, "").into_owned());
+        Some(stripped.trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mention_variations() {
+        let name = Arc::new(RwLock::new(BotName::new("PirateBot".to_string())));
+        let mentions = MentionMatcher::new(name.clone()).with_aliases(parse_aliases("Pirates Bot, /coach\\s*bot/, /(/"));
+
+        assert!(mentions.is_mentioned("@PirateBot next game"));
+        assert!(mentions.is_mentioned("@Pirate Bot next game"));
+        assert!(mentions.is_mentioned("hey @pirate-bot, next game?"));
+        assert!(mentions.is_mentioned("@pirates bot next game"));
+        assert!(mentions.is_mentioned("@Coach Bot next game"));
+        assert!(!mentions.is_mentioned("@PirateBotanist next game"));
+        assert!(!mentions.is_mentioned("PirateBot next game"));
+
+        assert_eq!(mentions.strip("@Pirate Bot I've got snacks").as_deref(), Some("I've got snacks"));
+        assert_eq!(mentions.strip("I've got snacks"), None);
+        assert!(mentions.is_bot_name("PirateBot"));
+        assert!(!mentions.is_bot_name("PirateBot fan"));
+
+        // A rename in chat takes effect right away
+        name.write().unwrap().rename("Ahoy Bot".to_string());
+        assert!(mentions.is_mentioned("@ahoybot next game"));
+        assert!(!mentions.is_mentioned("@PirateBot next game"));
+    }
+}
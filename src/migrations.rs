@@ -0,0 +1,265 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use std::path::{Path, PathBuf};
+
+use crate::error::{BotError, Result};
+
+const DATA_DIR: &str = "data";
+const VERSION_FILE: &str = "schema_version.json";
+const BACKUPS_DIR: &str = "backups";
+/// Older backups are deleted once there are more than this many
+const MAX_BACKUPS: usize = 5;
+
+/// One change to the on-disk format. `run` gets the data directory and upgrades the files it
+/// touches from the previous version's layout.
+pub struct Migration {
+    pub version: u32,
+    pub description: &'static str,
+    pub run: fn(&Path) -> std::result::Result<(), String>,
+}
+
+/// Every format change, oldest first. When a store's file layout changes, add a step here that
+/// rewrites the old layout (see `rewrite_json`). Never edit or reorder a step that has shipped.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Start tracking the data format version",
+        run: |_| Ok(()),
+    },
+];
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SchemaVersion {
+    version: u32,
+    migrated_at: Option<DateTime<Utc>>,
+}
+
+/// Upgrade data/ to the format this build expects, before any store reads it.
+/// Returns a line per step applied.
+pub fn run_startup_migrations() -> Result<Vec<String>> {
+    migrate(Path::new(DATA_DIR), MIGRATIONS, Utc::now())
+}
+
+/// Apply the steps in `migrations` newer than the directory's recorded version. Everything in the
+/// directory is copied to backups/ first, and put back if a step fails, so a bad upgrade never
+/// leaves half-converted files for the stores to read as empty.
+pub fn migrate(dir: &Path, migrations: &[Migration], now: DateTime<Utc>) -> Result<Vec<String>> {
+    std::fs::create_dir_all(dir).map_err(|e| BotError::Config(format!("Can't create {}: {}", dir.display(), e)))?;
+    let current = read_version(dir)?;
+    let latest = migrations.last().map_or(0, |m| m.version);
+    if current > latest {
+        // An older build would read newer files as empty and then save over them
+        return Err(BotError::Config(format!(
+            "{} is at data format version {}, but this build only knows up to {}. Run a newer build or restore a backup.",
+            dir.display(), current, latest
+        )));
+    }
+    let pending: Vec<&Migration> = migrations.iter().filter(|m| m.version > current).collect();
+    if pending.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let backup = backup(dir, current, now)?;
+    let mut applied = Vec::new();
+    for migration in pending {
+        if let Err(e) = (migration.run)(dir) {
+            let restored = match &backup {
+                Some(backup) => restore(backup, dir).map(|_| format!("Files were restored from {}", backup.display())),
+                None => Ok("There was nothing to back up".to_string()),
+            };
+            let restored = restored.unwrap_or_else(|e| format!("Restoring the backup also failed: {}", e));
+            return Err(BotError::Config(format!(
+                "Data migration {} ({}) failed: {}. {}.",
+                migration.version, migration.description, e, restored
+            )));
+        }
+        applied.push(format!("v{}: {}", migration.version, migration.description));
+    }
+    write_version(dir, &SchemaVersion { version: latest, migrated_at: Some(now) })?;
+    Ok(applied)
+}
+
+/// Load a JSON data file, change it, and write it back pretty-printed. A missing file is left
+/// alone, since the store will start fresh either way.
+pub fn rewrite_json(
+    dir: &Path,
+    file: &str,
+    upgrade: impl FnOnce(serde_json::Value) -> std::result::Result<serde_json::Value, String>,
+) -> std::result::Result<(), String> {
+    let path = dir.join(file);
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return Ok(());
+    };
+    let value = serde_json::from_str(&content).map_err(|e| format!("{} isn't valid JSON: {}", file, e))?;
+    let upgraded = upgrade(value)?;
+    let content = serde_json::to_string_pretty(&upgraded).map_err(|e| e.to_string())?;
+    std::fs::write(&path, content).map_err(|e| format!("Can't write {}: {}", file, e))
+}
+
+fn read_version(dir: &Path) -> Result<u32> {
+    match std::fs::read_to_string(dir.join(VERSION_FILE)) {
+        Ok(content) => serde_json::from_str::<SchemaVersion>(&content)
+            .map(|v| v.version)
+            .map_err(|e| BotError::Config(format!("{} is unreadable: {}", VERSION_FILE, e))),
+        // Data from before versioning, or a fresh install
+        Err(_) => Ok(0),
+    }
+}
+
+fn write_version(dir: &Path, version: &SchemaVersion) -> Result<()> {
+    std::fs::write(dir.join(VERSION_FILE), serde_json::to_string_pretty(version)?)
+        .map_err(|e| BotError::Config(format!("Can't write {}: {}", VERSION_FILE, e)))
+}
+
+/// Data files directly in `dir` (backups/ and other folders aren't data)
+fn data_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/// Copy every data file to backups/v<from>-<timestamp>/; None when there's nothing to copy
+fn backup(dir: &Path, from: u32, now: DateTime<Utc>) -> Result<Option<PathBuf>> {
+    let fail = |e: std::io::Error| BotError::Config(format!("Can't back up {} before migrating: {}", dir.display(), e));
+    let files = data_files(dir).map_err(fail)?;
+    if files.is_empty() {
+        return Ok(None);
+    }
+    let backups = dir.join(BACKUPS_DIR);
+    let target = backups.join(format!("v{}-{}", from, now.format("%Y%m%d-%H%M%S")));
+    std::fs::create_dir_all(&target).map_err(fail)?;
+    for file in &files {
+        if let Some(name) = file.file_name() {
+            std::fs::copy(file, target.join(name)).map_err(fail)?;
+        }
+    }
+    prune_backups(&backups);
+    Ok(Some(target))
+}
+
+/// Version and timestamp from a v<version>-<timestamp> backup name, which sort oldest first
+fn backup_key(name: &str) -> Option<(u32, String)> {
+    let (version, timestamp) = name.strip_prefix('v')?.split_once('-')?;
+    Some((version.parse().ok()?, timestamp.to_string()))
+}
+
+fn prune_backups(backups: &Path) {
+    let Ok(entries) = std::fs::read_dir(backups) else { return };
+    // Anything not named like a backup is left alone
+    let mut dirs: Vec<((u32, String), PathBuf)> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.is_dir())
+        .filter_map(|p| Some((backup_key(p.file_name()?.to_str()?)?, p)))
+        .collect();
+    dirs.sort();
+    let excess = dirs.len().saturating_sub(MAX_BACKUPS);
+    for (_, old) in &dirs[..excess] {
+        if let Err(e) = std::fs::remove_dir_all(old) {
+            tracing::warn!("Failed to remove old data backup {}: {}", old.display(), e);
+        }
+    }
+}
+
+/// Put the backed-up files back, dropping any file a failed step created
+fn restore(backup: &Path, dir: &Path) -> std::io::Result<()> {
+    for file in data_files(dir)? {
+        if file.file_name().is_some_and(|name| !backup.join(name).exists()) {
+            std::fs::remove_file(&file)?;
+        }
+    }
+    for file in data_files(backup)? {
+        if let Some(name) = file.file_name() {
+            std::fs::copy(&file, dir.join(name))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("groupme-bot-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn rename_mods(dir: &Path) -> std::result::Result<(), String> {
+        rewrite_json(dir, "moderators.json", |mut value| {
+            let mods = value.get_mut("mods").map(serde_json::Value::take).ok_or("no mods")?;
+            Ok(serde_json::json!({ "moderators": mods }))
+        })
+    }
+
+    #[test]
+    fn test_migrate_backs_up_and_records_version() {
+        let dir = scratch_dir("migrate");
+        let now = Utc.with_ymd_and_hms(2026, 5, 1, 12, 0, 0).unwrap();
+        std::fs::write(dir.join("moderators.json"), r#"{"mods":["123"]}"#).unwrap();
+        let migrations = [
+            Migration { version: 1, description: "baseline", run: |_| Ok(()) },
+            Migration { version: 2, description: "rename mods", run: rename_mods },
+        ];
+
+        let applied = migrate(&dir, &migrations, now).unwrap();
+        assert_eq!(applied, vec!["v1: baseline", "v2: rename mods"]);
+        let content = std::fs::read_to_string(dir.join("moderators.json")).unwrap();
+        assert!(content.contains("\"moderators\""));
+        let backup = dir.join("backups/v0-20260501-120000/moderators.json");
+        assert_eq!(std::fs::read_to_string(backup).unwrap(), r#"{"mods":["123"]}"#);
+        assert_eq!(read_version(&dir).unwrap(), 2);
+
+        // Already current: nothing runs
+        assert!(migrate(&dir, &migrations, now).unwrap().is_empty());
+        // An older build refuses newer data rather than overwriting it
+        assert!(migrate(&dir, &migrations[..1], now).is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_failed_migration_restores_files() {
+        let dir = scratch_dir("migrate-fail");
+        let now = Utc.with_ymd_and_hms(2026, 5, 1, 12, 0, 0).unwrap();
+        std::fs::write(dir.join("moderators.json"), r#"{"mods":["123"]}"#).unwrap();
+        let migrations = [
+            Migration { version: 1, description: "rename mods", run: rename_mods },
+            Migration { version: 2, description: "broken", run: |dir: &Path| {
+                std::fs::write(dir.join("half_done.json"), "{}").map_err(|e| e.to_string())?;
+                Err("boom".to_string())
+            } },
+        ];
+
+        assert!(migrate(&dir, &migrations, now).is_err());
+        assert_eq!(std::fs::read_to_string(dir.join("moderators.json")).unwrap(), r#"{"mods":["123"]}"#);
+        assert!(!dir.join("half_done.json").exists());
+        assert_eq!(read_version(&dir).unwrap(), 0);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_prune_backups_keeps_newest_versions() {
+        let dir = scratch_dir("prune");
+        let names = ["v2-20260101-000000", "v8-20260102-000000", "v9-20260103-000000", "v9-20260104-000000",
+            "v10-20260105-000000", "v11-20260106-000000", "notes"];
+        for name in names {
+            std::fs::create_dir_all(dir.join(name)).unwrap();
+        }
+
+        prune_backups(&dir);
+        let mut left: Vec<String> = std::fs::read_dir(&dir).unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        left.sort();
+        assert_eq!(left, vec!["notes", "v10-20260105-000000", "v11-20260106-000000", "v8-20260102-000000",
+            "v9-20260103-000000", "v9-20260104-000000"]);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
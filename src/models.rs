@@ -12,6 +12,7 @@ pub struct EventData {
     pub scoreboard: Option<String>,
     pub pitch_count: Option<String>,
     pub gamechanger: Option<String>,
+    pub notes: Option<String>,
 }
 
 impl EventData {
@@ -26,6 +27,7 @@ impl EventData {
             scoreboard: if scoreboard.is_empty() { None } else { Some(scoreboard) },
             pitch_count: if pitch_count.is_empty() { None } else { Some(pitch_count) },
             gamechanger: if gamechanger.is_empty() { None } else { Some(gamechanger) },
+            notes: None,
         }
     }
 }
@@ -45,10 +47,20 @@ pub struct Attachment {
     pub user_ids: Vec<String>,
     #[serde(default)]
     pub loci: Vec<Vec<i32>>,
+    /// Id of the message being replied to, on "reply" attachments
+    #[serde(default)]
+    pub reply_id: Option<String>,
+    /// Image service URL, on "image" attachments
+    #[serde(default)]
+    pub url: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct GroupMeMessage {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub group_id: String,
     pub text: String,
     pub sender_type: String,
     pub name: String,
@@ -67,10 +79,31 @@ pub struct GroupMeMessageInfo {
     pub created_at: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+pub struct GroupMember {
+    pub user_id: String,
+    pub nickname: String,
+}
+
+/// A bot as registered with the GroupMe bots API
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BotRegistration {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<String>,
+    pub group_id: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callback_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct GroupMePostMessage {
     pub bot_id: String,
     pub text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<serde_json::Value>,
 }
 
 // Google Sheets API models
@@ -79,15 +112,16 @@ pub struct SheetsResponse {
     pub values: Option<Vec<Vec<String>>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum BotCommand {
     NextGame,                                    // @Bot next game
     NextGames(usize),                           // @Bot next 3 games
-    NextGameCategory(String),                   // @Bot next game snacks
+    NextGameCategory(Vec<String>, Option<NaiveDate>), // @Bot next game snacks; categories, date (None = next game)
     LetsGo(String),                            // @Bot lets go pirates
     Volunteer(NaiveDate, String, String),      // @Bot volunteer snacks 2025-01-15 John
     ShowVolunteers(Option<NaiveDate>),          // @Bot volunteers [date]
     Commands,                                   // @Bot commands
+    HelpByDm,                                   // the commands list, sent privately after repeated misunderstandings
     VolunteerNextGame(String, String), // role, person - resolved to next game
     RemoveVolunteer(String, String, Option<NaiveDate>), // person, role, date
     AssignVolunteer(String, String, Option<NaiveDate>), // person, role, date
@@ -95,9 +129,104 @@ pub enum BotCommand {
     RemoveModerator(String), // user_id
     ListModerators,
     ListBotMessages(usize), // count - list recent bot messages
+    WhoIs(String), // user_id
+    RequestConfirmation(Box<BotCommand>), // destructive command waiting for "confirm"
+    Confirmed(Box<BotCommand>), // user confirmed a pending command
+    CancelPending,
+    SetGameNote(Option<NaiveDate>, String), // date (None = next game), note
+    ClearGameNote(Option<NaiveDate>),
+    CancelGame(Option<NaiveDate>, Option<String>), // date (None = next game), reason
+    RequestBringItems(String, Vec<String>), // event, items
+    BringItem(Option<String>, String, String), // event (None = the only open list), item, person
+    BringListStatus(Option<String>), // event
+    ClearBringList(String), // event
+    RecordResult(Option<NaiveDate>, Option<String>, u32, u32), // date, opponent, our score, their score
+    HeadToHead(String), // opponent
+    LogPlayerStats(Option<NaiveDate>, String, crate::stats::StatCounts), // date, player, stats
+    PlayerStats(String), // player
+    TeamStats,
+    RenameBot(String), // new name
+    SetBotAvatar(String), // image url
+    ClearAssignments(String), // person
+    ClearVolunteers(NaiveDate), // game date
+    CopyVolunteers(NaiveDate, NaiveDate), // from game date, to game date
+    ModeratorDigest,
+    ImportSchedule(String), // csv/ics url
+    SetVenueTimezone(String, Option<String>), // venue, IANA time zone (None = local)
+    ListVenues,
+    StartTournament(String), // name
+    SetBracketGame(crate::tournament::BracketGame),
+    RecordBracketResult(u32, crate::tournament::Outcome), // game number, outcome
+    ShowBracket,
+    ClearTournament,
+    SetTournamentDates(NaiveDate, NaiveDate), // first day, last day
+    SetTournamentLogistics(String, Option<String>), // label, value (None = remove)
+    TournamentInfo,
+    SignupLink(Option<NaiveDate>), // date (None = next game)
+    QrCode(QrTarget),
+    AddToCalendar(Option<NaiveDate>), // date (None = next game)
+    Weather(Option<NaiveDate>), // game date (None = next game)
+    ClaimOpenRole(String, String), // id of the bot post replied to with 🙋, person
+    SetGameMode(bool), // on
+    ScheduleMessage(Option<NaiveDate>, chrono::NaiveTime, String), // date (None = next time it's that time), time, text
+    ListScheduledMessages,
+    CancelScheduledMessage(u32), // id
+    RecordAbsence(String, NaiveDate, NaiveDate), // who, first day away, last day away
+    ListAbsences,
+    RemoveAbsence(u32), // id
+    BlockWord(String),
+    UnblockWord(String),
+    ListBlockedWords,
+    VolunteerAnalytics,
+    Version,
+    AddSpecialEvent(String, NaiveDate, Option<String>, Option<String>), // title, date, time, location
+    ListSpecialEvents,
+    RemoveSpecialEvent(u32), // id
+    SetEventReminder(String, u32, String), // event type, hours before, template
+    ShowEventReminders(String), // event type
+    ResetEventReminders(String), // event type
+    LiveScore(u32, u32), // our runs, their runs
+    CollectPhotos(Vec<String>), // image urls
+    GamePhotos(Option<NaiveDate>), // game date (None = latest game with photos)
+    RunPlugin(String, String), // plugin name, message text
+    SetFeature(crate::features::Feature, bool, bool), // feature, enabled, every group
+    ListFeatures,
+}
+
+/// What a QR code should point at
+#[derive(Debug, Clone, PartialEq)]
+pub enum QrTarget {
+    Calendar,
+    Signup(Option<NaiveDate>), // date (None = next game)
 }
 
+impl BotCommand {
+    /// Destructive moderator actions that must be confirmed before they run
+    pub fn requires_confirmation(&self) -> bool {
+        matches!(self, BotCommand::RemoveVolunteer(..) | BotCommand::ClearBringList(..) | BotCommand::ClearAssignments(..) | BotCommand::ClearVolunteers(..) | BotCommand::CopyVolunteers(..) | BotCommand::ClearTournament | BotCommand::CancelGame(..))
+    }
+
+    /// Moderator-only output that shouldn't sit in the group chat: it goes to the requester by DM
+    /// and the group only sees an acknowledgement
+    pub fn private_output(&self) -> bool {
+        matches!(self, BotCommand::ListBotMessages(..) | BotCommand::ModeratorDigest | BotCommand::ListBlockedWords | BotCommand::HelpByDm)
+    }
+}
+
+/// Sheet entries like "TBD" or "n/a" that fill a volunteer cell without naming anyone
+pub fn is_placeholder_name(name: &str) -> bool {
+    ["not needed", "n/a", "na", "tbd", "none", "-"].contains(&name.trim().to_lowercase().as_str())
+}
+
+/// What "cancel game" writes into a game's note; a note with it means the game isn't happening
+pub const CANCELLED_NOTE: &str = "CANCELLED";
+
 impl EventData {
+    /// Whether a moderator cancelled this game ("cancel game saturday: rain")
+    pub fn is_cancelled(&self) -> bool {
+        self.notes.as_deref().is_some_and(|note| note.contains(CANCELLED_NOTE))
+    }
+
     /// Format a location as a Google Maps hyperlink
     pub fn format_location_with_link(&self) -> String {
         if self.location.is_empty() {
@@ -123,6 +252,33 @@ impl EventData {
         }
     }
     
+    /// Roles this game needs someone for, filled or not, as (role, person if filled).
+    /// A role the sheet marks "not needed"/"n/a" isn't needed, and neither is scoreboard at home.
+    pub fn needed_roles(&self, my_team_name: &str) -> Vec<(&'static str, Option<&str>)> {
+        crate::signup::SIGNUP_ROLES
+            .iter()
+            .filter_map(|(role, _)| match self.get_field(role) {
+                Some(person) if is_placeholder_name(person) => None,
+                Some(person) => Some((*role, Some(person.as_str()))),
+                None => self.is_role_available(role, my_team_name).then_some((*role, None)),
+            })
+            .collect()
+    }
+
+    /// Filled volunteer roles as (role, person) pairs
+    pub fn volunteer_assignments(&self) -> Vec<(&'static str, &str)> {
+        [
+            ("snacks", &self.snacks),
+            ("livestream", &self.livestream),
+            ("scoreboard", &self.scoreboard),
+            ("pitchcount", &self.pitch_count),
+            ("gamechanger", &self.gamechanger),
+        ]
+        .into_iter()
+        .filter_map(|(role, person)| person.as_deref().map(|p| (role, p)))
+        .collect()
+    }
+
     /// Check if the game is a Home game
     pub fn is_home_game(&self) -> bool {
         let ht = self.home_team.trim().to_lowercase();
@@ -175,10 +331,11 @@ impl EventData {
         }
     }
     
-    pub fn format_all(&self) -> String {
+    /// Every detail of the game; `today` is where the team is, for "(in 3 days)"
+    pub fn format_all(&self, today: NaiveDate) -> String {
         let mut details = String::new();
         
-        details.push_str(&format!("Date: {}\n", self.date.format("%Y-%m-%d")));
+        details.push_str(&format!("Date: {}\n", crate::relative_dates::game_day(self.date, today)));
         details.push_str(&format!("Time: {}\n", self.time));
         details.push_str(&format!("Location: {}\n", self.format_location_with_link()));
         details.push_str(&format!("Home/Away: {}\n", self.home_team));
@@ -200,6 +357,10 @@ impl EventData {
             
         details.push_str(&format!("GameChanger: {}\n", 
             self.gamechanger.as_ref().unwrap_or(&"⚠️ NEEDED".to_string())));
+
+        if let Some(notes) = &self.notes {
+            details.push_str(&format!("📝 Notes: {}\n", notes));
+        }
         
         details
     }
@@ -266,11 +427,30 @@ impl CorrelatedEvent {
         }
     }
     
+    /// The other team in this game, if the schedule names one.
+    /// Tries the matchup in the summary or Home Team column first, then treats a Home Team
+    /// value that isn't us (or just "Home"/"Away") as the opponent hosting us.
+    pub fn opponent(&self, my_team: &str) -> Option<String> {
+        let matchup = Self::parse_matchup(&self.event_summary)
+            .or_else(|| Self::parse_matchup(&self.data.home_team));
+        if let Some((team1, team2)) = matchup {
+            return Some(if teams_match(&team2, my_team) { team1 } else { team2 });
+        }
+
+        let home_team = Self::clean_team_name(&self.data.home_team);
+        let placeholder = matches!(home_team.to_lowercase().as_str(), "" | "home" | "away" | "h" | "a" | "tbd");
+        if placeholder || teams_match(&home_team, my_team) {
+            None
+        } else {
+            Some(home_team)
+        }
+    }
+
     /// Parse matchup from calendar summary
     /// The calendar format from TeamSideline is: " Vs [OpponentTeam] - [Field] ([HomeTeam] - [Coach])"
     /// Example: " Vs Chaos 8U - Hall (Pirates - Hines)"
     /// Returns (HomeTeam, OpponentTeam) tuple
-    fn parse_matchup(summary: &str) -> Option<(String, String)> {
+    pub(crate) fn parse_matchup(summary: &str) -> Option<(String, String)> {
         let summary = summary.trim();
         let summary_lower = summary.to_lowercase();
         
@@ -341,3 +521,91 @@ impl CorrelatedEvent {
         }
     }
 }
+
+/// Loose team-name comparison that ignores case and age-group tags, so "Chaos 8U" matches "chaos"
+pub fn teams_match(a: &str, b: &str) -> bool {
+    let normalize = |s: &str| -> String {
+        s.to_lowercase()
+            .split_whitespace()
+            .filter(|w| !(w.len() > 1 && w.ends_with('u') && w[..w.len() - 1].chars().all(|c| c.is_ascii_digit())))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let (a, b) = (normalize(a), normalize(b));
+    !a.is_empty() && !b.is_empty() && (a == b || a.contains(&b) || b.contains(&a))
+}
+
+/// One team's line from the league standings sheet
+#[derive(Debug, Clone)]
+pub struct TeamStanding {
+    pub team: String,
+    pub wins: u32,
+    pub losses: u32,
+    pub ties: u32,
+}
+
+impl TeamStanding {
+    /// Parse a standings row laid out as Team | Wins | Losses | Ties (ties optional)
+    pub fn from_row(row: &[String]) -> Option<Self> {
+        let team = row.first()?.trim();
+        if team.is_empty() {
+            return None;
+        }
+        let number = |idx: usize| row.get(idx).and_then(|v| v.trim().parse::<u32>().ok());
+        Some(Self {
+            team: team.to_string(),
+            wins: number(1)?,
+            losses: number(2)?,
+            ties: number(3).unwrap_or(0),
+        })
+    }
+
+    /// "6-2", or "6-2-1" when there are ties
+    pub fn record(&self) -> String {
+        if self.ties > 0 {
+            format!("{}-{}-{}", self.wins, self.losses, self.ties)
+        } else {
+            format!("{}-{}", self.wins, self.losses)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event_with(summary: &str, home_team: &str) -> CorrelatedEvent {
+        let date = NaiveDate::from_ymd_opt(2025, 5, 3).unwrap();
+        CorrelatedEvent {
+            event_date: date,
+            event_summary: summary.to_string(),
+            data: EventData::new(date, "10:00".to_string(), "Hall".to_string(), home_team.to_string(),
+                String::new(), String::new(), String::new(), String::new(), String::new()),
+        }
+    }
+
+    #[test]
+    fn test_opponent_lookup() {
+        assert_eq!(event_with(" Vs Chaos 8U - Hall (Pirates - Hines)", "Pirates").opponent("Pirates"), Some("Chaos 8U".to_string()));
+        assert_eq!(event_with("10:00 - Chaos", "Chaos").opponent("Pirates"), Some("Chaos".to_string()));
+        assert_eq!(event_with("10:00 - Home", "Home").opponent("Pirates"), None);
+        assert_eq!(event_with("10:00 - Pirates 8U", "Pirates 8U").opponent("Pirates"), None);
+    }
+
+    #[test]
+    fn test_teams_match() {
+        assert!(teams_match("Chaos 8U", "chaos"));
+        assert!(!teams_match("Chaos", "Pirates"));
+    }
+
+    #[test]
+    fn test_is_cancelled() {
+        let mut data = EventData::new(NaiveDate::from_ymd_opt(2025, 5, 3).unwrap(), "10:00 AM".to_string(), String::new(),
+            String::new(), String::new(), String::new(), String::new(), String::new(), String::new());
+        assert!(!data.is_cancelled());
+        data.notes = Some("bring water | CANCELLED - field is flooded".to_string());
+        assert!(data.is_cancelled());
+        data.notes = Some("not cancelled, just delayed".to_string());
+        assert!(!data.is_cancelled());
+    }
+}
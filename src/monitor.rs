@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::time::{sleep, Duration as TokioDuration};
+use tracing::{info, warn, error};
+
+use crate::config::Config;
+use crate::error::Result;
+use crate::groupme_client::GroupMeClient;
+use crate::service::BotService;
+
+const ROSTER_FILE: &str = "data/roster.json";
+
+/// Members who joined and left between two roster snapshots, as (user_id, nickname)
+#[derive(Debug, Default, PartialEq)]
+pub struct RosterChanges {
+    pub joined: Vec<(String, String)>,
+    pub left: Vec<(String, String)>,
+}
+
+pub fn diff_rosters(before: &BTreeMap<String, String>, after: &BTreeMap<String, String>) -> RosterChanges {
+    let missing_from = |a: &BTreeMap<String, String>, b: &BTreeMap<String, String>| -> Vec<(String, String)> {
+        a.iter()
+            .filter(|(id, _)| !b.contains_key(*id))
+            .map(|(id, nick)| (id.clone(), nick.clone()))
+            .collect()
+    };
+    RosterChanges {
+        joined: missing_from(after, before),
+        left: missing_from(before, after),
+    }
+}
+
+/// Polls the group's member list and DMs the admin when people join or leave
+pub struct MembershipMonitor {
+    bot_service: BotService,
+    groupme_client: GroupMeClient,
+    config: Config,
+}
+
+impl MembershipMonitor {
+    pub fn new(config: Config, bot_service: BotService) -> Self {
+        Self {
+            bot_service,
+            groupme_client: GroupMeClient::new(config.clone()),
+            config,
+        }
+    }
+
+    /// Start polling in the background; does nothing without a poll interval and GroupMe API access
+    pub fn start(self: Arc<Self>) {
+        if self.config.membership_poll_minutes == 0
+            || self.config.groupme_access_token.is_none()
+            || self.config.groupme_group_id.is_none()
+        {
+            info!("Membership monitor disabled");
+            return;
+        }
+
+        tokio::spawn(async move {
+            info!("Membership monitor started (every {} minutes)", self.config.membership_poll_minutes);
+
+            loop {
+                if let Err(e) = self.check_roster().await {
+                    error!("Error checking group roster: {}", e);
+                }
+                sleep(TokioDuration::from_secs(self.config.membership_poll_minutes * 60)).await;
+            }
+        });
+    }
+
+    fn load_roster() -> Option<BTreeMap<String, String>> {
+        std::fs::read_to_string(ROSTER_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+    }
+
+    fn save_roster(roster: &BTreeMap<String, String>) {
+        if let Err(e) = std::fs::create_dir_all("data") { error!("Failed to create data dir: {}", e); }
+        if let Err(e) = std::fs::write(ROSTER_FILE, serde_json::to_string_pretty(roster).unwrap_or_default()) {
+            error!("Failed to save roster: {}", e);
+        }
+    }
+
+    async fn check_roster(&self) -> Result<()> {
+        let current: BTreeMap<String, String> = self.groupme_client.list_members().await?
+            .into_iter()
+            .map(|m| (m.user_id, m.nickname))
+            .collect();
+
+        // The first snapshot is just a baseline - don't announce the whole group as new
+        let Some(previous) = Self::load_roster() else {
+            info!("Recorded initial roster of {} members", current.len());
+            Self::save_roster(&current);
+            return Ok(());
+        };
+
+        let changes = diff_rosters(&previous, &current);
+        for (user_id, nickname) in &changes.joined {
+            self.notify_admin(&format!("👋 {} ({}) joined the group.", nickname, user_id)).await;
+        }
+        for (user_id, nickname) in &changes.left {
+            let message = self.departure_message(user_id, nickname).await;
+            self.notify_admin(&message).await;
+        }
+
+        if changes != RosterChanges::default() {
+            Self::save_roster(&current);
+        }
+        Ok(())
+    }
+
+    /// Departure notice, listing any upcoming volunteer spots they still hold
+    async fn departure_message(&self, user_id: &str, nickname: &str) -> String {
+        let mut message = format!("👋 {} ({}) left the group.", nickname, user_id);
+        match self.bot_service.upcoming_assignments_for(nickname).await {
+            Ok(assignments) if !assignments.is_empty() => {
+                let spots: Vec<String> = assignments
+                    .iter()
+                    .map(|(date, role)| format!("{} on {}", role, date.format("%-m/%-d")))
+                    .collect();
+                message.push_str(&format!(
+                    "\nThey're still signed up for: {}.\nTo free those spots, post in the group: @{} clear assignments for {}",
+                    spots.join(", "),
+                    self.config.groupme_bot_name,
+                    nickname
+                ));
+            }
+            Ok(_) => {}
+            Err(e) => warn!("Could not check assignments for {}: {}", nickname, e),
+        }
+        message
+    }
+
+    async fn notify_admin(&self, message: &str) {
+        if let Err(e) = self.bot_service.alert_admin(message).await {
+            warn!("Failed to DM admin about roster change: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_rosters() {
+        let before: BTreeMap<String, String> = [("1", "Jane"), ("2", "Mike")]
+            .into_iter()
+            .map(|(id, nick)| (id.to_string(), nick.to_string()))
+            .collect();
+        let after: BTreeMap<String, String> = [("2", "Mike"), ("3", "Ana")]
+            .into_iter()
+            .map(|(id, nick)| (id.to_string(), nick.to_string()))
+            .collect();
+
+        let changes = diff_rosters(&before, &after);
+        assert_eq!(changes.joined, vec![("3".to_string(), "Ana".to_string())]);
+        assert_eq!(changes.left, vec![("1".to_string(), "Jane".to_string())]);
+    }
+}
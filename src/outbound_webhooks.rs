@@ -0,0 +1,123 @@
+use chrono::{NaiveDate, Utc};
+use serde::Serialize;
+use tracing::warn;
+
+use crate::config::Config;
+
+/// Something that happened which outside tools (Zapier, IFTTT, ...) may want to hear about
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum WebhookEvent {
+    VolunteerAssigned { date: NaiveDate, matchup: String, role: String, person: String },
+    /// A moderator cancelled a game with "cancel game"; `note` is the game's note afterwards
+    GameCancelled { date: NaiveDate, note: String },
+    /// `reminder` is "24h", "briefing" (game-day morning) or "15m"
+    ReminderSent { date: NaiveDate, matchup: String, reminder: String },
+}
+
+impl WebhookEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            WebhookEvent::VolunteerAssigned { .. } => "volunteer_assigned",
+            WebhookEvent::GameCancelled { .. } => "game_cancelled",
+            WebhookEvent::ReminderSent { .. } => "reminder_sent",
+        }
+    }
+}
+
+/// One OUTBOUND_WEBHOOKS entry; `event` None means every event
+#[derive(Debug, Clone, PartialEq)]
+struct Subscription {
+    event: Option<String>,
+    url: String,
+}
+
+/// Parse OUTBOUND_WEBHOOKS: comma-separated `[event=]url` entries
+fn parse_subscriptions(raw: &str) -> Vec<Subscription> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| match entry.split_once('=') {
+            // URLs can contain '=' in their query, so only a leading event name counts
+            Some((event, url)) if !event.contains("://") => Subscription {
+                event: Some(event.trim().to_lowercase()),
+                url: url.trim().to_string(),
+            },
+            _ => Subscription { event: None, url: entry.to_string() },
+        })
+        .collect()
+}
+
+/// POSTs events as JSON to the configured URLs without holding up the chat reply
+#[derive(Clone)]
+pub struct WebhookNotifier {
+    subscriptions: Vec<Subscription>,
+    team_name: String,
+    client: reqwest::Client,
+}
+
+impl WebhookNotifier {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            subscriptions: parse_subscriptions(config.outbound_webhooks.as_deref().unwrap_or_default()),
+            team_name: config.team_name.clone(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub fn emit(&self, event: WebhookEvent) {
+        let urls: Vec<String> = self.subscriptions
+            .iter()
+            .filter(|s| s.event.as_deref().is_none_or(|e| e == event.name()))
+            .map(|s| s.url.clone())
+            .collect();
+        if urls.is_empty() {
+            return;
+        }
+
+        let mut payload = serde_json::to_value(&event).unwrap_or_default();
+        payload["team"] = self.team_name.clone().into();
+        payload["sent_at"] = Utc::now().to_rfc3339().into();
+
+        for url in urls {
+            let client = self.client.clone();
+            let payload = payload.clone();
+            tokio::spawn(async move {
+                match client.post(&url).json(&payload).send().await {
+                    Ok(response) if !response.status().is_success() => {
+                        warn!("Outbound webhook {} returned {}", url, response.status());
+                    }
+                    Ok(_) => {}
+                    Err(e) => warn!("Outbound webhook {} failed: {}", url, e),
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_subscriptions() {
+        let subs = parse_subscriptions("https://hooks.zapier.com/a?x=1, volunteer_assigned=https://example.com/b,,");
+        assert_eq!(subs, vec![
+            Subscription { event: None, url: "https://hooks.zapier.com/a?x=1".to_string() },
+            Subscription { event: Some("volunteer_assigned".to_string()), url: "https://example.com/b".to_string() },
+        ]);
+    }
+
+    #[test]
+    fn test_event_payload() {
+        let event = WebhookEvent::ReminderSent {
+            date: NaiveDate::from_ymd_opt(2025, 5, 3).unwrap(),
+            matchup: "Pirates vs Chaos".to_string(),
+            reminder: "24h".to_string(),
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["event"], "reminder_sent");
+        assert_eq!(json["date"], "2025-05-03");
+        assert_eq!(json["reminder"], "24h");
+    }
+}
@@ -3,41 +3,246 @@ use crate::conversation_context::ConversationContextStore;
 use crate::error::{BotError, Result};
 use crate::models::BotCommand;
 use crate::conversational_parser::{ConversationalParser, ParsedIntent};
-use std::sync::{Arc, Mutex};
+use crate::features::{Feature, FeatureFlags};
+use crate::game_mode::GameMode;
+use crate::mentions::{self, BotName, MentionMatcher};
+use crate::plugins::PluginManager;
+use crate::synonyms;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
 
 pub struct CommandParser {
-    bot_name: String,
-    failed_attempts: Arc<Mutex<u32>>,
+    /// Shared with the conversational parser and BotService so a rename applies everywhere
+    bot_name: Arc<RwLock<BotName>>,
+    mentions: MentionMatcher,
+    /// Misunderstood messages in a row, by user id
+    failed_attempts: Arc<Mutex<HashMap<String, FailedAttempts>>>,
+    /// UNKNOWN_REPLY_LIMIT (0 = always reply) and UNKNOWN_COOLDOWN_MINUTES
+    unknown_reply_limit: u32,
+    unknown_cooldown: Duration,
     context_store: ConversationContextStore,
     conversational_parser: ConversationalParser,
+    plugins: PluginManager,
+    features: FeatureFlags,
+    /// Recent ids of the bot's own posts, so a GroupMe reply to one counts as talking to the bot
+    bot_message_ids: Arc<Mutex<VecDeque<String>>>,
+    /// CONTEXTUAL_VOLUNTEERING: false means only @mentions are ever answered
+    contextual_volunteering: bool,
+    /// While it's on, score updates and photos don't need a mention
+    game_mode: GameMode,
+}
+
+/// How many of the bot's own posts to remember for reply detection
+const REMEMBERED_BOT_MESSAGES: usize = 50;
+
+#[derive(Default)]
+struct FailedAttempts {
+    in_a_row: u32,
+    quiet_until: Option<Instant>,
+}
+
+/// What to do about a message the parser didn't understand
+#[derive(Debug, PartialEq)]
+enum UnknownReply {
+    Usual,
+    /// Too many in a row: DM the help text and go quiet for the cooldown
+    HelpByDm,
+    Quiet,
 }
 
 impl CommandParser {
     pub fn new(bot_name: String) -> Self {
-        let conversational_parser = ConversationalParser::new(bot_name.clone());
+        let bot_name = Arc::new(RwLock::new(BotName::new(bot_name)));
+        let mentions = MentionMatcher::new(bot_name.clone());
+        let conversational_parser = ConversationalParser::with_mentions(mentions.clone());
         Self { 
             bot_name,
-            failed_attempts: Arc::new(Mutex::new(0)),
+            mentions,
+            failed_attempts: Arc::new(Mutex::new(HashMap::new())),
+            unknown_reply_limit: 3,
+            unknown_cooldown: Duration::from_secs(30 * 60),
             conversational_parser,
             context_store: ConversationContextStore::new(3),
+            plugins: PluginManager::default(),
+            features: FeatureFlags::default(),
+            bot_message_ids: Arc::new(Mutex::new(VecDeque::new())),
+            contextual_volunteering: true,
+            game_mode: GameMode::default(),
+        }
+    }
+
+    /// Also answer to these names (BOT_ALIASES), e.g. after the group renames the bot in GroupMe
+    pub fn with_bot_aliases(mut self, aliases: &str) -> Self {
+        self.mentions = self.mentions.with_aliases(mentions::parse_aliases(aliases));
+        self.conversational_parser = self.conversational_parser.with_mention_matcher(self.mentions.clone());
+        self
+    }
+
+    /// Resolve dates in messages in the team's timezone (TEAM_TIMEZONE) rather than the server's
+    pub fn with_timezone(mut self, timezone: Option<chrono_tz::Tz>) -> Self {
+        self.conversational_parser = self.conversational_parser.with_timezone(timezone);
+        self
+    }
+
+    /// Turn off answering messages that don't mention the bot, whatever the feature flags say
+    pub fn with_contextual_volunteering(mut self, enabled: bool) -> Self {
+        self.contextual_volunteering = enabled;
+        self
+    }
+
+    /// After `limit` misunderstood messages in a row from one user, DM them help and stop
+    /// answering their misunderstood messages for `cooldown_minutes`
+    pub fn with_unknown_reply_limit(mut self, limit: u32, cooldown_minutes: u64) -> Self {
+        self.unknown_reply_limit = limit;
+        self.unknown_cooldown = Duration::from_secs(cooldown_minutes * 60);
+        self
+    }
+
+    /// Count a misunderstood message from `user_id`. The first `unknown_reply_limit` get the
+    /// usual reply; the next one gets help by DM, and the rest are ignored until the cooldown ends.
+    fn note_unknown(&self, user_id: &str) -> UnknownReply {
+        if self.unknown_reply_limit == 0 {
+            return UnknownReply::Usual;
+        }
+        let Ok(mut attempts) = self.failed_attempts.lock() else {
+            return UnknownReply::Usual;
+        };
+        let attempts = attempts.entry(user_id.to_string()).or_default();
+        if attempts.quiet_until.is_some_and(|until| Instant::now() < until) {
+            return UnknownReply::Quiet;
+        }
+        attempts.quiet_until = None;
+        attempts.in_a_row += 1;
+        if attempts.in_a_row <= self.unknown_reply_limit {
+            return UnknownReply::Usual;
+        }
+        attempts.in_a_row = 0;
+        attempts.quiet_until = Some(Instant::now() + self.unknown_cooldown);
+        UnknownReply::HelpByDm
+    }
+
+    /// A message that was understood ends the streak and any cooldown
+    fn clear_unknowns(&self, user_id: &str) {
+        if let Ok(mut attempts) = self.failed_attempts.lock() {
+            attempts.remove(user_id);
         }
     }
 
-    pub async fn parse_message(&self, text: &str, sender_name: Option<&str>, user_id: Option<&str>, attachments: &[crate::models::Attachment]) -> Result<Option<BotCommand>> {
+    /// Note a post the bot made (seen coming back through the callback) so replies to it are recognized
+    pub fn remember_bot_message(&self, sender_name: &str, message_id: &str) {
+        if !self.mentions.is_bot_name(sender_name) || message_id.is_empty() {
+            return;
+        }
+        if let Ok(mut ids) = self.bot_message_ids.lock() {
+            ids.push_back(message_id.to_string());
+            while ids.len() > REMEMBERED_BOT_MESSAGES {
+                ids.pop_front();
+            }
+        }
+    }
+
+    /// Id of the bot post this message replies to, if it's a GroupMe reply to the bot
+    fn replied_bot_post(&self, attachments: &[crate::models::Attachment]) -> Option<String> {
+        let ids = self.bot_message_ids.lock().ok()?;
+        attachments
+            .iter()
+            .filter(|a| a.attachment_type == "reply")
+            .filter_map(|a| a.reply_id.as_ref())
+            .find(|id| ids.contains(id))
+            .cloned()
+    }
+
+    /// Share BotService's game mode so "game mode on" relaxes mentions here
+    pub fn with_game_mode(mut self, game_mode: GameMode) -> Self {
+        self.game_mode = game_mode;
+        self
+    }
+
+    /// Share BotService's feature flags so admin toggles apply to parsing too
+    pub fn with_features(mut self, features: FeatureFlags) -> Self {
+        self.features = features;
+        self
+    }
+
+    /// Let plugin triggers claim mentions before the built-in commands
+    pub fn with_plugins(mut self, plugins: PluginManager) -> Self {
+        self.plugins = plugins;
+        self
+    }
+
+    /// Handle to the live bot name, for components that need to follow a rename
+    pub fn bot_name_handle(&self) -> Arc<RwLock<BotName>> {
+        self.bot_name.clone()
+    }
+
+    fn bot_mention(&self) -> String {
+        format!("@{}", self.bot_name.read().unwrap_or_else(|e| e.into_inner())).to_lowercase()
+    }
+
+    /// `group_id` is the group the message came from, for per-group feature flags
+    pub async fn parse_message(&self, text: &str, sender_name: Option<&str>, user_id: Option<&str>, group_id: Option<&str>, attachments: &[crate::models::Attachment]) -> Result<Option<BotCommand>> {
         let text = text.trim();
-        let mentioned_bot = text.to_lowercase().contains(&format!("@{}", self.bot_name).to_lowercase());
+        if let Some(uid) = user_id {
+            if let Some(reply) = self.check_confirmation_reply(text, uid).await {
+                return reply;
+            }
+        }
+
+        let mentioned_bot = self.mentions.is_mentioned(text);
         let active_context = if let Some(uid) = user_id { self.context_store.get_active_context(uid).await } else { None };
         let has_volunteer_context = active_context.as_ref().map_or(false, |ctx| ctx.volunteer_intent);
+        let replied_post = if mentioned_bot { None } else { self.replied_bot_post(attachments) };
+        let replying_to_bot = replied_post.is_some();
 
-        
-        let confidence = self.calculate_volunteer_confidence(text, has_volunteer_context, mentioned_bot);
-        let should_process = mentioned_bot || (confidence >= 60 && has_volunteer_context);
+        // Game mode: "4-2" and photos are for the bot, mention or not
+        if self.game_mode.is_active().await {
+            let stripped = self.mentions.strip(text);
+            if let Some((ours, theirs)) = crate::game_mode::parse_score_update(stripped.as_deref().unwrap_or(text)) {
+                return Ok(Some(BotCommand::LiveScore(ours, theirs)));
+            }
+            let photos: Vec<String> = attachments
+                .iter()
+                .filter(|a| a.attachment_type == "image")
+                .filter_map(|a| a.url.clone())
+                .collect();
+            if !photos.is_empty() && !mentioned_bot {
+                return Ok(Some(BotCommand::CollectPhotos(photos)));
+            }
+        }
+
+        // 🙋 in reply to a game reminder claims a spot for that game
+        if let (Some(post_id), Some(name)) = (&replied_post, sender_name) {
+            if self.contextual_volunteering && crate::game_posts::is_hand_raise(text) {
+                return Ok(Some(BotCommand::ClaimOpenRole(post_id.clone(), name.to_string())));
+            }
+        }
+
+        let confidence = self.calculate_volunteer_confidence(text, has_volunteer_context || replying_to_bot, mentioned_bot);
+        // Without a mention, banter after a volunteer question shouldn't set the bot off: the message
+        // has to be a reply to the bot, or name a role and a game ("I'll do snacks saturday")
+        let should_process = mentioned_bot
+            || (self.contextual_volunteering
+                && confidence >= 60
+                && (replying_to_bot || (has_volunteer_context && self.names_role_and_game(text)))
+                && self.features.is_enabled(Feature::NlpVolunteering, group_id).await);
         
         if !should_process {
             return Ok(None);
         }
 
-        if let Some(intent) = self.conversational_parser.parse_message(text, sender_name, attachments) {
+        if mentioned_bot {
+            if let Some(name) = self.plugins.find_trigger(text) {
+                return Ok(Some(BotCommand::RunPlugin(name, text.to_string())));
+            }
+        }
+
+        let intent = if mentioned_bot {
+            self.conversational_parser.parse_message(text, sender_name, attachments)
+        } else {
+            self.conversational_parser.parse_volunteer_statement(text, sender_name)
+        };
+        if let Some(intent) = intent {
             let is_volunteer_intent = matches!(intent, ParsedIntent::Volunteer { .. });
             
             if mentioned_bot && is_volunteer_intent {
@@ -50,20 +255,78 @@ impl CommandParser {
                 }
             }
             
-            return self.intent_to_command(intent, text);
+            if let Some(uid) = user_id.filter(|_| mentioned_bot) {
+                if !matches!(intent, ParsedIntent::Unknown) {
+                    self.clear_unknowns(uid);
+                } else {
+                    match self.note_unknown(uid) {
+                        UnknownReply::Usual => {}
+                        UnknownReply::HelpByDm => return Ok(Some(BotCommand::HelpByDm)),
+                        UnknownReply::Quiet => return Ok(None),
+                    }
+                }
+            }
+
+            if matches!(intent, ParsedIntent::Unknown) && !self.features.is_enabled(Feature::WittyResponses, group_id).await {
+                return Err(BotError::InvalidCommand(format!(
+                    "🤔 I didn't understand that. Try \"{} help\" to see what I can do.",
+                    self.bot_mention()
+                )));
+            }
+
+            let command = self.intent_to_command(intent, text)?;
+            return Ok(self.hold_for_confirmation(command, sender_name, user_id).await);
         }
 
         // If no intent detected, shouldn't happen but return None
         Ok(None)
     }
 
+    /// Handle a bare "confirm"/"cancel" answering a pending destructive command.
+    /// No mention is needed since the user is replying to the bot's own prompt.
+    async fn check_confirmation_reply(&self, text: &str, user_id: &str) -> Option<Result<Option<BotCommand>>> {
+        let reply = self.mentions.strip(text).unwrap_or_else(|| text.to_string()).to_lowercase();
+        let reply = reply.trim().trim_matches(|c: char| !c.is_alphanumeric());
+        if reply != "confirm" && reply != "cancel" {
+            return None;
+        }
+
+        let pending = self.context_store.take_pending_action(user_id).await?;
+        if reply == "cancel" {
+            return Some(Ok(Some(BotCommand::CancelPending)));
+        }
+        if pending.is_expired() {
+            return Some(Err(BotError::InvalidCommand(
+                "⌛ That request expired. Send the command again if you still want it.".to_string()
+            )));
+        }
+        Some(Ok(Some(BotCommand::Confirmed(Box::new(pending.command)))))
+    }
+
+    /// Park a command the service wants confirmed; the user's next "confirm" runs it
+    pub async fn park_pending_action(&self, user_id: &str, user_name: &str, command: BotCommand) {
+        self.context_store.set_pending_action(user_id.to_string(), user_name.to_string(), command).await;
+    }
+
+    /// Park destructive commands in the user's context and ask for confirmation instead
+    async fn hold_for_confirmation(&self, command: Option<BotCommand>, sender_name: Option<&str>, user_id: Option<&str>) -> Option<BotCommand> {
+        match (command, user_id) {
+            (Some(cmd), Some(uid)) if cmd.requires_confirmation() => {
+                let name = sender_name.unwrap_or_default().to_string();
+                self.context_store.set_pending_action(uid.to_string(), name, cmd.clone()).await;
+                Some(BotCommand::RequestConfirmation(Box::new(cmd)))
+            }
+            (command, _) => command,
+        }
+    }
+
     fn intent_to_command(&self, intent: ParsedIntent, original_text: &str) -> Result<Option<BotCommand>> {
         match intent {
             ParsedIntent::Volunteer { roles, date, person, relative_game } => {
                 self.handle_volunteer_intent(roles, date, person, relative_game, original_text)
             }
-            ParsedIntent::GameQuery { category, count, relative: _ } => {
-                self.handle_game_query_intent(category, count)
+            ParsedIntent::GameQuery { categories, count, relative: _, date } => {
+                self.handle_game_query_intent(categories, count, date)
             }
             ParsedIntent::VolunteerQuery { date } => {
                 Ok(Some(BotCommand::ShowVolunteers(date)))
@@ -78,9 +341,11 @@ impl CommandParser {
                 Err(BotError::InvalidCommand(message))
             },
             ParsedIntent::RemoveVolunteer { person, role, date } => {
+                let role = synonyms::canonical_role(&role).map(String::from).unwrap_or(role);
                 Ok(Some(BotCommand::RemoveVolunteer(person, role, date)))
             },
             ParsedIntent::AssignVolunteer { person, role, date } => {
+                let role = synonyms::canonical_role(&role).map(String::from).unwrap_or(role);
                 Ok(Some(BotCommand::AssignVolunteer(person, role, date)))
             },
             ParsedIntent::AddModerator { user_id } => {
@@ -95,6 +360,197 @@ impl CommandParser {
             ParsedIntent::ListBotMessages { count } => {
                 Ok(Some(BotCommand::ListBotMessages(count)))
             },
+            ParsedIntent::WhoIs { user_id } => {
+                Ok(Some(BotCommand::WhoIs(user_id)))
+            },
+            ParsedIntent::SetNote { date, note } => {
+                Ok(Some(BotCommand::SetGameNote(date, note)))
+            },
+            ParsedIntent::ClearNote { date } => {
+                Ok(Some(BotCommand::ClearGameNote(date)))
+            },
+            ParsedIntent::CancelGame { date, reason } => {
+                Ok(Some(BotCommand::CancelGame(date, reason)))
+            },
+            ParsedIntent::RequestBringItems { event, items } => {
+                Ok(Some(BotCommand::RequestBringItems(event, items)))
+            },
+            ParsedIntent::BringItem { event, item, person } => {
+                match person {
+                    Some(p) => Ok(Some(BotCommand::BringItem(event, item, p))),
+                    None => Err(BotError::InvalidCommand(
+                        format!("🎉 Thanks for bringing {}! Could you tell me your name?", item)
+                    )),
+                }
+            },
+            ParsedIntent::BringListStatus { event } => {
+                Ok(Some(BotCommand::BringListStatus(event)))
+            },
+            ParsedIntent::ClearBringList { event } => {
+                Ok(Some(BotCommand::ClearBringList(event)))
+            },
+            ParsedIntent::RecordResult { date, opponent, our_score, their_score } => {
+                Ok(Some(BotCommand::RecordResult(date, opponent, our_score, their_score)))
+            },
+            ParsedIntent::HeadToHead { opponent } => {
+                Ok(Some(BotCommand::HeadToHead(opponent)))
+            },
+            ParsedIntent::LogStats { date, player, counts } => {
+                if counts.is_empty() {
+                    return Err(BotError::InvalidCommand(
+                        format!("📈 What did {} do? Try \"stat {} 2-3, 1 RBI\"", player, player)
+                    ));
+                }
+                Ok(Some(BotCommand::LogPlayerStats(date, player, counts)))
+            },
+            ParsedIntent::PlayerStats { player } => {
+                Ok(Some(BotCommand::PlayerStats(player)))
+            },
+            ParsedIntent::TeamStats => {
+                Ok(Some(BotCommand::TeamStats))
+            },
+            ParsedIntent::RenameBot { name } => {
+                Ok(Some(BotCommand::RenameBot(name)))
+            },
+            ParsedIntent::SetAvatar { url } => {
+                Ok(Some(BotCommand::SetBotAvatar(url)))
+            },
+            ParsedIntent::ClearAssignments { person } => {
+                Ok(Some(BotCommand::ClearAssignments(person)))
+            },
+            ParsedIntent::ClearVolunteers { date } => {
+                Ok(Some(BotCommand::ClearVolunteers(date)))
+            },
+            ParsedIntent::SetGameMode { on } => {
+                Ok(Some(BotCommand::SetGameMode(on)))
+            },
+            ParsedIntent::ScheduleMessage { date, time, text } => {
+                Ok(Some(BotCommand::ScheduleMessage(date, time, text)))
+            },
+            ParsedIntent::ListScheduledMessages => {
+                Ok(Some(BotCommand::ListScheduledMessages))
+            },
+            ParsedIntent::CancelScheduledMessage { id } => {
+                Ok(Some(BotCommand::CancelScheduledMessage(id)))
+            }
+            ParsedIntent::RecordAbsence { who, start, end } => {
+                Ok(Some(BotCommand::RecordAbsence(who, start, end)))
+            }
+            ParsedIntent::ListAbsences => {
+                Ok(Some(BotCommand::ListAbsences))
+            }
+            ParsedIntent::RemoveAbsence { id } => {
+                Ok(Some(BotCommand::RemoveAbsence(id)))
+            }
+            ParsedIntent::BlockWord { word } => {
+                Ok(Some(BotCommand::BlockWord(word)))
+            }
+            ParsedIntent::UnblockWord { word } => {
+                Ok(Some(BotCommand::UnblockWord(word)))
+            }
+            ParsedIntent::ListBlockedWords => {
+                Ok(Some(BotCommand::ListBlockedWords))
+            }
+            ParsedIntent::VolunteerAnalytics => {
+                Ok(Some(BotCommand::VolunteerAnalytics))
+            }
+            ParsedIntent::Version => {
+                Ok(Some(BotCommand::Version))
+            }
+            ParsedIntent::AddSpecialEvent { title, date, time, location } => {
+                Ok(Some(BotCommand::AddSpecialEvent(title, date, time, location)))
+            }
+            ParsedIntent::ListSpecialEvents => {
+                Ok(Some(BotCommand::ListSpecialEvents))
+            }
+            ParsedIntent::RemoveSpecialEvent { id } => {
+                Ok(Some(BotCommand::RemoveSpecialEvent(id)))
+            }
+            ParsedIntent::SetEventReminder { kind, lead_hours, text } => {
+                Ok(Some(BotCommand::SetEventReminder(kind, lead_hours, text)))
+            }
+            ParsedIntent::ShowEventReminders { kind } => {
+                Ok(Some(BotCommand::ShowEventReminders(kind)))
+            }
+            ParsedIntent::ResetEventReminders { kind } => {
+                Ok(Some(BotCommand::ResetEventReminders(kind)))
+            },
+            ParsedIntent::GamePhotos { date } => {
+                Ok(Some(BotCommand::GamePhotos(date)))
+            },
+            ParsedIntent::CopyVolunteers { from, to } => {
+                Ok(Some(BotCommand::CopyVolunteers(from, to)))
+            },
+            ParsedIntent::ModeratorDigest => {
+                Ok(Some(BotCommand::ModeratorDigest))
+            },
+            ParsedIntent::ImportSchedule { url } => {
+                Ok(Some(BotCommand::ImportSchedule(url)))
+            },
+            ParsedIntent::SetVenueTimezone { venue, timezone } => {
+                Ok(Some(BotCommand::SetVenueTimezone(venue, timezone)))
+            },
+            ParsedIntent::ListVenues => {
+                Ok(Some(BotCommand::ListVenues))
+            },
+            ParsedIntent::StartTournament { name } => {
+                Ok(Some(BotCommand::StartTournament(name)))
+            },
+            ParsedIntent::SetBracketGame { number, date, time, location, opponent, condition } => {
+                let Some(date) = date else {
+                    return Err(BotError::InvalidCommand(
+                        format!("🏆 What day is game {}? Try \"bracket game {} 6/14 3pm at Tournament Park\"", number, number)
+                    ));
+                };
+                Ok(Some(BotCommand::SetBracketGame(crate::tournament::BracketGame {
+                    number,
+                    date,
+                    time,
+                    location,
+                    opponent,
+                    condition,
+                    outcome: None,
+                })))
+            },
+            ParsedIntent::BracketResult { number, outcome } => {
+                Ok(Some(BotCommand::RecordBracketResult(number, outcome)))
+            },
+            ParsedIntent::ShowBracket => {
+                Ok(Some(BotCommand::ShowBracket))
+            },
+            ParsedIntent::ClearTournament => {
+                Ok(Some(BotCommand::ClearTournament))
+            },
+            ParsedIntent::SetTournamentDates { start, end } => match (start, end) {
+                (Some(start), Some(end)) if start <= end => Ok(Some(BotCommand::SetTournamentDates(start, end))),
+                _ => Err(BotError::InvalidCommand(
+                    "🏆 I couldn't read those dates. Try \"tournament dates 6/13-6/15\"".to_string()
+                )),
+            },
+            ParsedIntent::SetTournamentLogistics { label, value } => {
+                Ok(Some(BotCommand::SetTournamentLogistics(label, value)))
+            },
+            ParsedIntent::TournamentInfo => {
+                Ok(Some(BotCommand::TournamentInfo))
+            },
+            ParsedIntent::SignupLink { date } => {
+                Ok(Some(BotCommand::SignupLink(date)))
+            },
+            ParsedIntent::QrCode { target } => {
+                Ok(Some(BotCommand::QrCode(target)))
+            },
+            ParsedIntent::AddToCalendar { date } => {
+                Ok(Some(BotCommand::AddToCalendar(date)))
+            },
+            ParsedIntent::Weather { date } => {
+                Ok(Some(BotCommand::Weather(date)))
+            },
+            ParsedIntent::SetFeature { feature, enabled, all_groups } => {
+                Ok(Some(BotCommand::SetFeature(feature, enabled, all_groups)))
+            },
+            ParsedIntent::ListFeatures => {
+                Ok(Some(BotCommand::ListFeatures))
+            },
             ParsedIntent::Unknown => {
                 // Return a witty response instead of an error
                 Err(BotError::InvalidCommand(self.conversational_parser.get_witty_response()))
@@ -153,15 +609,16 @@ impl CommandParser {
 
     fn handle_game_query_intent(
         &self,
-        category: Option<String>,
-        count: Option<usize>
+        categories: Vec<String>,
+        count: Option<usize>,
+        date: Option<NaiveDate>,
     ) -> Result<Option<BotCommand>> {
-        match (category, count) {
-            (Some(cat), _) => {
-                // Specific category requested
-                Ok(Some(BotCommand::NextGameCategory(cat)))
+        match (categories.is_empty(), count) {
+            (false, _) => {
+                // Specific categories requested
+                Ok(Some(BotCommand::NextGameCategory(categories, date)))
             }
-            (None, Some(n)) => {
+            (true, Some(n)) => {
                 // Multiple games requested
                 if n > 0 && n <= 10 {
                     Ok(Some(BotCommand::NextGames(n)))
@@ -169,13 +626,17 @@ impl CommandParser {
                     Ok(Some(BotCommand::NextGames(3))) // Default to 3
                 }
             }
-            (None, None) => {
+            (true, None) => {
                 // Just asking about the next game
                 Ok(Some(BotCommand::NextGame))
             }
         }
     }
 
+    fn names_role_and_game(&self, text: &str) -> bool {
+        !synonyms::roles_in(&text.to_lowercase()).is_empty() && self.conversational_parser.mentions_game_or_date(text)
+    }
+
     fn calculate_volunteer_confidence(&self, text: &str, has_context: bool, mentioned_bot: bool) -> u32 {
         let text_lower = text.to_lowercase();
         let mut confidence = 0u32;
@@ -215,15 +676,34 @@ mod tests {
         let parser = create_parser();
         
         // These should be understood conversationally
-        let result = parser.parse_message("@TestBot I've got snacks for Saturday John", None, None, &[]).await;
+        let result = parser.parse_message("@TestBot I've got snacks for Saturday John", None, None, None, &[]).await;
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_repeated_unknowns_get_help_by_dm_then_quiet() {
+        let parser = create_parser();
+        let unknown = || parser.parse_message("@TestBot blah blah random stuff", None, Some("u1"), None, &[]);
+
+        for _ in 0..3 {
+            assert!(unknown().await.is_err());
+        }
+        assert!(matches!(unknown().await, Ok(Some(BotCommand::HelpByDm))));
+        assert!(matches!(unknown().await, Ok(None)));
+        // Someone else still gets the usual reply
+        assert!(parser.parse_message("@TestBot blah blah random stuff", None, Some("u2"), None, &[]).await.is_err());
+
+        // Getting one right ends the cooldown
+        let result = parser.parse_message("@TestBot when's the next game?", None, Some("u1"), None, &[]).await;
+        assert!(matches!(result, Ok(Some(BotCommand::NextGame))));
+        assert!(unknown().await.is_err());
+    }
+
     #[tokio::test]
     async fn test_conversational_game_query() {
         let parser = create_parser();
         
-        let result = parser.parse_message("@TestBot when's the next game?", None, None, &[]).await;
+        let result = parser.parse_message("@TestBot when's the next game?", None, None, None, &[]).await;
         assert!(result.is_ok());
         assert!(matches!(result.unwrap(), Some(BotCommand::NextGame)));
     }
@@ -232,7 +712,7 @@ mod tests {
     async fn test_unknown_intent_returns_friendly_message() {
         let parser = create_parser();
         
-        let result = parser.parse_message("@TestBot blah blah random stuff", None, None, &[]).await;
+        let result = parser.parse_message("@TestBot blah blah random stuff", None, None, None, &[]).await;
         // Should return an error with a friendly message, not panic
         assert!(result.is_err());
         if let Err(BotError::InvalidCommand(msg)) = result {
@@ -254,7 +734,7 @@ mod tests {
     async fn test_help_intent() {
         let parser = create_parser();
         
-        let result = parser.parse_message("@TestBot help", None, None, &[]).await;
+        let result = parser.parse_message("@TestBot help", None, None, None, &[]).await;
         assert!(result.is_ok());
         assert!(matches!(result.unwrap(), Some(BotCommand::Commands)));
     }
@@ -263,7 +743,7 @@ mod tests {
     async fn test_team_spirit() {
         let parser = create_parser();
         
-        let result = parser.parse_message("@TestBot let's go pirates!", None, None, &[]).await;
+        let result = parser.parse_message("@TestBot let's go pirates!", None, None, None, &[]).await;
         assert!(result.is_ok());
         assert!(matches!(result.unwrap(), Some(BotCommand::LetsGo(_))));
     }
@@ -272,18 +752,122 @@ mod tests {
     async fn test_volunteer_next_game() {
         let parser = create_parser();
         
-        let result = parser.parse_message("@TestBot Hobbs have snacks for the next game", None, None, &[]).await;
+        let result = parser.parse_message("@TestBot Hobbs have snacks for the next game", None, None, None, &[]).await;
         assert!(result.is_ok());
         assert!(matches!(result.unwrap(), Some(BotCommand::VolunteerNextGame(_, _))));
     }
     
+    #[tokio::test]
+    async fn test_destructive_command_requires_confirmation() {
+        let parser = create_parser();
+
+        let result = parser.parse_message("@TestBot remove jane from snacks", Some("Coach"), Some("111"), None, &[]).await;
+        assert!(matches!(result.unwrap(), Some(BotCommand::RequestConfirmation(_))));
+
+        // Someone else saying "confirm" doesn't trigger it
+        let result = parser.parse_message("confirm", Some("Other"), Some("222"), None, &[]).await;
+        assert!(matches!(result.unwrap(), None));
+
+        let result = parser.parse_message("confirm", Some("Coach"), Some("111"), None, &[]).await;
+        match result.unwrap() {
+            Some(BotCommand::Confirmed(cmd)) => assert!(matches!(*cmd, BotCommand::RemoveVolunteer(..))),
+            other => panic!("Expected confirmed command, got {:?}", other),
+        }
+
+        // The pending action is consumed
+        let result = parser.parse_message("confirm", Some("Coach"), Some("111"), None, &[]).await;
+        assert!(matches!(result.unwrap(), None));
+    }
+
+    #[tokio::test]
+    async fn test_volunteering_keeps_a_pending_confirmation() {
+        let parser = create_parser();
+
+        let result = parser.parse_message("@TestBot remove jane from snacks", Some("Coach"), Some("111"), None, &[]).await;
+        assert!(matches!(result.unwrap(), Some(BotCommand::RequestConfirmation(_))));
+
+        // Signing up in between refreshes the conversation without dropping the parked command
+        let result = parser.parse_message("@TestBot I'll do livestream", Some("Coach"), Some("111"), None, &[]).await;
+        assert!(matches!(result.unwrap(), Some(BotCommand::VolunteerNextGame(..))));
+
+        let result = parser.parse_message("confirm", Some("Coach"), Some("111"), None, &[]).await;
+        match result.unwrap() {
+            Some(BotCommand::Confirmed(cmd)) => assert!(matches!(*cmd, BotCommand::RemoveVolunteer(..))),
+            other => panic!("Expected confirmed command, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_volunteer_defaults_to_next_game() {
         let parser = create_parser();
         
         // No date specified - should default to next game
-        let result = parser.parse_message("@TestBot Hobbs have snacks", None, None, &[]).await;
+        let result = parser.parse_message("@TestBot Hobbs have snacks", None, None, None, &[]).await;
         assert!(result.is_ok());
         assert!(matches!(result.unwrap(), Some(BotCommand::VolunteerNextGame(_, _))));
     }
+
+    #[tokio::test]
+    async fn test_chatter_without_mention_is_ignored() {
+        let parser = create_parser();
+        parser.parse_message("@TestBot I'll do snacks", Some("Jane"), Some("111"), None, &[]).await.unwrap();
+
+        // Follow-up banter in the same conversation doesn't name a role and a game
+        let result = parser.parse_message("I got it, will do later lol", Some("Jane"), Some("111"), None, &[]).await;
+        assert!(matches!(result, Ok(None)));
+        let result = parser.parse_message("I'll do scoreboard Saturday", Some("Jane"), Some("111"), None, &[]).await;
+        assert!(matches!(result, Ok(Some(BotCommand::Volunteer(_, _, _)))));
+
+        // A GroupMe reply to one of the bot's posts counts as talking to it
+        parser.remember_bot_message("TestBot", "m1");
+        let reply = crate::models::Attachment {
+            attachment_type: "reply".to_string(),
+            user_ids: Vec::new(),
+            loci: Vec::new(),
+            reply_id: Some("m1".to_string()),
+            url: None,
+        };
+        let result = parser.parse_message("I'll do livestream", Some("Sam"), Some("222"), None, &[reply.clone()]).await;
+        assert!(matches!(result, Ok(Some(BotCommand::VolunteerNextGame(_, _)))));
+        // Turning a reminder down isn't a sign-up
+        for declined in ["I can't do snacks saturday", "I don't think I can do snacks saturday", "I'll do snacks if no one else can"] {
+            let result = parser.parse_message(declined, Some("Sam"), Some("222"), None, &[reply.clone()]).await;
+            assert!(matches!(result, Ok(None)), "{} => {:?}", declined, result);
+        }
+        let result = parser.parse_message("🙋", Some("Sam"), Some("222"), None, &[reply.clone()]).await;
+        assert!(matches!(result, Ok(Some(BotCommand::ClaimOpenRole(ref post, ref person))) if post == "m1" && person == "Sam"));
+
+        let mentions_only = create_parser().with_contextual_volunteering(false);
+        mentions_only.remember_bot_message("TestBot", "m1");
+        let result = mentions_only.parse_message("I'll do livestream", Some("Sam"), Some("222"), None, &[reply]).await;
+        assert!(matches!(result, Ok(None)));
+    }
+
+    #[tokio::test]
+    async fn test_game_mode_relaxes_mentions() {
+        let game_mode = GameMode::default();
+        let parser = create_parser().with_game_mode(game_mode.clone());
+        let photo = crate::models::Attachment {
+            attachment_type: "image".to_string(),
+            user_ids: Vec::new(),
+            loci: Vec::new(),
+            reply_id: None,
+            url: Some("https://i.groupme.com/1.jpeg".to_string()),
+        };
+
+        // Off: nobody's talking to the bot
+        let result = parser.parse_message("up 4-2!", Some("Jane"), Some("111"), None, &[]).await;
+        assert!(matches!(result, Ok(None)));
+
+        let ends_at = chrono::Utc::now() + chrono::Duration::hours(2);
+        game_mode.start(chrono::Local::now().date_naive(), Some("Storm".to_string()), ends_at, None).await;
+        let result = parser.parse_message("up 4-2!", Some("Jane"), Some("111"), None, &[]).await;
+        assert!(matches!(result, Ok(Some(BotCommand::LiveScore(4, 2)))));
+        let result = parser.parse_message("@TestBot down 3-1", Some("Jane"), Some("111"), None, &[]).await;
+        assert!(matches!(result, Ok(Some(BotCommand::LiveScore(1, 3)))));
+        let result = parser.parse_message("", Some("Jane"), Some("111"), None, &[photo]).await;
+        assert!(matches!(result, Ok(Some(BotCommand::CollectPhotos(ref urls))) if urls.len() == 1));
+        let result = parser.parse_message("great catch!", Some("Jane"), Some("111"), None, &[]).await;
+        assert!(matches!(result, Ok(None)));
+    }
 }
@@ -0,0 +1,226 @@
+use rhai::{Array, CallFnOptions, Dynamic, Engine, Map, Scope, AST};
+use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
+
+use crate::error::{BotError, Result};
+use crate::models::CorrelatedEvent;
+
+/// Script engine with the standard rhai language but nothing beyond what's registered here:
+/// no file, network or process access, and hard caps so a bad loop can't stall the bot
+fn sandboxed_engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.set_max_operations(200_000);
+    engine.set_max_call_levels(32);
+    engine.set_max_expr_depths(64, 32);
+    engine.set_max_string_size(10_000);
+    engine.set_max_array_size(1_000);
+    engine.set_max_map_size(1_000);
+    engine.on_print(|text| info!("plugin: {}", text));
+    engine.on_debug(|text, _, _| info!("plugin: {}", text));
+    engine
+}
+
+/// A game as scripts see it
+fn game_map(event: &CorrelatedEvent) -> Dynamic {
+    let data = &event.data;
+    let mut volunteers = Map::new();
+    for (role, person) in data.volunteer_assignments() {
+        volunteers.insert(role.into(), person.to_string().into());
+    }
+
+    let mut game = Map::new();
+    game.insert("date".into(), event.event_date.format("%Y-%m-%d").to_string().into());
+    game.insert("time".into(), data.time.clone().into());
+    game.insert("location".into(), data.location.clone().into());
+    game.insert("home_team".into(), data.home_team.clone().into());
+    game.insert("matchup".into(), event.format_matchup().into());
+    game.insert("notes".into(), data.notes.clone().unwrap_or_default().into());
+    game.insert("volunteers".into(), volunteers.into());
+    game.into()
+}
+
+/// One script from the plugins directory
+#[derive(Clone)]
+pub struct Plugin {
+    pub name: String,
+    /// Lowercase phrases that run the script when the bot is mentioned with one of them
+    pub triggers: Vec<String>,
+    ast: Arc<AST>,
+}
+
+/// What a script run produced: its return value (the reply) and anything passed to `send`
+#[derive(Debug, Default, PartialEq)]
+pub struct PluginOutput {
+    pub reply: Option<String>,
+    pub messages: Vec<String>,
+}
+
+impl Plugin {
+    /// Compile a script and read its `triggers()` list
+    pub fn from_source(name: &str, source: &str) -> Result<Self> {
+        let engine = sandboxed_engine();
+        let ast = engine
+            .compile(source)
+            .map_err(|e| BotError::InvalidCommand(format!("Plugin {} doesn't compile: {}", name, e)))?;
+        let triggers: Array = engine
+            .call_fn_with_options(CallFnOptions::new().eval_ast(false), &mut Scope::new(), &ast, "triggers", ())
+            .map_err(|e| BotError::InvalidCommand(format!("Plugin {} needs a triggers() function returning a list: {}", name, e)))?;
+        let triggers = triggers
+            .into_iter()
+            .filter_map(|t| t.into_string().ok())
+            .map(|t| t.trim().to_lowercase())
+            .filter(|t| !t.is_empty())
+            .collect();
+
+        Ok(Self { name: name.to_string(), triggers, ast: Arc::new(ast) })
+    }
+
+    fn matches(&self, text_lower: &str) -> bool {
+        self.triggers.iter().any(|t| text_lower.contains(t.as_str()))
+    }
+
+    /// Call the script's `on_trigger(text, sender)` with `upcoming` behind `schedule()`/`next_game()`
+    pub fn run(&self, text: &str, sender: &str, upcoming: &[CorrelatedEvent]) -> Result<PluginOutput> {
+        let mut engine = sandboxed_engine();
+
+        let outbox = Arc::new(Mutex::new(Vec::new()));
+        let sent = outbox.clone();
+        engine.register_fn("send", move |text: &str| {
+            if let Ok(mut sent) = sent.lock() {
+                sent.push(text.to_string());
+            }
+        });
+        let games: Array = upcoming.iter().map(game_map).collect();
+        let next = games.first().cloned().unwrap_or(Dynamic::UNIT);
+        engine.register_fn("schedule", move || games.clone());
+        engine.register_fn("next_game", move || next.clone());
+
+        let result: Dynamic = engine
+            .call_fn_with_options(
+                CallFnOptions::new().eval_ast(false),
+                &mut Scope::new(),
+                &self.ast,
+                "on_trigger",
+                (text.to_string(), sender.to_string()),
+            )
+            .map_err(|e| BotError::InvalidCommand(format!("Plugin {} failed: {}", self.name, e)))?;
+
+        let reply = if result.is_unit() { None } else { Some(result.to_string()) };
+        let messages = outbox.lock().map(|m| m.clone()).unwrap_or_default();
+        Ok(PluginOutput { reply, messages })
+    }
+
+    /// `run` on the blocking thread pool, so a script spending its whole operation budget
+    /// doesn't hold up the webhook and the schedulers
+    pub async fn run_blocking(&self, text: &str, sender: &str, upcoming: Vec<CorrelatedEvent>) -> Result<PluginOutput> {
+        let plugin = self.clone();
+        let (text, sender) = (text.to_string(), sender.to_string());
+        tokio::task::spawn_blocking(move || plugin.run(&text, &sender, &upcoming))
+            .await
+            .map_err(|e| BotError::InvalidCommand(format!("Plugin {} stopped unexpectedly: {}", self.name, e)))?
+    }
+}
+
+/// Scripts loaded from PLUGINS_DIR at startup
+#[derive(Clone, Default)]
+pub struct PluginManager {
+    plugins: Arc<Vec<Plugin>>,
+}
+
+impl PluginManager {
+    /// Load every `*.rhai` file in `dir`; a missing directory just means no plugins
+    pub fn load(dir: &str) -> Self {
+        let mut plugins = Vec::new();
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Self::default();
+        };
+
+        let mut paths: Vec<_> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "rhai"))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let name = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default().to_string();
+            match std::fs::read_to_string(&path).map_err(|e| BotError::Config(e.to_string())).and_then(|source| Plugin::from_source(&name, &source)) {
+                Ok(plugin) if plugin.triggers.is_empty() => warn!("Plugin {} has no triggers, skipping", name),
+                Ok(plugin) => {
+                    info!("Loaded plugin {} (triggers: {})", name, plugin.triggers.join(", "));
+                    plugins.push(plugin);
+                }
+                Err(e) => warn!("Skipping plugin {}: {}", path.display(), e),
+            }
+        }
+
+        Self { plugins: Arc::new(plugins) }
+    }
+
+    /// Name of the first plugin whose trigger appears in the message
+    pub fn find_trigger(&self, text: &str) -> Option<String> {
+        let text_lower = text.to_lowercase();
+        self.plugins.iter().find(|p| p.matches(&text_lower)).map(|p| p.name.clone())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Plugin> {
+        self.plugins.iter().find(|p| p.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EventData;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_plugin_run() {
+        let source = r#"
+            fn triggers() { ["Field Rules"] }
+            fn on_trigger(text, sender) {
+                let game = next_game();
+                send("Reminder: no dogs at " + game.location);
+                `Thanks ${sender}! ${schedule().len()} game(s) left, snacks: ${game.volunteers.snacks}`
+            }
+        "#;
+        let plugin = Plugin::from_source("field-rules", source).unwrap();
+        assert_eq!(plugin.triggers, vec!["field rules".to_string()]);
+        assert!(plugin.matches("@piratebot field rules please"));
+
+        let date = NaiveDate::from_ymd_opt(2025, 5, 3).unwrap();
+        let mut data = EventData::new(date, "10:00 AM".to_string(), "Hall Field".to_string(), "Home".to_string(),
+            String::new(), String::new(), String::new(), String::new(), String::new());
+        data.assign_volunteer("snacks", "Jane");
+        let games = vec![CorrelatedEvent { event_date: date, event_summary: "Pirates vs Chaos".to_string(), data }];
+
+        let output = plugin.run("field rules", "Sam", &games).unwrap();
+        assert_eq!(output, PluginOutput {
+            reply: Some("Thanks Sam! 1 game(s) left, snacks: Jane".to_string()),
+            messages: vec!["Reminder: no dogs at Hall Field".to_string()],
+        });
+    }
+
+    #[test]
+    fn test_example_plugin() {
+        let plugin = Plugin::from_source("field-rules", include_str!("../plugins/examples/field-rules.rhai")).unwrap();
+        let output = plugin.run("field rules", "Sam", &[]).unwrap();
+        assert_eq!(output.reply.as_deref(), Some("No upcoming games on the schedule."));
+    }
+
+    #[test]
+    fn test_runaway_plugin_is_stopped() {
+        let plugin = Plugin::from_source("loop", "fn triggers() { [\"spin\"] } fn on_trigger(t, s) { loop {} }").unwrap();
+        assert!(plugin.run("spin", "Sam", &[]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_blocking() {
+        let plugin = Plugin::from_source("echo", "fn triggers() { [\"echo\"] } fn on_trigger(t, s) { s + \": \" + t }").unwrap();
+        let output = plugin.run_blocking("echo hi", "Sam", Vec::new()).await.unwrap();
+        assert_eq!(output.reply.as_deref(), Some("Sam: echo hi"));
+
+        let runaway = Plugin::from_source("loop", "fn triggers() { [\"spin\"] } fn on_trigger(t, s) { loop {} }").unwrap();
+        assert!(runaway.run_blocking("spin", "Sam", Vec::new()).await.is_err());
+    }
+}
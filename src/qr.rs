@@ -0,0 +1,29 @@
+use image::{DynamicImage, ImageFormat, Luma};
+use qrcode::QrCode;
+use std::io::Cursor;
+
+use crate::error::{BotError, Result};
+
+/// PNG of a QR code for `data`, big enough to print on a handout
+pub fn qr_png(data: &str) -> Result<Vec<u8>> {
+    let code = QrCode::new(data.as_bytes())
+        .map_err(|e| BotError::InvalidCommand(format!("Couldn't make a QR code: {}", e)))?;
+    let image = code.render::<Luma<u8>>().min_dimensions(400, 400).build();
+
+    let mut png = Vec::new();
+    DynamicImage::ImageLuma8(image)
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|e| BotError::InvalidCommand(format!("Couldn't encode the QR code: {}", e)))?;
+    Ok(png)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qr_png() {
+        let png = qr_png("https://bot.example.com/calendar.ics").unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+}
@@ -0,0 +1,72 @@
+use embedded_graphics::mono_font::{MonoFont, MonoTextStyle};
+use embedded_graphics::pixelcolor::{Rgb888, RgbColor};
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Line, PrimitiveStyle};
+use embedded_graphics::text::{Baseline, Text};
+use image::{DynamicImage, ImageFormat, Rgb, RgbImage};
+use std::io::Cursor;
+
+use crate::error::{BotError, Result};
+
+/// An `image` buffer that embedded-graphics can draw text and lines into, for images posted to GroupMe
+pub struct Canvas(RgbImage);
+
+impl OriginDimensions for Canvas {
+    fn size(&self) -> Size {
+        Size::new(self.0.width(), self.0.height())
+    }
+}
+
+impl DrawTarget for Canvas {
+    type Color = Rgb888;
+    type Error = std::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> std::result::Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let (Ok(x), Ok(y)) = (u32::try_from(point.x), u32::try_from(point.y)) {
+                if x < self.0.width() && y < self.0.height() {
+                    self.0.put_pixel(x, y, Rgb([color.r(), color.g(), color.b()]));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Canvas {
+    /// A blank white canvas
+    pub fn new(width: u32, height: u32) -> Self {
+        Self(RgbImage::from_pixel(width, height, Rgb([255, 255, 255])))
+    }
+
+    pub fn fill(&mut self, x: u32, y: u32, width: u32, height: u32, color: [u8; 3]) {
+        for py in y..(y + height).min(self.0.height()) {
+            for px in x..(x + width).min(self.0.width()) {
+                self.0.put_pixel(px, py, Rgb(color));
+            }
+        }
+    }
+
+    /// Black text with its top-left corner at (x, y)
+    pub fn text(&mut self, text: &str, x: i32, y: i32, font: &MonoFont) {
+        let style = MonoTextStyle::new(font, Rgb888::BLACK);
+        // Drawing into memory can't fail
+        let _ = Text::with_baseline(text, Point::new(x, y), style, Baseline::Top).draw(self);
+    }
+
+    pub fn line(&mut self, from: (i32, i32), to: (i32, i32), color: [u8; 3], width: u32) {
+        let style = PrimitiveStyle::with_stroke(Rgb888::new(color[0], color[1], color[2]), width);
+        let _ = Line::new(Point::new(from.0, from.1), Point::new(to.0, to.1)).into_styled(style).draw(self);
+    }
+
+    pub fn into_png(self) -> Result<Vec<u8>> {
+        let mut png = Vec::new();
+        DynamicImage::ImageRgb8(self.0)
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .map_err(|e| BotError::InvalidCommand(format!("Couldn't encode the image: {}", e)))?;
+        Ok(png)
+    }
+}
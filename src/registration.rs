@@ -0,0 +1,76 @@
+use crate::config::Config;
+use crate::error::{BotError, Result};
+use crate::groupme_client::GroupMeClient;
+use crate::models::BotRegistration;
+use crate::routes::{callback_url, resolve_routes};
+
+/// Make the GroupMe bot registrations match this deployment: callback URL for every route,
+/// plus name and avatar for the primary bot. Creates the primary bot if the access token's
+/// user has no bot by that name in the group yet, so a wrong GROUPME_BOT_ID doesn't add a
+/// duplicate on every restart. Returns one human-readable line per bot.
+pub async fn sync_bot_registrations(config: &Config, client: &GroupMeClient) -> Result<Vec<String>> {
+    if config.public_base_url.is_none() {
+        return Err(BotError::Config(
+            "PUBLIC_BASE_URL (or BOT_SUBDOMAIN and BASE_DOMAIN) is needed to register callback URLs".to_string(),
+        ));
+    }
+
+    let existing = client.list_bots().await?;
+    let mut report = Vec::new();
+
+    for (idx, route) in resolve_routes(config).iter().enumerate() {
+        let is_primary = idx == 0;
+        let url = callback_url(config, route);
+
+        match existing.iter().find(|bot| bot.bot_id.as_deref() == Some(route.bot_id.as_str())) {
+            Some(current) => {
+                let mut wanted = current.clone();
+                wanted.callback_url = Some(url);
+                if is_primary {
+                    wanted.name = config.groupme_bot_name.clone();
+                    if config.bot_avatar_url.is_some() {
+                        wanted.avatar_url = config.bot_avatar_url.clone();
+                    }
+                }
+
+                if wanted == *current {
+                    report.push(format!("✅ {} ({}) is up to date", current.name, route.bot_id));
+                } else {
+                    client.update_bot(&wanted).await?;
+                    report.push(format!("🔄 Updated {} ({}) -> {}", wanted.name, route.bot_id, route.path));
+                }
+            }
+            None if is_primary => {
+                let group_id = route.group_id.clone().ok_or_else(|| {
+                    BotError::Config("GROUPME_GROUP_ID is needed to create the bot".to_string())
+                })?;
+                if let Some(found) = existing.iter().find(|bot| bot.group_id == group_id && bot.name == config.groupme_bot_name) {
+                    return Err(BotError::Config(format!(
+                        "GROUPME_BOT_ID {} isn't owned by this access token, but {} ({}) already is in that group - set GROUPME_BOT_ID={} in .env",
+                        route.bot_id,
+                        found.name,
+                        found.bot_id.as_deref().unwrap_or_default(),
+                        found.bot_id.as_deref().unwrap_or_default()
+                    )));
+                }
+                let created = client.create_bot(&BotRegistration {
+                    bot_id: None,
+                    group_id,
+                    name: config.groupme_bot_name.clone(),
+                    callback_url: Some(url),
+                    avatar_url: config.bot_avatar_url.clone(),
+                }).await?;
+                report.push(format!(
+                    "🆕 Created {} - set GROUPME_BOT_ID={} in .env",
+                    created.name,
+                    created.bot_id.unwrap_or_default()
+                ));
+            }
+            None => {
+                report.push(format!("⚠️ Bot {} isn't owned by this access token, skipping", route.bot_id));
+            }
+        }
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,42 @@
+use chrono::NaiveDate;
+
+/// "today", "tomorrow", "in 3 days", "in 2 weeks", "yesterday", "5 days ago"
+pub fn relative_day(date: NaiveDate, today: NaiveDate) -> String {
+    let days = (date - today).num_days();
+    let span = |days: i64| match days {
+        ..=13 => format!("{} days", days),
+        14..=59 => format!("{} weeks", days / 7),
+        _ => format!("{} months", days / 30),
+    };
+    match days {
+        0 => "today".to_string(),
+        1 => "tomorrow".to_string(),
+        -1 => "yesterday".to_string(),
+        d if d > 0 => format!("in {}", span(d)),
+        d => format!("{} ago", span(-d)),
+    }
+}
+
+/// How replies name a game's date: "Saturday 5/3 (in 3 days)"
+pub fn game_day(date: NaiveDate, today: NaiveDate) -> String {
+    format!("{} ({})", date.format("%A %-m/%-d"), relative_day(date, today))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_day() {
+        let today = NaiveDate::from_ymd_opt(2025, 4, 30).unwrap();
+        let day = |d: i64| today + chrono::Duration::days(d);
+        assert_eq!(relative_day(today, today), "today");
+        assert_eq!(relative_day(day(1), today), "tomorrow");
+        assert_eq!(relative_day(day(3), today), "in 3 days");
+        assert_eq!(relative_day(day(16), today), "in 2 weeks");
+        assert_eq!(relative_day(day(75), today), "in 2 months");
+        assert_eq!(relative_day(day(-1), today), "yesterday");
+        assert_eq!(relative_day(day(-5), today), "5 days ago");
+        assert_eq!(game_day(day(3), today), "Saturday 5/3 (in 3 days)");
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::{Local, Timelike};
+use chrono::Timelike;
 use std::collections::HashSet;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -6,69 +6,73 @@ use tokio::time::{sleep, Duration as TokioDuration};
 use tracing::{info, warn, error};
 
 use crate::config::Config;
+use crate::features::Feature;
+use crate::outbound_webhooks::WebhookEvent;
 use crate::service::BotService;
+use crate::watchdog::{self, Heartbeat};
 
 /// Tracks which reminders have been sent to avoid duplicates
 #[derive(Default)]
 pub struct ReminderState {
     sent_24h_reminders: HashSet<String>,  // game_date as string
     sent_15m_reminders: HashSet<String>,
+    sent_briefings: HashSet<String>,
 }
 
 pub struct ReminderScheduler {
     bot_service: Arc<BotService>,
     state: Arc<RwLock<ReminderState>>,
     config: Config,
-    team_facts: Option<Arc<crate::team_facts::TeamFactsProvider>>,
+    heartbeat: Heartbeat,
 }
 
 impl ReminderScheduler {
-    pub fn new(config: Config) -> Self {
-        let bot_service = Arc::new(BotService::new(config.clone()));
+    /// Shares the webhook's BotService so venue time zone changes apply right away
+    pub fn new(config: Config, bot_service: BotService) -> Self {
+        let bot_service = Arc::new(bot_service);
         let state = Arc::new(RwLock::new(ReminderState::default()));
         
-        // Initialize team facts for 15m reminder
-        let team_facts = if config.enable_team_facts {
-            Some(Arc::new(crate::team_facts::TeamFactsProvider::new(
-                config.team_name.clone(),
-                config.team_emoji.clone(),
-                config.enable_team_facts,
-                config.team_facts_file.clone(),
-            )))
-        } else {
-            None
-        };
-        
         Self {
             bot_service,
             state,
             config,
-            team_facts,
+            // Three missed 5-minute checks
+            heartbeat: Heartbeat::new(chrono::Duration::minutes(15)),
         }
     }
 
-    /// Start the reminder scheduler in the background
+    /// Tick record for /healthz
+    pub fn heartbeat(&self) -> Heartbeat {
+        self.heartbeat.clone()
+    }
+
+    /// Start the reminder scheduler in the background, restarted by the watchdog if it dies or stalls
     pub fn start(self: Arc<Self>) {
-        let start_hour = self.config.reminder_start_hour;
-        let end_hour = self.config.reminder_end_hour;
-        
-        tokio::spawn(async move {
-            info!("Reminder scheduler started (active hours: {}:00 - {}:00)", start_hour, end_hour);
-            
-            loop {
-                // Check every 5 minutes
-                sleep(TokioDuration::from_secs(300)).await;
-                
-                if let Err(e) = self.check_and_send_reminders().await {
-                    error!("Error checking reminders: {}", e);
-                }
+        info!(
+            "Reminder scheduler started (active hours: {}:00 - {}:00)",
+            self.config.reminder_start_hour, self.config.reminder_end_hour
+        );
+        let heartbeat = self.heartbeat.clone();
+        let bot_service = self.bot_service.clone();
+        let admin_user_id = self.config.admin_user_id.clone();
+        watchdog::supervise("reminder", heartbeat, bot_service, admin_user_id, move || self.clone().run());
+    }
+
+    async fn run(self: Arc<Self>) {
+        loop {
+            // Check every 5 minutes
+            sleep(TokioDuration::from_secs(300)).await;
+            self.heartbeat.tick();
+
+            if let Err(e) = self.check_and_send_reminders().await {
+                error!("Error checking reminders: {}", e);
             }
-        });
+        }
     }
 
     /// Check if current time is within acceptable reminder hours
     fn is_within_reminder_hours(&self) -> bool {
-        let now = Local::now().naive_local();
+        let now = self.config.now();
         let current_hour = now.hour();
         
         // Check if current hour is within the configured range
@@ -81,8 +85,12 @@ impl ReminderScheduler {
             // Silently skip - don't send reminders too early or too late
             return Ok(());
         }
+        if !self.bot_service.feature_enabled(Feature::Reminders, self.config.groupme_group_id.as_deref()).await {
+            return Ok(());
+        }
+        self.send_special_event_reminders().await;
 
-        let now = Local::now().naive_local();
+        let now = chrono::Utc::now();
         
         // ALWAYS fetch fresh data for reminders
         // Use find_next_event logic manually or adapt to new structure
@@ -90,23 +98,27 @@ impl ReminderScheduler {
         // But reminder scheduler logic was previously iterating.
         // Let's rewrite to use correlate_data() which returns HashMap<Date, Vec<Event>>
         
-        match self.bot_service.correlate_data().await {
+        match self.bot_service.schedule_with_bracket().await {
             Ok(events_map) => {
                 // Flatten and sort all events
                 let mut all_events: Vec<crate::models::CorrelatedEvent> = events_map.values().flatten().cloned().collect();
                 all_events.sort_by(|a, b| a.event_date.cmp(&b.event_date));
                 
-                let today = chrono::Utc::now().date_naive();
+                let today = self.config.today();
                 
                 // Find next event (same logic as service.rs basically)
                 let mut next_event: Option<crate::models::CorrelatedEvent> = None;
                 
                 for event in all_events {
+                    // A cancelled game gets no 24h, briefing or 15m reminders
+                    if event.data.is_cancelled() {
+                        continue;
+                    }
                     if event.event_date >= today {
                         // Check if time has passed if it is today
                         if event.event_date == today {
-                             // Try parse time
-                             if let Ok(dt) = self.parse_game_datetime(&event.event_date, &event.data.time) {
+                             // Try parse time (in the venue's time zone)
+                             if let Some(dt) = self.bot_service.event_start(&event).await {
                                  if dt > now {
                                      next_event = Some(event);
                                      break; 
@@ -134,11 +146,11 @@ impl ReminderScheduler {
                         return Ok(());
                     }
                     
-                    // Parse game time to get exact datetime
-                    let game_datetime = match self.parse_game_datetime(&event.event_date, &event.data.time) {
-                        Ok(dt) => dt,
-                        Err(e) => {
-                            warn!("Could not parse time '{}' for game {}: {}", event.data.time, game_key, e);
+                    // Parse game time to get exact datetime, honoring any venue time zone override
+                    let game_datetime = match self.bot_service.event_start(&event).await {
+                        Some(dt) => dt,
+                        None => {
+                            warn!("Could not parse time '{}' for game {}", event.data.time, game_key);
                             return Ok(());
                         }
                     };
@@ -147,6 +159,24 @@ impl ReminderScheduler {
                     info!("Game datetime parsed: {} (date: {}, time: {}), Current time: {}, Minutes until game: {}", 
                         game_datetime, event.event_date, event.data.time, now, time_until_game.num_minutes());
                 
+                    // Game-day morning briefing, at the first check of the day
+                    if self.config.morning_briefing
+                        && event.event_date == self.config.today()
+                        && time_until_game.num_minutes() > 30
+                    {
+                        let should_send = {
+                            let state = self.state.read().await;
+                            !state.sent_briefings.contains(&game_key)
+                        };
+
+                        if should_send {
+                            info!("Sending morning briefing for game on {}", game_key);
+                            self.send_morning_briefing(&event).await?;
+                            let mut state = self.state.write().await;
+                            state.sent_briefings.insert(game_key.clone());
+                        }
+                    }
+
                     // Check for 24-hour reminder
                     if time_until_game.num_hours() <= 24 && time_until_game.num_hours() > 23 {
                         let should_send = {
@@ -155,7 +185,7 @@ impl ReminderScheduler {
                         };
                         
                         if should_send {
-                            info!("Sending 24-hour reminder for game on {} (current hour: {})", game_key, now.hour());
+                            info!("Sending 24-hour reminder for game on {} (current hour: {})", game_key, self.config.now().hour());
                             self.send_24h_reminder(&event).await?;
                             let mut state = self.state.write().await;
                             state.sent_24h_reminders.insert(game_key.clone());
@@ -170,7 +200,7 @@ impl ReminderScheduler {
                         };
                         
                         if should_send {
-                            info!("Sending 15-minute reminder for game on {} (current hour: {})", game_key, now.hour());
+                            info!("Sending 15-minute reminder for game on {} (current hour: {})", game_key, self.config.now().hour());
                             self.send_15m_reminder(&event).await?;
                             let mut state = self.state.write().await;
                             state.sent_15m_reminders.insert(game_key);
@@ -191,70 +221,107 @@ impl ReminderScheduler {
         Ok(())
     }
 
+    /// Picture days, fundraisers and other special events, each on its own lead times
+    async fn send_special_event_reminders(&self) {
+        for message in self.bot_service.due_special_event_reminders().await {
+            info!("Sending special event reminder");
+            if let Err(e) = self.bot_service.send_response(&message).await {
+                warn!("Failed to send special event reminder: {}", e);
+            }
+        }
+    }
+
     async fn send_24h_reminder(&self, event: &crate::models::CorrelatedEvent) -> Result<(), Box<dyn std::error::Error>> {
         let matchup = event.format_matchup();
-        let mut message = format!("⏰ Game Reminder! 24 hours until:\n\n{} {}\n", self.config.team_emoji, matchup);
-        message.push_str(&event.data.format_all());
+        let profile = self.bot_service.profile(self.config.groupme_group_id.as_deref());
+        let mut message = format!("⏰ Game Reminder! 24 hours until:\n\n{} {}\n", profile.team_emoji, matchup);
+        message.push_str(&event.data.format_all(self.config.today()));
         message.push_str("\n");
         message.push_str(&event.data.format_volunteer_needs(&self.config.team_name));
         
+        self.bot_service.expect_game_post(&message, event.event_date);
         self.bot_service.send_response(&message).await
-            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        self.bot_service.emit_webhook(WebhookEvent::ReminderSent {
+            date: event.event_date,
+            matchup,
+            reminder: "24h".to_string(),
+        });
+        self.bot_service.record_reminder(event, "24h").await;
+        Ok(())
     }
 
-    async fn send_15m_reminder(&self, _event: &crate::models::CorrelatedEvent) -> Result<(), Box<dyn std::error::Error>> {
-        let mut message = format!("⚾ Game starting in 15 minutes! {}\n\n", self.config.team_emoji);
-        
-        // Add a team fact if enabled
-        if let Some(ref facts) = self.team_facts {
-            message.push_str(&facts.get_fact());
-            message.push_str("\n\n");
+    async fn send_morning_briefing(&self, event: &crate::models::CorrelatedEvent) -> Result<(), Box<dyn std::error::Error>> {
+        let group_id = self.config.groupme_group_id.as_deref();
+        let matchup = event.format_matchup();
+        let profile = self.bot_service.profile(group_id);
+        let mut message = format!("☀️ Game day! {} {}\n\n", profile.team_emoji, matchup);
+        message.push_str(&event.data.format_all(self.config.today()));
+        message.push('\n');
+        message.push_str(&event.data.format_volunteer_needs(&self.config.team_name));
+
+        let mut chart_url = None;
+        if let Some((forecast, chart)) = self.bot_service.game_day_weather(event, group_id).await {
+            message.push_str(&format!("\n{}", forecast));
+            chart_url = chart;
+        }
+
+        // GroupMe echoes the text without the image line, so that's what a 🙋 reply is matched on
+        self.bot_service.expect_game_post(&message, event.event_date);
+        if let Some(url) = chart_url {
+            message.push_str(&format!("\n{}", url));
         }
-        
-        message.push_str(&format!("⚾ Let's go {}! {}", self.config.team_name, self.config.team_emoji));
-        
         self.bot_service.send_response(&message).await
-            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        self.bot_service.emit_webhook(WebhookEvent::ReminderSent {
+            date: event.event_date,
+            matchup,
+            reminder: "briefing".to_string(),
+        });
+        self.bot_service.record_reminder(event, "briefing").await;
+        Ok(())
     }
 
-    fn parse_game_datetime(&self, date: &chrono::NaiveDate, time_str: &str) -> Result<chrono::NaiveDateTime, Box<dyn std::error::Error>> {
-        // Try to parse time from string (e.g., "10:00 AM", "14:30", etc.)
-        let time_formats = [
-            "%I:%M %p",  // 10:00 AM
-            "%I:%M%p",   // 10:00AM
-            "%H:%M",     // 14:30
-            "%H:%M:%S",  // 14:30:00
-        ];
+    async fn send_15m_reminder(&self, event: &crate::models::CorrelatedEvent) -> Result<(), Box<dyn std::error::Error>> {
+        let profile = self.bot_service.profile(self.config.groupme_group_id.as_deref());
+        let mut message = format!("⚾ Game starting in 15 minutes! {}\n\n", profile.team_emoji);
+
+        if let Some(ref notes) = event.data.notes {
+            message.push_str(&format!("📝 {}\n\n", notes));
+        }
         
-        for format in &time_formats {
-            if let Ok(time) = chrono::NaiveTime::parse_from_str(time_str.trim(), format) {
-                return Ok(date.and_time(time));
-            }
+        // Add a team fact if enabled
+        if let Some(fact) = self.bot_service.team_fact(self.config.groupme_group_id.as_deref()).await {
+            message.push_str(&fact);
+            message.push_str("\n\n");
         }
         
-        // If parsing fails, return error instead of defaulting
-        Err(format!("Could not parse time: '{}'", time_str).into())
+        message.push_str(&format!("⚾ Let's go {}! {}", profile.team_name, profile.team_emoji));
+        
+        self.bot_service.send_response(&message).await
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        self.bot_service.emit_webhook(WebhookEvent::ReminderSent {
+            date: event.event_date,
+            matchup: event.format_matchup(),
+            reminder: "15m".to_string(),
+        });
+        self.bot_service.record_reminder(event, "15m").await;
+        Ok(())
     }
 
     async fn cleanup_old_reminders(&self) {
-        let now = Local::now().naive_local().date();
-        
+        let now = self.config.today();
+        // Keys are "<date>T<time>"; keep them until the day after the game
+        let recent = |game_key: &String| {
+            let date = game_key.split('T').next().unwrap_or_default();
+            chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map(|date| (date - now).num_days() >= -1)
+                .unwrap_or(false)
+        };
+
         let mut state = self.state.write().await;
-        // Remove reminders for games that are more than 1 day old
-        state.sent_24h_reminders.retain(|game_date| {
-            if let Ok(date) = chrono::NaiveDate::parse_from_str(game_date, "%Y-%m-%d") {
-                (date - now).num_days() >= -1
-            } else {
-                false
-            }
-        });
-        
-        state.sent_15m_reminders.retain(|game_date| {
-            if let Ok(date) = chrono::NaiveDate::parse_from_str(game_date, "%Y-%m-%d") {
-                (date - now).num_days() >= -1
-            } else {
-                false
-            }
-        });
+        state.sent_24h_reminders.retain(recent);
+        state.sent_15m_reminders.retain(recent);
+        state.sent_briefings.retain(recent);
     }
 }
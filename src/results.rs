@@ -0,0 +1,103 @@
+use std::sync::Arc;
+use chrono::NaiveDate;
+use serde::{Serialize, Deserialize};
+use tokio::sync::RwLock;
+
+use crate::models::teams_match;
+
+const RESULTS_FILE: &str = "data/results.json";
+
+/// Final score of one game, from our side
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameResult {
+    pub date: NaiveDate,
+    pub opponent: String,
+    pub our_score: u32,
+    pub their_score: u32,
+}
+
+impl GameResult {
+    pub fn outcome(&self) -> &'static str {
+        if self.our_score > self.their_score {
+            "won"
+        } else if self.our_score < self.their_score {
+            "lost"
+        } else {
+            "tied"
+        }
+    }
+
+    /// "we lost 4-5 on 4/12"
+    pub fn summary(&self) -> String {
+        format!("we {} {}-{} on {}", self.outcome(), self.our_score, self.their_score, self.date.format("%-m/%-d"))
+    }
+}
+
+#[derive(Clone)]
+pub struct ResultsStore {
+    results: Arc<RwLock<Vec<GameResult>>>,
+}
+
+impl Default for ResultsStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResultsStore {
+    pub fn new() -> Self {
+        let _ = std::fs::create_dir_all("data");
+        let results = std::fs::read_to_string(RESULTS_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self { results: Arc::new(RwLock::new(results)) }
+    }
+
+    fn save(results: &[GameResult]) {
+        if let Err(e) = std::fs::create_dir_all("data") { tracing::error!("Failed to create data dir: {}", e); }
+        if let Err(e) = std::fs::write(RESULTS_FILE, serde_json::to_string_pretty(results).unwrap_or_default()) {
+            tracing::error!("Failed to save game results: {}", e);
+        }
+    }
+
+    /// Record a final score, replacing any earlier entry for the same date and opponent
+    pub async fn record(&self, result: GameResult) {
+        let mut results = self.results.write().await;
+        results.retain(|r| !(r.date == result.date && teams_match(&r.opponent, &result.opponent)));
+        results.push(result);
+        results.sort_by_key(|r| r.date);
+        Self::save(&results);
+    }
+
+    /// Every recorded game against an opponent, oldest first
+    pub async fn against(&self, opponent: &str) -> Vec<GameResult> {
+        self.results
+            .read()
+            .await
+            .iter()
+            .filter(|r| teams_match(&r.opponent, opponent))
+            .cloned()
+            .collect()
+    }
+
+    pub async fn last_against(&self, opponent: &str) -> Option<GameResult> {
+        self.against(opponent).await.pop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_result_summary() {
+        let result = GameResult {
+            date: NaiveDate::from_ymd_opt(2025, 4, 12).unwrap(),
+            opponent: "Chaos".to_string(),
+            our_score: 4,
+            their_score: 5,
+        };
+        assert_eq!(result.summary(), "we lost 4-5 on 4/12");
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+
+use crate::config::Config;
+
+const GENERATED_PATHS_FILE: &str = "data/callback_paths.json";
+const PRIMARY_KEY: &str = "primary";
+
+/// A webhook path GroupMe posts to, and the bot that answers messages arriving on it
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallbackRoute {
+    pub path: String,
+    pub bot_id: String,
+    /// When set, messages from any other group are ignored on this path
+    pub group_id: Option<String>,
+}
+
+/// One CALLBACK_ROUTES entry before generated paths are filled in
+#[derive(Debug, Clone, PartialEq)]
+struct RouteSpec {
+    path: Option<String>,
+    bot_id: String,
+    group_id: Option<String>,
+}
+
+/// Parse CALLBACK_ROUTES: comma-separated `[path=]bot_id[@group_id]` entries.
+/// Entries without a path get a generated one.
+fn parse_route_specs(raw: &str) -> Vec<RouteSpec> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .filter_map(|entry| {
+            let (path, target) = match entry.split_once('=') {
+                Some((path, target)) => (Some(normalize_path(path)), target),
+                None => (None, entry),
+            };
+            let (bot_id, group_id) = match target.split_once('@') {
+                Some((bot, group)) => (bot.trim(), Some(group.trim().to_string()).filter(|g| !g.is_empty())),
+                None => (target.trim(), None),
+            };
+            (!bot_id.is_empty()).then(|| RouteSpec { path, bot_id: bot_id.to_string(), group_id })
+        })
+        .collect()
+}
+
+/// "webhook/abc" -> "/webhook/abc", without a trailing slash
+fn normalize_path(path: &str) -> String {
+    format!("/{}", path.trim().trim_matches('/'))
+}
+
+fn generate_path() -> String {
+    let token: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(24)
+        .map(char::from)
+        .collect();
+    format!("/webhook/{}", token.to_lowercase())
+}
+
+/// Every callback route to serve: the primary WEBHOOK_PATH for GROUPME_BOT_ID, then CALLBACK_ROUTES.
+/// Generated paths are saved so the URLs configured in GroupMe survive restarts.
+pub fn resolve_routes(config: &Config) -> Vec<CallbackRoute> {
+    let mut generated: BTreeMap<String, String> = std::fs::read_to_string(GENERATED_PATHS_FILE)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+    let generated_before = generated.len();
+
+    let primary_path = if config.webhook_path.eq_ignore_ascii_case("auto") {
+        generated.entry(PRIMARY_KEY.to_string()).or_insert_with(generate_path).clone()
+    } else {
+        normalize_path(&config.webhook_path)
+    };
+    let mut routes = vec![CallbackRoute {
+        path: primary_path,
+        bot_id: config.groupme_bot_id.clone(),
+        group_id: config.groupme_group_id.clone(),
+    }];
+
+    for spec in parse_route_specs(config.callback_routes.as_deref().unwrap_or_default()) {
+        let path = spec.path.unwrap_or_else(|| generated.entry(spec.bot_id.clone()).or_insert_with(generate_path).clone());
+        if routes.iter().any(|r| r.path == path) {
+            tracing::warn!("Skipping duplicate callback path {}", path);
+            continue;
+        }
+        routes.push(CallbackRoute { path, bot_id: spec.bot_id, group_id: spec.group_id });
+    }
+
+    if generated.len() != generated_before {
+        if let Err(e) = std::fs::create_dir_all("data") { tracing::error!("Failed to create data dir: {}", e); }
+        if let Err(e) = std::fs::write(GENERATED_PATHS_FILE, serde_json::to_string_pretty(&generated).unwrap_or_default()) {
+            tracing::error!("Failed to save generated callback paths: {}", e);
+        }
+    }
+
+    routes
+}
+
+/// Full callback URL to paste into the GroupMe bot settings, or just the path if the public URL is unknown
+pub fn callback_url(config: &Config, route: &CallbackRoute) -> String {
+    match &config.public_base_url {
+        Some(base) => format!("{}{}", base.trim_end_matches('/'), route.path),
+        None => route.path.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_route_specs() {
+        let specs = parse_route_specs("webhook/dragons=abc123@555, def456 ,");
+        assert_eq!(specs, vec![
+            RouteSpec { path: Some("/webhook/dragons".to_string()), bot_id: "abc123".to_string(), group_id: Some("555".to_string()) },
+            RouteSpec { path: None, bot_id: "def456".to_string(), group_id: None },
+        ]);
+    }
+}
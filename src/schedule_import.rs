@@ -0,0 +1,274 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::io::BufReader;
+
+use crate::config::Config;
+use crate::conflicts::same_time;
+use crate::error::{BotError, Result};
+use crate::models::{teams_match, CorrelatedEvent, EventData};
+
+/// A game read from a league export, ready to become a sheet row (columns A-D)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedGame {
+    pub date: NaiveDate,
+    pub time: String,
+    pub location: String,
+    pub home_team: String,
+    /// Matchup text as the source wrote it, e.g. " Vs Chaos 8U - Hall (Pirates - Hines)"
+    pub summary: String,
+}
+
+impl ImportedGame {
+    pub fn to_row(&self) -> Vec<String> {
+        vec![
+            self.date.format("%Y-%m-%d").to_string(),
+            self.time.clone(),
+            self.location.clone(),
+            self.home_team.clone(),
+        ]
+    }
+
+    /// View this game like a sheet event so it can be compared with one
+    pub fn to_event(&self) -> CorrelatedEvent {
+        CorrelatedEvent {
+            event_date: self.date,
+            event_summary: self.summary.clone(),
+            data: EventData::new(
+                self.date,
+                self.time.clone(),
+                self.location.clone(),
+                self.home_team.clone(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+            ),
+        }
+    }
+
+    /// Whether the sheet already has this game: same date, start time and opponent, so
+    /// re-running an import skips it but the second game of a doubleheader doesn't
+    pub fn is_on_sheet(&self, sheet: &[CorrelatedEvent], my_team: &str) -> bool {
+        let opponent = self.to_event().opponent(my_team);
+        sheet.iter().filter(|event| event.event_date == self.date).any(|event| {
+            same_time(&event.data.time, &self.time)
+                && match (event.opponent(my_team), &opponent) {
+                    (Some(a), Some(b)) => teams_match(&a, b),
+                    (a, b) => a.is_none() && b.is_none(),
+                }
+        })
+    }
+
+    /// "5/3 10:00 AM @ Hall Field (Pirates)"
+    pub fn describe(&self) -> String {
+        let mut line = self.date.format("%-m/%-d").to_string();
+        if !self.time.is_empty() {
+            line.push_str(&format!(" {}", self.time));
+        }
+        if !self.location.is_empty() {
+            line.push_str(&format!(" @ {}", self.location));
+        }
+        if !self.home_team.is_empty() {
+            line.push_str(&format!(" ({})", self.home_team));
+        }
+        line
+    }
+}
+
+/// Download a schedule export and parse it as iCal (TeamSideline and most league sites) or CSV.
+/// iCal times are converted to the team's timezone.
+pub async fn fetch_schedule(url: &str, config: &Config) -> Result<Vec<ImportedGame>> {
+    let url = url.replacen("webcal://", "https://", 1);
+    let response = reqwest::get(&url).await?;
+    if !response.status().is_success() {
+        return Err(BotError::InvalidCommand(format!("Schedule download returned {}", response.status())));
+    }
+    let body = response.text().await?;
+
+    let mut games = if body.contains("BEGIN:VCALENDAR") {
+        parse_ics(&body, |utc| config.team_time(utc))
+    } else {
+        parse_csv(&body)?
+    };
+    games.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.time.cmp(&b.time)));
+    Ok(games)
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    ["%Y-%m-%d", "%m/%d/%Y", "%m/%d/%y", "%m-%d-%Y"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(value.trim(), format).ok())
+}
+
+/// Parse a CSV export by its header row. Needs a Date column; Time, Location/Field/Venue and
+/// Home/Home Team are picked up when present.
+pub fn parse_csv(text: &str) -> Result<Vec<ImportedGame>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(text.as_bytes());
+
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| BotError::InvalidCommand(format!("Couldn't read the CSV header: {}", e)))?
+        .iter()
+        .map(|h| h.to_lowercase())
+        .collect();
+    let column = |names: &[&str]| headers.iter().position(|h| names.contains(&h.as_str()));
+
+    let date_col = column(&["date", "game date"])
+        .ok_or_else(|| BotError::InvalidCommand("The CSV needs a Date column".to_string()))?;
+    let time_col = column(&["time", "start", "start time", "game time"]);
+    let location_col = column(&["location", "field", "venue", "site"]);
+    let home_col = column(&["home", "home team"]);
+    let away_col = column(&["away", "away team", "visitor", "opponent"]);
+
+    let mut games = Vec::new();
+    for record in reader.records().flatten() {
+        let get = |col: Option<usize>| col.and_then(|c| record.get(c)).unwrap_or_default().to_string();
+        if let Some(date) = parse_date(&get(Some(date_col))) {
+            let (home_team, away_team) = (get(home_col), get(away_col));
+            let summary = if !home_team.is_empty() && !away_team.is_empty() {
+                format!("{} vs {}", home_team, away_team)
+            } else {
+                String::new()
+            };
+            games.push(ImportedGame {
+                date,
+                time: get(time_col),
+                location: get(location_col),
+                home_team,
+                summary,
+            });
+        }
+    }
+    Ok(games)
+}
+
+/// A DTSTART value as a date and "6:00 PM" on the team's clock. UTC ("...Z") and TZID times go
+/// through `team_time`; floating times and zones chrono-tz doesn't know are taken as written.
+fn parse_start(value: &str, tzid: Option<&str>, team_time: &impl Fn(DateTime<Utc>) -> NaiveDateTime) -> Option<(NaiveDate, String)> {
+    let value = value.trim();
+    let Ok(written) = NaiveDateTime::parse_from_str(value.trim_end_matches('Z'), "%Y%m%dT%H%M%S") else {
+        return NaiveDate::parse_from_str(value, "%Y%m%d").ok().map(|date| (date, String::new()));
+    };
+    let instant = if value.ends_with('Z') {
+        Some(written.and_utc())
+    } else {
+        tzid.and_then(|tz| tz.trim_matches('"').parse::<Tz>().ok())
+            .and_then(|tz| tz.from_local_datetime(&written).earliest())
+            .map(|start| start.with_timezone(&Utc))
+    };
+    let start = instant.map_or(written, team_time);
+    Some((start.date(), start.format("%-I:%M %p").to_string()))
+}
+
+/// Parse an iCal feed, with times on the team's clock (see `parse_start`). The home team comes
+/// from a TeamSideline-style summary (" Vs Chaos 8U - Hall (Pirates - Hines)"), falling back to the raw summary.
+pub fn parse_ics(text: &str, team_time: impl Fn(DateTime<Utc>) -> NaiveDateTime) -> Vec<ImportedGame> {
+    let mut games = Vec::new();
+    for calendar in ical::IcalParser::new(BufReader::new(text.as_bytes())).flatten() {
+        for event in calendar.events {
+            let property = |name: &str| {
+                event.properties
+                    .iter()
+                    .find(|p| p.name == name)
+                    .and_then(|p| p.value.clone())
+                    .unwrap_or_default()
+            };
+
+            let tzid = event.properties
+                .iter()
+                .find(|p| p.name == "DTSTART")
+                .and_then(|p| p.params.as_ref())
+                .and_then(|params| params.iter().find(|(name, _)| name == "TZID"))
+                .and_then(|(_, values)| values.first().cloned());
+            let Some((date, time)) = parse_start(&property("DTSTART"), tzid.as_deref(), &team_time) else {
+                continue;
+            };
+
+            let summary = property("SUMMARY").replace("\\,", ",");
+            let home_team = CorrelatedEvent::parse_matchup(&summary)
+                .map(|(home, _)| home)
+                .unwrap_or_else(|| summary.trim().to_string());
+
+            games.push(ImportedGame {
+                date,
+                time,
+                location: property("LOCATION").replace("\\,", ","),
+                home_team,
+                summary,
+            });
+        }
+    }
+    games
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv() {
+        let csv = "Date,Time,Field,Home Team,Away Team\n5/3/2025,10:00 AM,Hall,Pirates,Chaos\nnot a date,,,,\n";
+        let games = parse_csv(csv).unwrap();
+        assert_eq!(games, vec![ImportedGame {
+            date: NaiveDate::from_ymd_opt(2025, 5, 3).unwrap(),
+            time: "10:00 AM".to_string(),
+            location: "Hall".to_string(),
+            home_team: "Pirates".to_string(),
+            summary: "Pirates vs Chaos".to_string(),
+        }]);
+    }
+
+    /// The team plays in Chicago
+    fn central_time(utc: DateTime<Utc>) -> NaiveDateTime {
+        utc.with_timezone(&chrono_tz::America::Chicago).naive_local()
+    }
+
+    #[test]
+    fn test_parse_ics() {
+        let ics = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nDTSTART:20250503T100000\r\nSUMMARY: Vs Chaos 8U - Hall (Pirates - Hines)\r\nLOCATION:Hall Field\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+        let games = parse_ics(ics, central_time);
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].time, "10:00 AM");
+        assert_eq!(games[0].home_team, "Pirates");
+        assert_eq!(games[0].location, "Hall Field");
+    }
+
+    #[test]
+    fn test_parse_ics_time_zones() {
+        let ics = "BEGIN:VCALENDAR\r\n\
+            BEGIN:VEVENT\r\nDTSTART:20250503T230000Z\r\nSUMMARY:Pirates vs Chaos\r\nEND:VEVENT\r\n\
+            BEGIN:VEVENT\r\nDTSTART;TZID=America/New_York:20250510T190000\r\nSUMMARY:Pirates vs Storm\r\nEND:VEVENT\r\n\
+            BEGIN:VEVENT\r\nDTSTART:20250517T040000Z\r\nSUMMARY:Pirates vs Hawks\r\nEND:VEVENT\r\n\
+            END:VCALENDAR\r\n";
+        let games = parse_ics(ics, central_time);
+        let when: Vec<(String, &str)> = games.iter().map(|g| (g.date.format("%-m/%-d").to_string(), g.time.as_str())).collect();
+        assert_eq!(when, vec![
+            // 23:00 UTC is 6 PM CDT
+            ("5/3".to_string(), "6:00 PM"),
+            // 7 PM in New York is 6 PM in Chicago
+            ("5/10".to_string(), "6:00 PM"),
+            // Still the evening before in Chicago
+            ("5/16".to_string(), "11:00 PM"),
+        ]);
+    }
+
+    #[test]
+    fn test_doubleheader_is_not_on_sheet() {
+        let day = NaiveDate::from_ymd_opt(2025, 5, 3).unwrap();
+        let game = |time: &str, summary: &str| ImportedGame {
+            date: day,
+            time: time.to_string(),
+            location: "Hall".to_string(),
+            home_team: "Pirates".to_string(),
+            summary: summary.to_string(),
+        };
+        let sheet = vec![game("10:00 AM", "Pirates vs Chaos").to_event()];
+        assert!(game("10am", "Pirates vs Chaos").is_on_sheet(&sheet, "Pirates"));
+        assert!(!game("1:00 PM", "Pirates vs Chaos").is_on_sheet(&sheet, "Pirates"));
+        assert!(!game("10:00 AM", "Pirates vs Storm").is_on_sheet(&sheet, "Pirates"));
+    }
+}
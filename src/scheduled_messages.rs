@@ -0,0 +1,136 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Serialize, Deserialize};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tokio::time::{sleep, Duration as TokioDuration};
+use tracing::{info, warn};
+
+use crate::config::Config;
+use crate::service::BotService;
+
+const SCHEDULED_MESSAGES_FILE: &str = "data/scheduled_messages.json";
+
+/// Messages that come due while the bot is down are still sent after a restart, unless they're this stale
+const MAX_LATE_HOURS: i64 = 6;
+
+/// A one-off message a moderator asked the bot to post later
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledMessage {
+    pub id: u32,
+    pub send_at: DateTime<Utc>,
+    pub text: String,
+    pub scheduled_by: String,
+}
+
+impl ScheduledMessage {
+    /// "#3 Fri 5/2 6:00 PM: practice moved to Field 2", in the team's timezone
+    pub fn describe(&self, config: &Config) -> String {
+        format!("#{} {}: {}", self.id, config.team_time(self.send_at).format("%a %-m/%-d %-I:%M %p"), self.text)
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Scheduled {
+    next_id: u32,
+    messages: Vec<ScheduledMessage>,
+}
+
+/// Pending "@bot at 6 PM say: ..." messages, kept in data/scheduled_messages.json so a restart doesn't drop them
+#[derive(Clone)]
+pub struct ScheduledMessageStore {
+    scheduled: Arc<RwLock<Scheduled>>,
+}
+
+impl Default for ScheduledMessageStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScheduledMessageStore {
+    pub fn new() -> Self {
+        let scheduled = std::fs::read_to_string(SCHEDULED_MESSAGES_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self { scheduled: Arc::new(RwLock::new(scheduled)) }
+    }
+
+    fn save(scheduled: &Scheduled) {
+        if let Err(e) = std::fs::create_dir_all("data") { tracing::error!("Failed to create data dir: {}", e); }
+        if let Err(e) = std::fs::write(SCHEDULED_MESSAGES_FILE, serde_json::to_string_pretty(scheduled).unwrap_or_default()) {
+            tracing::error!("Failed to save scheduled messages: {}", e);
+        }
+    }
+
+    pub async fn add(&self, send_at: DateTime<Utc>, text: String, scheduled_by: String) -> ScheduledMessage {
+        let mut scheduled = self.scheduled.write().await;
+        scheduled.next_id += 1;
+        let message = ScheduledMessage { id: scheduled.next_id, send_at, text, scheduled_by };
+        scheduled.messages.push(message.clone());
+        scheduled.messages.sort_by_key(|m| m.send_at);
+        Self::save(&scheduled);
+        message
+    }
+
+    /// Pending messages, soonest first
+    pub async fn list(&self) -> Vec<ScheduledMessage> {
+        self.scheduled.read().await.messages.clone()
+    }
+
+    /// Remove a message, whether cancelled or already sent
+    pub async fn cancel(&self, id: u32) -> Option<ScheduledMessage> {
+        let mut scheduled = self.scheduled.write().await;
+        let index = scheduled.messages.iter().position(|m| m.id == id)?;
+        let message = scheduled.messages.remove(index);
+        Self::save(&scheduled);
+        Some(message)
+    }
+
+    /// Every message due by `now`, left in the store until it's removed
+    pub async fn due(&self, now: DateTime<Utc>) -> Vec<ScheduledMessage> {
+        self.scheduled.read().await.messages.iter()
+            .filter(|m| m.send_at <= now)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Posts scheduled messages when they come due
+pub struct ScheduledMessageSender {
+    bot_service: BotService,
+    store: ScheduledMessageStore,
+}
+
+impl ScheduledMessageSender {
+    pub fn new(bot_service: BotService, store: ScheduledMessageStore) -> Self {
+        Self { bot_service, store }
+    }
+
+    pub fn start(self: Arc<Self>) {
+        tokio::spawn(async move {
+            loop {
+                // Check every 30 seconds
+                sleep(TokioDuration::from_secs(30)).await;
+                self.send_due().await;
+            }
+        });
+    }
+
+    async fn send_due(&self) {
+        let now = Utc::now();
+        for message in self.store.due(now).await {
+            if now - message.send_at > Duration::hours(MAX_LATE_HOURS) {
+                warn!("Dropping scheduled message #{} that was due at {}", message.id, message.send_at);
+                self.store.cancel(message.id).await;
+                continue;
+            }
+            info!("Sending scheduled message #{} from {}", message.id, message.scheduled_by);
+            match self.bot_service.send_response(&message.text).await {
+                Ok(()) => { self.store.cancel(message.id).await; }
+                // Left in the store, so the next check retries it until it's too stale
+                Err(e) => warn!("Failed to send scheduled message #{}, will retry: {}", message.id, e),
+            }
+        }
+    }
+}
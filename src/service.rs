@@ -1,26 +1,104 @@
-use chrono::{NaiveDate, Utc};
+use chrono::{Datelike, Duration, NaiveDate, Utc};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 use tracing::{info, warn};
 
+use crate::absences::AbsenceStore;
+use crate::bring_list::BringListStore;
+use crate::conflicts::{find_conflicts, ScheduleConflict};
 use crate::config::Config;
+use crate::content_filter::{ContentFilter, FILTERED_REPLY};
+use crate::conversation_context::CONFIRMATION_WINDOW_SECONDS;
 use crate::error::{Result, BotError};
+use crate::features::{Feature, FeatureFlags};
+use crate::game_mode::{GameMode, GameSession};
+use crate::game_posts::GamePosts;
 use crate::google_client::GoogleClient;
+use crate::group_profiles::{GroupProfile, GroupProfiles};
 use crate::groupme_client::GroupMeClient;
-use crate::models::{CorrelatedEvent, EventData, BotCommand};
-use crate::team_facts::TeamFactsProvider;
+use crate::members::{names_match, same_person, MembersCache};
+use crate::mentions::BotName;
+use crate::outbound_webhooks::{WebhookEvent, WebhookNotifier};
+use crate::plugins::PluginManager;
+use crate::routes::CallbackRoute;
+use crate::models::{is_placeholder_name, teams_match, CorrelatedEvent, EventData, BotCommand, QrTarget, TeamStanding, CANCELLED_NOTE};
+use crate::results::{GameResult, ResultsStore};
+use crate::scheduled_messages::ScheduledMessageStore;
+use crate::special_events::{describe_lead, merge_events, render, ReminderTemplate, SpecialEvent, SpecialEventStore};
+use crate::stats::{StatLine, StatsStore};
+use crate::tournament::{Outcome, TournamentStore};
+use crate::venues::{event_start, format_countdown, VenueStore};
+use crate::volunteer_metrics::{FillStats, FillTracker, GameSnapshot, LAST_MINUTE_HOURS, REMINDER_WINDOW_HOURS};
 use crate::weather_client::WeatherClient;
 
+/// Parse a sheet or calendar start time like "10:00 AM", "8am" or "14:00"
+pub(crate) fn parse_time_string(time_str: &str) -> Option<chrono::NaiveTime> {
+    let formats = [
+        "%I:%M %p", // 10:00 AM
+        "%l:%M %p", // 8:00 AM
+        "%I:%M%p",  // 10:00AM
+        "%l:%M%p",  // 8:00AM
+        "%l%p",     // 8am
+        "%I%p",     // 10am
+        "%H:%M",    // 14:00
+    ];
+    
+    let mut upper_time = time_str.trim().to_uppercase();
+    // chrono can't build a time without minutes, so "8AM" becomes "8:00AM"
+    if !upper_time.contains(':') && (upper_time.ends_with("AM") || upper_time.ends_with("PM")) {
+        let split = upper_time.len() - 2;
+        upper_time = format!("{}:00{}", upper_time[..split].trim(), &upper_time[split..]);
+    }
+    for fmt in &formats {
+        if let Ok(t) = chrono::NaiveTime::parse_from_str(&upper_time, fmt) {
+            return Some(t);
+        }
+    }
+    None
+}
+
+/// Start of a time or range like "8am-9:30am"
+pub(crate) fn parse_start_time(time_str: &str) -> Option<chrono::NaiveTime> {
+    parse_time_string(time_str.split('-').next()?.trim())
+}
+
 #[derive(Clone)]
 pub struct BotService {
     google_client: GoogleClient,
     groupme_client: GroupMeClient,
     weather_client: WeatherClient,
+    members: MembersCache,
+    bring_lists: BringListStore,
+    results: ResultsStore,
+    stats: StatsStore,
+    venues: VenueStore,
+    tournament: TournamentStore,
+    webhooks: WebhookNotifier,
+    plugins: PluginManager,
+    features: FeatureFlags,
+    // Team name, emoji and facts as each group sees them
+    profiles: GroupProfiles,
+    // Which bot posts are about which game, for 🙋 replies
+    game_posts: GamePosts,
+    game_mode: GameMode,
+    scheduled_messages: ScheduledMessageStore,
+    absences: AbsenceStore,
+    // Moderator word list checked before names and notes reach the sheet
+    content_filter: ContentFilter,
+    // When volunteer spots get filled, for analytics and /metrics
+    fills: FillTracker,
+    // Picture days, fundraisers and the like, with their own reminder templates
+    special_events: SpecialEventStore,
     config: Config,
-    team_facts: Arc<TeamFactsProvider>,
     // Cache for event data to reduce API calls and enable volunteer modifications
     // Use Vec to support multiple events on the same day
     event_cache: Arc<RwLock<HashMap<NaiveDate, Vec<CorrelatedEvent>>>>,
+    // Live bot name, which "rename to ..." can change without a restart
+    bot_name: Arc<RwLock<BotName>>,
+    // Owner of GROUPME_ACCESS_TOKEN; GroupMe won't deliver a DM to them, so theirs go to the group
+    token_owner_id: Arc<RwLock<Option<String>>>,
+    // Bot that answers each group, from the callback routes tied to a group
+    route_bots: Arc<HashMap<String, String>>,
 }
 
 impl BotService {
@@ -28,23 +106,198 @@ impl BotService {
         let google_client = GoogleClient::new(config.clone());
         let groupme_client = GroupMeClient::new(config.clone());
         let weather_client = WeatherClient::new();
-        
-        // Initialize team facts provider
-        let team_facts = Arc::new(TeamFactsProvider::new(
-            config.team_name.clone(),
-            config.team_emoji.clone(),
-            config.enable_team_facts,
-            config.team_facts_file.clone(),
-        ));
+        let members = MembersCache::new(groupme_client.clone());
+        let config_bot_name = config.groupme_bot_name.clone();
         
         Self {
             google_client,
             groupme_client,
             weather_client,
+            members,
+            bring_lists: BringListStore::new(),
+            results: ResultsStore::new(),
+            stats: StatsStore::new(),
+            venues: VenueStore::new(),
+            tournament: TournamentStore::new(),
+            webhooks: WebhookNotifier::new(&config),
+            plugins: PluginManager::default(),
+            features: FeatureFlags::new(&config),
+            profiles: GroupProfiles::new(&config),
+            game_posts: GamePosts::default(),
+            game_mode: GameMode::default(),
+            scheduled_messages: ScheduledMessageStore::default(),
+            absences: AbsenceStore::new(),
+            content_filter: ContentFilter::new(&config.content_filter),
+            fills: FillTracker::new(),
+            special_events: SpecialEventStore::new(),
             config,
-            team_facts,
             event_cache: Arc::new(RwLock::new(HashMap::new())),
+            bot_name: Arc::new(RwLock::new(BotName::new(config_bot_name))),
+            token_owner_id: Arc::new(RwLock::new(None)),
+            route_bots: Arc::new(HashMap::new()),
+        }
+    }
+
+    /// Share the command parser's bot name so renames also change mention detection
+    pub fn with_bot_name(mut self, bot_name: Arc<RwLock<BotName>>) -> Self {
+        self.bot_name = bot_name;
+        self
+    }
+
+    /// Scripts that RunPlugin commands execute
+    pub fn with_plugins(mut self, plugins: PluginManager) -> Self {
+        self.plugins = plugins;
+        self
+    }
+
+    /// Callback routes, so messages a command posts on its own go out through the group's bot
+    pub fn with_routes(mut self, routes: &[CallbackRoute]) -> Self {
+        let route_bots = routes.iter()
+            .filter_map(|route| Some((route.group_id.clone()?, route.bot_id.clone())))
+            .collect();
+        self.route_bots = Arc::new(route_bots);
+        self
+    }
+
+    /// Share the command parser's game mode, so turning it on here relaxes mentions there
+    pub fn with_game_mode(mut self, game_mode: GameMode) -> Self {
+        self.game_mode = game_mode;
+        self
+    }
+
+    /// Share the store the scheduled message sender reads from
+    pub fn with_scheduled_messages(mut self, scheduled_messages: ScheduledMessageStore) -> Self {
+        self.scheduled_messages = scheduled_messages;
+        self
+    }
+
+    /// Share the command parser's feature flags so admin toggles apply everywhere
+    pub fn with_features(mut self, features: FeatureFlags) -> Self {
+        self.features = features;
+        self
+    }
+
+    pub async fn feature_enabled(&self, feature: Feature, group_id: Option<&str>) -> bool {
+        self.features.is_enabled(feature, group_id).await
+    }
+
+    /// How replies to a group should look
+    pub fn profile(&self, group_id: Option<&str>) -> &GroupProfile {
+        self.profiles.resolve(group_id)
+    }
+
+    /// A team fact for the group, or None when team facts are off there
+    pub async fn team_fact(&self, group_id: Option<&str>) -> Option<String> {
+        self.feature_enabled(Feature::TeamFacts, group_id).await.then(|| self.profile(group_id).team_facts.get_fact())
+    }
+
+    /// Forecast line and uploaded hourly chart for a game day, or None when weather is off
+    /// for the group or the location is unknown. The chart is left out if it can't be made.
+    pub async fn game_day_weather(&self, event: &CorrelatedEvent, group_id: Option<&str>) -> Option<(String, Option<String>)> {
+        let location = event.data.location.trim();
+        if location.is_empty() || location.eq_ignore_ascii_case("TBD") || !self.feature_enabled(Feature::Weather, group_id).await {
+            return None;
+        }
+        let forecast_line = match self.weather_client.get_forecast(location, event.event_date, &event.data.time).await {
+            Ok(line) => line,
+            Err(e) => {
+                warn!("Failed to fetch the game-day forecast: {}", e);
+                return None;
+            }
+        };
+
+        let game_hour = self.weather_client.game_hour(&event.data.time);
+        let (first_hour, last_hour) = crate::weather_chart::game_window(game_hour);
+        let png = match self.weather_client.get_hourly_forecast(location, event.event_date).await {
+            Ok(forecast) => crate::weather_chart::chart_png(&forecast, first_hour, last_hour, game_hour),
+            Err(e) => Err(e),
+        };
+        let chart_url = match png {
+            Ok(png) => match self.groupme_client.upload_image(png).await {
+                Ok(url) => Some(url),
+                Err(e) => {
+                    warn!("Failed to upload the weather chart: {}", e);
+                    None
+                }
+            },
+            Err(e) => {
+                warn!("Failed to make the weather chart: {}", e);
+                None
+            }
+        };
+        Some((forecast_line, chart_url))
+    }
+
+    /// Follow today's game, from an hour before first pitch until GAME_LENGTH_MINUTES after it
+    async fn start_game_mode(&self, team_emoji: &str) -> Result<String> {
+        let now = Utc::now();
+        let today = self.config.today();
+        let length = Duration::minutes(self.config.game_length_minutes as i64);
+        let games = self.schedule_with_bracket().await?.remove(&today).unwrap_or_default();
+        if games.is_empty() {
+            return Ok("❌ There's no game today, so there's nothing for game mode to follow.".to_string());
+        }
+
+        let mut next_opening = None;
+        for game in &games {
+            // A game with no start time is followed from now
+            let start = self.event_start(game).await;
+            let ends_at = start.unwrap_or(now) + length;
+            if ends_at <= now {
+                continue;
+            }
+            if let Some(start) = start.filter(|start| now < *start - Duration::hours(1)) {
+                next_opening.get_or_insert((start, game.data.time.clone()));
+                continue;
+            }
+
+            let prompt_every = (self.config.inning_prompt_minutes > 0)
+                .then(|| Duration::minutes(self.config.inning_prompt_minutes as i64));
+            self.game_mode.start(today, game.opponent(&self.config.team_name), ends_at, prompt_every).await;
+            let until = self.config.team_time(ends_at).format("%-I:%M %p");
+            return Ok(format!(
+                "🎬 Game mode on! {} {}\nNo need to mention me: post the score like \"4-2\" (us first) and share photos, I'll collect them. I'll sign off around {}.",
+                team_emoji,
+                game.format_matchup(),
+                until
+            ));
+        }
+
+        Ok(match next_opening {
+            Some((_, time)) => format!("⏳ Game mode opens an hour before first pitch ({}).", time),
+            None => "🏁 Today's game is already over.".to_string(),
+        })
+    }
+
+    /// Wrap-up posted when game mode ends
+    pub fn game_mode_summary(&self, game: &GameSession) -> String {
+        let mut summary = format!("🏁 Game mode off for {}.", game.date.format("%-m/%-d"));
+        if let Some(score) = game.describe_score(&self.config.team_name) {
+            summary.push_str(&format!("\nLast score: {}. Record the final with \"@{} final: we won 7-3\".", score, self.bot_name()));
+        }
+        if !game.photos.is_empty() {
+            summary.push_str(&format!("\n📸 {} photo(s) saved. \"@{} game photos\" shows them.", game.photos.len(), self.bot_name()));
         }
+        summary
+    }
+
+    /// Call before posting something about one game (e.g. a reminder) so 🙋 replies to it work
+    pub fn expect_game_post(&self, text: &str, date: NaiveDate) {
+        self.game_posts.expect(text, date);
+    }
+
+    /// One of the bot's own posts, echoed back through the callback
+    pub fn note_bot_post(&self, message_id: &str, text: &str) {
+        self.game_posts.on_bot_post(message_id, text);
+    }
+
+    /// Send an event to any OUTBOUND_WEBHOOKS subscribed to it
+    pub fn emit_webhook(&self, event: WebhookEvent) {
+        self.webhooks.emit(event);
+    }
+
+    fn bot_name(&self) -> String {
+        self.bot_name.read().map(|name| name.name().to_string()).unwrap_or_else(|_| self.config.groupme_bot_name.clone())
     }
 
     pub async fn correlate_data(&self) -> Result<HashMap<NaiveDate, Vec<CorrelatedEvent>>> {
@@ -55,10 +308,10 @@ impl BotService {
         let mut correlated_map: HashMap<NaiveDate, Vec<CorrelatedEvent>> = HashMap::new();
         
         // Populate directly from Google Sheets
-        for (date, time, location, home_team, snacks, livestream, scoreboard, pitch_count, gamechanger) in sheets_data {
+        for (date, time, location, home_team, snacks, livestream, scoreboard, pitch_count, gamechanger, notes) in sheets_data {
             info!("Processing sheet data for {}", date);
             
-            let event_data = EventData::new(
+            let mut event_data = EventData::new(
                 date,
                 time.clone(),
                 location,
@@ -69,6 +322,7 @@ impl BotService {
                 pitch_count,
                 gamechanger,
             );
+            event_data.notes = if notes.trim().is_empty() { None } else { Some(notes) };
             
             let summary = if !time.is_empty() && !home_team.is_empty() {
                 format!("{} - {}", time, home_team)
@@ -92,9 +346,149 @@ impl BotService {
             cache.clear();
             cache.extend(correlated_map.clone());
         }
+
+        self.track_fills(&correlated_map).await;
         
         Ok(correlated_map)
     }
+
+    /// Compare upcoming games' volunteer spots with the last load, so fill times are known
+    /// however the spot was filled (chat, signup page or straight in the sheet)
+    async fn track_fills(&self, events: &HashMap<NaiveDate, Vec<CorrelatedEvent>>) {
+        let today = self.config.today();
+        let mut snapshots: Vec<GameSnapshot> = Vec::new();
+        // Sign-ups go to a day's first game, so that's the one tracked
+        for event in events.iter().filter(|(date, _)| **date >= today).filter_map(|(_, games)| games.first()) {
+            let Some(start) = self.event_start(event).await else {
+                continue;
+            };
+            let roles = event.data.needed_roles(&self.config.team_name);
+            let needed = roles.iter().map(|(role, _)| *role).collect();
+            let filled = roles.iter().filter(|(_, person)| person.is_some()).map(|(role, _)| *role).collect();
+            snapshots.push((event.event_date, start, needed, filled));
+        }
+        self.fills.observe(&snapshots, Utc::now()).await;
+    }
+
+    /// "Saturday 5/3 (in 3 days)", counted from today where the team is
+    fn game_day(&self, date: NaiveDate) -> String {
+        crate::relative_dates::game_day(date, self.config.today())
+    }
+
+    /// Note that a reminder (`kind` is "24h", "briefing" or "15m") just went out for `event`,
+    /// so sign-ups right after it are credited to it
+    pub async fn record_reminder(&self, event: &CorrelatedEvent, kind: &str) {
+        if let Some(start) = self.event_start(event).await {
+            self.fills.record_reminder(event.event_date, start, kind, Utc::now()).await;
+        }
+    }
+
+    /// Upcoming special events: moderator-added ones plus SPECIAL_EVENTS_RANGE rows, soonest first
+    pub async fn upcoming_special_events(&self) -> Vec<SpecialEvent> {
+        let today = self.config.today();
+        let sheet = match &self.config.special_events_range {
+            Some(range) => match self.google_client.get_range_values(range).await {
+                Ok(rows) => rows.iter().filter_map(|row| SpecialEvent::from_row(row)).collect(),
+                Err(e) => {
+                    warn!("Failed to fetch special events: {}", e);
+                    Vec::new()
+                }
+            },
+            None => Vec::new(),
+        };
+        merge_events(self.special_events.list(today).await, sheet, today)
+    }
+
+    /// Special event reminders due now, ready to post. Each is marked sent as it's handed back.
+    pub async fn due_special_event_reminders(&self) -> Vec<String> {
+        let today = self.config.today();
+        let mut timed = Vec::new();
+        for event in self.upcoming_special_events().await {
+            let timezone = match &event.location {
+                Some(location) => self.venues.timezone_for(location).await,
+                None => None,
+            };
+            if let Some(start) = event.starts_at(timezone, self.config.reminder_start_hour) {
+                timed.push((event, start));
+            }
+        }
+        self.special_events
+            .take_due(&timed, Utc::now(), today)
+            .await
+            .into_iter()
+            .map(|(event, template)| render(&template.text, &event, today))
+            .collect()
+    }
+
+    /// "⏰ Picture Day reminders:" and each template with its lead time
+    async fn event_reminders_text(&self, kind: &str) -> String {
+        let event_type = crate::special_events::event_type(kind).unwrap_or(&crate::special_events::EVENT_TYPES[3]);
+        let lines: Vec<String> = self.special_events
+            .templates(kind)
+            .await
+            .iter()
+            .map(|t| format!("• {} before: {}", describe_lead(t.lead_hours), t.text.replace('\n', " / ")))
+            .collect();
+        format!("⏰ {} {} reminders:\n{}", event_type.emoji, event_type.label, lines.join("\n"))
+    }
+
+    /// Volunteer fill numbers, as served on /metrics
+    pub async fn volunteer_fill_stats(&self) -> FillStats {
+        self.fills.stats(Utc::now()).await
+    }
+
+    /// "@bot volunteer analytics"
+    async fn volunteer_analytics(&self) -> String {
+        let stats = self.volunteer_fill_stats().await;
+        if stats.games_played == 0 && stats.timed_fills == 0 {
+            return "📊 No volunteer history yet. Numbers show up once games have been tracked for a while.".to_string();
+        }
+        let days = |hours: f64| format!("{:.1} days", hours / 24.0);
+
+        let mut lines = vec![format!("📊 Volunteer analytics ({} game{} played)", stats.games_played, if stats.games_played == 1 { "" } else { "s" })];
+        if let Some(percent) = stats.fully_staffed_percent {
+            lines.push(format!("• Fully staffed: {:.0}% of games", percent));
+        }
+        if let Some(hours) = stats.avg_lead_hours {
+            lines.push(format!("• Spots get filled {} before the game on average", days(hours)));
+        }
+        if let Some(percent) = stats.last_minute_percent {
+            lines.push(format!("• {:.0}% are filled in the last {} hours", percent, LAST_MINUTE_HOURS as u32));
+        }
+
+        let roles: Vec<String> = stats.roles
+            .iter()
+            .filter(|r| r.needed > 0 || r.avg_lead_hours.is_some())
+            .map(|r| {
+                let label = crate::signup::SIGNUP_ROLES.iter().find(|(role, _)| *role == r.role).map_or(r.role, |(_, label)| *label);
+                let mut line = format!("• {}: {}/{} filled", label, r.filled, r.needed);
+                if let Some(hours) = r.avg_lead_hours {
+                    line.push_str(&format!(", usually {} ahead", days(hours)));
+                }
+                line
+            })
+            .collect();
+        if !roles.is_empty() {
+            lines.push(format!("\nBy role:\n{}", roles.join("\n")));
+        }
+        if !stats.reminders.is_empty() {
+            let mut section = format!("\nSign-ups within {} hours of a reminder:", REMINDER_WINDOW_HOURS as u32);
+            for r in &stats.reminders {
+                let label = match r.reminder.as_str() {
+                    "24h" => "24-hour reminder",
+                    "briefing" => "Game-day briefing",
+                    "15m" => "15-minute reminder",
+                    other => other,
+                };
+                let per_post = if r.posts > 0 { r.fills as f64 / r.posts as f64 } else { 0.0 };
+                section.push_str(&format!("\n• {}: {} after {} post{} ({:.1} per post)", label, r.fills, r.posts, if r.posts == 1 { "" } else { "s" }, per_post));
+            }
+            section.push_str(&format!("\n• Not after a reminder: {}", stats.unprompted_fills));
+            lines.push(section);
+        }
+        lines.push(format!("\n(Timing is from {} sign-ups seen as they happened.)", stats.timed_fills));
+        lines.join("\n")
+    }
     
     pub async fn get_cached_or_fresh_data(&self) -> Result<HashMap<NaiveDate, Vec<CorrelatedEvent>>> {
         // Check if cache is populated
@@ -133,19 +527,14 @@ impl BotService {
             
             // If date is today, check if time has passed
             if event.event_date == today {
-                // Try to parse start time from string like "10:00 AM" or "8am-9:30am"
-                if let Some(time_part) = event.data.time.split('-').next() {
-                    // Try parsing various formats
-                    let time_str = time_part.trim();
-                    
-                    // Helper to parse time
-                    let parsed_time = self.parse_time_string(time_str);
-                    
-                    if let Some(time) = parsed_time {
-                        if time > now.time() {
+                // Compare in the venue's time zone, from a start time like "10:00 AM" or "8am-9:30am"
+                match self.event_start(&event).await {
+                    Some(start) => {
+                        if start > Utc::now() {
                             return Ok(Some(event));
                         }
-                    } else {
+                    }
+                    None => {
                         // If can't parse time, assume it hasn't happened if it's today
                         // Or maybe return it if we are unsure?
                         // Let's err on side of showing it
@@ -157,25 +546,18 @@ impl BotService {
         
         Ok(None)
     }
-    
-    fn parse_time_string(&self, time_str: &str) -> Option<chrono::NaiveTime> {
-        let formats = [
-            "%I:%M %p", // 10:00 AM
-            "%l:%M %p", // 8:00 AM
-            "%I:%M%p",  // 10:00AM
-            "%l:%M%p",  // 8:00AM
-            "%l%p",     // 8am
-            "%I%p",     // 10am
-            "%H:%M",    // 14:00
-        ];
-        
-        let upper_time = time_str.to_uppercase();
-        for fmt in &formats {
-            if let Ok(t) = chrono::NaiveTime::parse_from_str(&upper_time, fmt) {
-                return Some(t);
-            }
-        }
-        None
+
+    /// Most recent game on or before today, e.g. the one a final score is being reported for
+    pub async fn find_last_event(&self) -> Result<Option<CorrelatedEvent>> {
+        let events_map = self.correlate_data().await?;
+        let today = self.config.today();
+
+        Ok(events_map
+            .values()
+            .flatten()
+            .filter(|event| event.event_date <= today)
+            .max_by(|a, b| a.event_date.cmp(&b.event_date).then_with(|| a.data.time.cmp(&b.data.time)))
+            .cloned())
     }
 
     pub async fn find_event_by_date(&self, query_date: NaiveDate) -> Result<Vec<CorrelatedEvent>> {
@@ -191,17 +573,81 @@ impl BotService {
         Ok(events_map.get(&query_date).cloned().unwrap_or_default())
     }
 
-    pub async fn handle_command(&self, command: BotCommand, sender_name: Option<&str>, user_id: Option<&str>, moderators_store: &crate::moderators::ModeratorsStore) -> Result<String> {
+    /// One line answering "next game <category>" for `event`; `label` is "Next" or the game's day
+    async fn category_answer(&self, event: &CorrelatedEvent, label: &str, category: &str, group_id: Option<&str>) -> String {
+        match category.to_lowercase().as_str() {
+            "location" => format!("⚾ {} game location: {}", label, event.data.format_location_with_link()),
+            "weather" if !self.feature_enabled(Feature::Weather, group_id).await => {
+                "🌤️ Weather is turned off for this group.".to_string()
+            }
+            "weather" => match self.weather_client.get_forecast(&event.data.location, event.data.date, &event.data.time).await {
+                Ok(forecast) => forecast,
+                Err(_) => "❌ Could not fetch weather forecast.".to_string(),
+            },
+            _ => match event.data.get_field(category) {
+                Some(data) => format!("⚾ {} game {}: {}", label, category, data),
+                None if label == "Next" => format!("❌ No {} information available for the next game.", category),
+                None => format!("❌ No {} information available for {}.", category, label),
+            },
+        }
+    }
+
+    /// `group_id` is the group the message came from, for per-group settings
+    pub async fn handle_command(&self, command: BotCommand, sender_name: Option<&str>, user_id: Option<&str>, group_id: Option<&str>, moderators_store: &crate::moderators::ModeratorsStore) -> Result<String> {
+        let private = command.private_output();
+        let response = self.execute_command(command, sender_name, user_id, group_id, moderators_store, false).await?;
+        match user_id {
+            Some(user) if private && !response.trim().is_empty() => self.reply_by_dm(user, sender_name, &response).await,
+            _ => Ok(response),
+        }
+    }
+
+    /// Send `response` to `user` privately; the group reply is just the acknowledgement.
+    /// If the DM can't go out the output is withheld rather than posted to the group.
+    async fn reply_by_dm(&self, user: &str, sender_name: Option<&str>, response: &str) -> Result<String> {
+        let mention = sender_name.map(|name| format!("@{} ", name)).unwrap_or_default();
+        match self.send_direct_message(user, response).await {
+            Ok(()) => Ok(format!("{}sent you a DM 📬", mention)),
+            Err(e) => {
+                warn!("Failed to DM {}: {}", user, e);
+                Ok(format!("{}❌ I couldn't DM you, so I'm keeping that out of the group. Code: DM001", mention))
+            }
+        }
+    }
+
+    /// `confirmed` is set when the user already answered a confirmation prompt for this command
+    async fn execute_command(&self, command: BotCommand, sender_name: Option<&str>, user_id: Option<&str>, group_id: Option<&str>, moderators_store: &crate::moderators::ModeratorsStore, confirmed: bool) -> Result<String> {
+        let profile = self.profiles.resolve(group_id);
         match command {
             BotCommand::NextGame => {
                 // @bot next game
-                match self.find_next_event().await? {
+                let projected = self.projected_bracket_games().await;
+                match self.find_next_game().await? {
                     Some(event) => {
-                        let mut response = format!("{} Next Game: {}\n", self.config.team_emoji, event.event_summary);
-                        response.push_str(&event.data.format_all());
+                        let mut response = format!("{} Next Game: {}\n", profile.team_emoji, event.event_summary);
+                        response.push_str(&event.data.format_all(self.config.today()));
+                        if let Some(preview) = self.opponent_preview(&event).await {
+                            response.push_str(&format!("📊 {}\n", preview));
+                        }
+                        if let Some(start) = self.event_start(&event).await {
+                            let until = start - Utc::now();
+                            if until > Duration::zero() {
+                                response.push_str(&format!("⏳ Starts in {}", format_countdown(until)));
+                                if let Some(tz) = self.venues.timezone_for(&event.data.location).await {
+                                    response.push_str(&format!(" (game time is {} local)", tz.name()));
+                                }
+                                response.push('\n');
+                            }
+                        }
+                        if let Some(url) = crate::game_card::game_url(&self.config, &self.id_for_event(&event).await?) {
+                            response.push_str(&format!("🔗 Game card: {}\n", url));
+                        }
+                        response.push_str(&projected);
                         
                         // Fetch weather
-                        if !event.data.location.is_empty() && event.data.location != "TBD" {
+                        if !event.data.location.is_empty() && event.data.location != "TBD"
+                            && self.feature_enabled(Feature::Weather, group_id).await
+                        {
                              match self.weather_client.get_forecast(&event.data.location, event.data.date, &event.data.time).await {
                                  Ok(forecast) => response.push_str(&format!("\n{}\n", forecast)),
                                  Err(e) => warn!("Failed to fetch weather: {}", e),
@@ -210,6 +656,7 @@ impl BotService {
                         
                         Ok(response)
                     }
+                    None if !projected.is_empty() => Ok(projected),
                     None => Ok("⚾ No upcoming games found.".to_string()),
                 }
             }
@@ -217,7 +664,7 @@ impl BotService {
             BotCommand::NextGames(count) => {
                 // @bot next X games
                 let events_map = self.correlate_data().await?;
-                let today = Utc::now().date_naive();
+                let today = self.config.today();
                 
                 let mut upcoming_events: Vec<CorrelatedEvent> = events_map.values().flatten().cloned().collect();
                 
@@ -231,10 +678,10 @@ impl BotService {
                     return Ok("⚾ No upcoming games found.".to_string());
                 }
                 
-                let mut response = format!("{} Next {} Games:\n\n", self.config.team_emoji, count.min(upcoming_events.len()));
+                let mut response = format!("{} Next {} Games:\n\n", profile.team_emoji, count.min(upcoming_events.len()));
                 
                 for event in upcoming_events.iter().take(count) {
-                    response.push_str(&format!("📅 {} - {}\n", event.event_date.format("%Y-%m-%d"), event.event_summary));
+                    response.push_str(&format!("📅 {} - {}\n", self.game_day(event.event_date), event.event_summary));
                     response.push_str(&format!("⏰ Time: {}\n", event.data.time));
                     response.push_str(&format!("📍 Location: {}\n", event.data.format_location_with_link()));
                     response.push_str(&format!("🏠 Home/Away: {}\n\n", event.data.home_team));
@@ -243,66 +690,342 @@ impl BotService {
                 Ok(response)
             }
             
-            BotCommand::NextGameCategory(category) => {
-                // @bot next game snacks
-                match self.find_next_event().await? {
-                    Some(event) => {
-                        match category.to_lowercase().as_str() {
-                            "location" => {
-                                Ok(format!("⚾ Next game location: {}", event.data.format_location_with_link()))
-                            }
-                            "weather" => {
-                                 if let Ok(forecast) = self.weather_client.get_forecast(&event.data.location, event.data.date, &event.data.time).await {
-                                     Ok(forecast)
-                                 } else {
-                                     Ok("❌ Could not fetch weather forecast.".to_string())
-                                 }
-                            }
-                            _ => {
-                                if let Some(data) = event.data.get_field(&category) {
-                                    Ok(format!("⚾ Next game {}: {}", category, data))
-                                } else {
-                                    Ok(format!("❌ No {} information available for the next game.", category))
-                                }
-                            }
-                        }
-                    }
-                    None => Ok("⚾ No upcoming games found.".to_string()),
+            BotCommand::NextGameCategory(categories, date) => {
+                // @bot next game snacks / who's got snacks and scoreboard saturday
+                let (event, label) = match date {
+                    Some(date) => (self.find_event_by_date(date).await?.into_iter().next(), self.game_day(date)),
+                    None => (self.find_next_event().await?, "Next".to_string()),
+                };
+                let Some(event) = event else {
+                    return Ok(match date {
+                        Some(date) => format!("⚾ No game on {}.", self.game_day(date)),
+                        None => "⚾ No upcoming games found.".to_string(),
+                    });
+                };
+                let mut answers = Vec::new();
+                for category in &categories {
+                    answers.push(self.category_answer(&event, &label, category, group_id).await);
                 }
+                Ok(answers.join("\n"))
             }
             
             BotCommand::LetsGo(_team) => {
                 // @bot lets go [team]
-                Ok(self.team_facts.get_fact())
+                Ok(self.team_fact(group_id).await.unwrap_or_else(|| profile.team_facts.cheer()))
             }
             
             BotCommand::Volunteer(date, role, person) => {
                 // If there are multiple games, try to assign to the first available one?
                 // For simplicity, we'll try to assign to ANY game on that date that has the role open.
                 // Or maybe we should just assign to the first one.
-                self.handle_volunteer_assignment(date, role, person, sender_name).await
+                if !confirmed {
+                    self.check_volunteer_name(&person, sender_name, BotCommand::Volunteer(date, role.clone(), person.clone())).await?;
+                }
+                let allow_past = self.can_backfill(user_id, moderators_store).await;
+                self.handle_volunteer_assignment(date, role, person, sender_name, allow_past).await
             }
             
             BotCommand::VolunteerNextGame(role, person) => {
                 // Find the next game date and volunteer for it
+                if !confirmed {
+                    self.check_volunteer_name(&person, sender_name, BotCommand::VolunteerNextGame(role.clone(), person.clone())).await?;
+                }
                 match self.find_next_event().await? {
                     Some(event) => {
-                        self.handle_volunteer_assignment(event.event_date, role, person, sender_name).await
+                        self.handle_volunteer_assignment(event.event_date, role, person, sender_name, false).await
                     }
                     None => Ok("❌ No upcoming games found to volunteer for.".to_string()),
                 }
             }
             
             BotCommand::ShowVolunteers(maybe_date) => {
-                self.handle_show_volunteers(maybe_date).await
+                let response = self.handle_show_volunteers(maybe_date, profile).await?;
+                if let Some(date) = maybe_date {
+                    self.game_posts.expect(&response, date);
+                }
+                Ok(response)
+            }
+
+            BotCommand::ClaimOpenRole(post_id, person) => {
+                let Some(date) = self.game_posts.game_for(&post_id) else {
+                    return Ok("🙋 Reply to a game reminder or a day's volunteer list and I'll sign you up for that game.".to_string());
+                };
+                let events = self.find_event_by_date(date).await?;
+                if events.is_empty() {
+                    return Ok(format!("❌ No event found for {}.", self.game_day(date)));
+                }
+                // Same order as the "Still needed" list, so the hand-raise takes the first one listed
+                let open: Vec<&str> = crate::signup::SIGNUP_ROLES
+                    .iter()
+                    .map(|(role, _)| *role)
+                    .filter(|role| events.iter().any(|e| e.data.is_role_available(role, &self.config.team_name)))
+                    .collect();
+                let Some(role) = open.first() else {
+                    return Ok(format!("🎉 Every spot for {} is already taken. Thanks anyway, {}!", date.format("%-m/%-d"), person));
+                };
+                let mut response = self.handle_volunteer_assignment(date, role.to_string(), person, sender_name, false).await?;
+                if open.len() > 1 {
+                    response.push_str(&format!(
+                        "\n🔄 Rather do something else? Still open: {}. Say \"@{} I'll do <role> {}\" instead.",
+                        open[1..].join(", "),
+                        self.bot_name(),
+                        date.format("%-m/%-d")
+                    ));
+                }
+                Ok(response)
             }
             
-            BotCommand::Commands => {
-                let team_spirit_text = if self.config.enable_team_facts {
-                    format!("Get a {} fact!", self.config.team_name)
+            BotCommand::SetGameMode(true) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can start game mode", profile.team_emoji)));
+                }
+                if let Some(game) = self.game_mode.current().await {
+                    return Ok(format!("🎬 Game mode is already on for {}.", game.date.format("%-m/%-d")));
+                }
+                self.start_game_mode(&profile.team_emoji).await
+            }
+            BotCommand::SetGameMode(false) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can end game mode", profile.team_emoji)));
+                }
+                match self.game_mode.stop().await {
+                    Some(game) => Ok(self.game_mode_summary(&game)),
+                    None => Ok("👍 Game mode isn't on.".to_string()),
+                }
+            }
+            BotCommand::LiveScore(ours, theirs) => {
+                let Some(game) = self.game_mode.record_score(ours, theirs).await else {
+                    return Ok(String::new());
+                };
+                Ok(format!("📣 {}", game.describe_score(&profile.team_name).unwrap_or_default()))
+            }
+            // Photos are collected quietly; the summary at the end says how many
+            BotCommand::CollectPhotos(urls) => {
+                if let Some(count) = self.game_mode.add_photos(&urls).await {
+                    info!("Game mode album now has {} photos", count);
+                }
+                Ok(String::new())
+            }
+            BotCommand::GamePhotos(date) => {
+                match self.game_mode.photos(date).await {
+                    Some((date, photos)) if !photos.is_empty() => Ok(format!(
+                        "📸 {} photo(s) from {}:\n{}",
+                        photos.len(),
+                        date.format("%-m/%-d"),
+                        photos.join("\n")
+                    )),
+                    _ => Ok("📸 No game photos yet. Turn on game mode and share photos during the game.".to_string()),
+                }
+            }
+
+            BotCommand::ScheduleMessage(date, time, text) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can schedule messages", profile.team_emoji)));
+                }
+                let now = Utc::now();
+                let team_now = self.config.team_time(now);
+                // Without a date, the next time the team's clock reads that time
+                let day = date.unwrap_or_else(|| {
+                    let today = team_now.date();
+                    if time > team_now.time() { today } else { today + Duration::days(1) }
+                });
+                let Some(send_at) = self.config.team_instant(day.and_time(time)) else {
+                    return Ok("❓ That time doesn't exist on that day (clocks change). Pick another time.".to_string());
+                };
+                if send_at <= now {
+                    return Ok("⏰ That time has already passed.".to_string());
+                }
+                let message = self.scheduled_messages
+                    .add(send_at, text, sender_name.unwrap_or("a moderator").to_string())
+                    .await;
+                Ok(format!("🗓️ Scheduled {}\nCancel it with \"@{} cancel scheduled message {}\".", message.describe(&self.config), self.bot_name(), message.id))
+            }
+            BotCommand::ListScheduledMessages => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can see scheduled messages", profile.team_emoji)));
+                }
+                let messages = self.scheduled_messages.list().await;
+                if messages.is_empty() {
+                    return Ok("🗓️ No scheduled messages.".to_string());
+                }
+                let lines: Vec<String> = messages.iter().map(|m| format!("• {}", m.describe(&self.config))).collect();
+                Ok(format!("🗓️ Scheduled messages:\n{}", lines.join("\n")))
+            }
+            BotCommand::CancelScheduledMessage(id) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can cancel scheduled messages", profile.team_emoji)));
+                }
+                match self.scheduled_messages.cancel(id).await {
+                    Some(message) => Ok(format!("🗑️ Cancelled {}", message.describe(&self.config))),
+                    None => Ok(format!("❓ There's no scheduled message #{}.", id)),
+                }
+            }
+
+            BotCommand::RecordAbsence(who, start, end) => {
+                let Some(who) = self.content_filter.screen(&who).await else {
+                    return Ok(FILTERED_REPLY.to_string());
+                };
+                let today = self.config.today();
+                let absence = self.absences
+                    .add(who, start, end, sender_name.unwrap_or("someone").to_string(), today)
+                    .await;
+                let mut response = format!("🏖️ Got it: {} out {}.", absence.who, absence.describe_dates());
+                // Spots they already hold during the trip need someone else
+                let held: Vec<String> = self.upcoming_assignments_matching(|name, date| absence.covers(name, date))
+                    .await?
+                    .into_iter()
+                    .map(|(date, role, _)| format!("{} on {}", role, date.format("%-m/%-d")))
+                    .collect();
+                if !held.is_empty() {
+                    response.push_str(&format!("\n⚠️ Still signed up for: {}. Ask a moderator to reassign, or find a swap.", held.join(", ")));
+                }
+                response.push_str(&format!("\nUndo with \"@{} remove absence {}\".", self.bot_name(), absence.id));
+                Ok(response)
+            }
+            BotCommand::ListAbsences => {
+                let absences = self.absences.list(self.config.today()).await;
+                if absences.is_empty() {
+                    return Ok("🏖️ Nobody has said they'll be away.".to_string());
+                }
+                let lines: Vec<String> = absences.iter().map(|a| format!("• {}", a.describe())).collect();
+                Ok(format!("🏖️ Away:\n{}", lines.join("\n")))
+            }
+            BotCommand::RemoveAbsence(id) => {
+                match self.absences.remove(id).await {
+                    Some(absence) => Ok(format!("🗑️ Removed {}", absence.describe())),
+                    None => Ok(format!("❓ There's no absence #{}.", id)),
+                }
+            }
+
+            BotCommand::Version => Ok(crate::version::version_text()),
+
+            BotCommand::VolunteerAnalytics => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can see volunteer analytics", profile.team_emoji)));
+                }
+                Ok(self.volunteer_analytics().await)
+            }
+
+            BotCommand::AddSpecialEvent(title, date, time, location) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can add events", profile.team_emoji)));
+                }
+                let today = self.config.today();
+                if date < today {
+                    return Ok(format!("❌ {} has already passed.", self.game_day(date)));
+                }
+                let kind = crate::special_events::classify(&title);
+                let event = SpecialEvent { id: 0, kind: kind.key.to_string(), title, date, time, location };
+                let event = self.special_events.add(event, today).await;
+                let leads: Vec<String> = self.special_events
+                    .templates(kind.key)
+                    .await
+                    .iter()
+                    .map(|t| describe_lead(t.lead_hours))
+                    .collect();
+                Ok(format!(
+                    "{} Added {}\nReminders go out {} before. Change them with \"@{} {} reminder 2d: ...\".",
+                    kind.emoji,
+                    event.describe(today),
+                    leads.join(" and "),
+                    self.bot_name(),
+                    kind.name(),
+                ))
+            }
+            BotCommand::ListSpecialEvents => {
+                let today = self.config.today();
+                let events = self.upcoming_special_events().await;
+                if events.is_empty() {
+                    return Ok("📅 No special events coming up.".to_string());
+                }
+                let lines: Vec<String> = events.iter().map(|e| format!("• {}", e.describe(today))).collect();
+                Ok(format!("📅 Coming up:\n{}", lines.join("\n")))
+            }
+            BotCommand::RemoveSpecialEvent(id) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can remove events", profile.team_emoji)));
+                }
+                match self.special_events.remove(id).await {
+                    Some(event) => Ok(format!("🗑️ Removed {}", event.describe(self.config.today()))),
+                    None => Ok(format!("❓ There's no event #{}. Events from the spreadsheet are removed there.", id)),
+                }
+            }
+            BotCommand::SetEventReminder(kind, lead_hours, text) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can change event reminders", profile.team_emoji)));
+                }
+                self.special_events.set_template(&kind, ReminderTemplate { lead_hours, text }).await;
+                Ok(self.event_reminders_text(&kind).await)
+            }
+            BotCommand::ShowEventReminders(kind) => {
+                Ok(self.event_reminders_text(&kind).await)
+            }
+            BotCommand::ResetEventReminders(kind) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can change event reminders", profile.team_emoji)));
+                }
+                if !self.special_events.reset_templates(&kind).await {
+                    return Ok("⏰ Those reminders haven't been changed.".to_string());
+                }
+                Ok(format!("↩️ Back to the usual reminders.\n{}", self.event_reminders_text(&kind).await))
+            }
+
+            BotCommand::BlockWord(word) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can change the word filter", profile.team_emoji)));
+                }
+                if self.content_filter.block(&word).await {
+                    Ok("🚫 Added to the word filter. Names and notes using it won't be written to the sheet.".to_string())
+                } else {
+                    Ok("🚫 That's already on the word filter.".to_string())
+                }
+            }
+            BotCommand::UnblockWord(word) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can change the word filter", profile.team_emoji)));
+                }
+                if self.content_filter.unblock(&word).await {
+                    Ok(format!("✅ Removed \"{}\" from the word filter.", word))
+                } else {
+                    Ok(format!("❓ \"{}\" isn't on the word filter.", word))
+                }
+            }
+            BotCommand::ListBlockedWords => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can see the word filter", profile.team_emoji)));
+                }
+                let words = self.content_filter.list().await;
+                let mut response = if words.is_empty() {
+                    "🚫 The word filter is empty.".to_string()
+                } else {
+                    format!("🚫 Blocked words:\n{}", words.iter().map(|w| format!("• {}", w)).collect::<Vec<_>>().join("\n"))
+                };
+                if self.content_filter.is_off() {
+                    response.push_str("\n(CONTENT_FILTER=off, so nothing is being checked right now.)");
+                }
+                Ok(response)
+            }
+
+            BotCommand::Commands | BotCommand::HelpByDm => {
+                let team_spirit_text = if self.feature_enabled(Feature::TeamFacts, group_id).await {
+                    format!("Get a {} fact!", profile.team_name)
                 } else {
                     "Show team spirit!".to_string()
                 };
+                let bot_name = self.bot_name();
                 
                 Ok(format!(
                     "⚾ {} Commands:
@@ -323,27 +1046,27 @@ impl BotService {
                      📋 Categories: time, location, home, snacks, livestream, scoreboard, pitchcount, gamechanger
 
                      {} Let's go {}! ⚾",
-                    self.config.groupme_bot_name,
-                    self.config.team_emoji,
-                    self.config.groupme_bot_name,
-                    self.config.groupme_bot_name,
-                    self.config.groupme_bot_name,
-                    self.config.team_emoji,
-                    self.config.groupme_bot_name,
-                    self.config.team_name.to_lowercase(),
+                    bot_name,
+                    profile.team_emoji,
+                    bot_name,
+                    bot_name,
+                    bot_name,
+                    profile.team_emoji,
+                    bot_name,
+                    profile.team_name.to_lowercase(),
                     team_spirit_text,
-                    self.config.team_emoji,
-                    self.config.groupme_bot_name,
-                    self.config.groupme_bot_name,
-                    self.config.groupme_bot_name,
-                    self.config.team_emoji,
-                    self.config.team_name
+                    profile.team_emoji,
+                    bot_name,
+                    bot_name,
+                    bot_name,
+                    profile.team_emoji,
+                    profile.team_name
                 ))
             }
             BotCommand::RemoveVolunteer(person, role, date) => {
                 let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
                 if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
-                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can remove volunteers", self.config.team_emoji)));
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can remove volunteers", profile.team_emoji)));
                 }
                 
                 // If date is provided, use it. Otherwise, find the next game.
@@ -371,7 +1094,7 @@ impl BotService {
             BotCommand::AssignVolunteer(person, role, date) => {
                 let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
                 if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
-                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can assign volunteers", self.config.team_emoji)));
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can assign volunteers", profile.team_emoji)));
                 }
                 
                 // If date is provided, use it. Otherwise, find the next game.
@@ -388,55 +1111,1332 @@ impl BotService {
                 // or just rely on the standard message.
                 // The person argument is the volunteer's name.
                 
-                self.handle_volunteer_assignment(target_date, role, person, None).await
+                if !confirmed {
+                    self.check_volunteer_name(&person, sender_name, BotCommand::AssignVolunteer(person.clone(), role.clone(), Some(target_date))).await?;
+                }
+                let allow_past = self.can_backfill(user_id, moderators_store).await;
+                self.handle_volunteer_assignment(target_date, role, person, None, allow_past).await
             },
             BotCommand::AddModerator(new_mod_id) => {
                 let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
                 if !moderators_store.is_admin(user, &self.config.admin_user_id) {
-                    return Err(BotError::InvalidCommand(format!("{} Only the admin can add moderators", self.config.team_emoji)));
+                    return Err(BotError::InvalidCommand(format!("{} Only the admin can add moderators", profile.team_emoji)));
                 }
                 moderators_store.add_moderator(new_mod_id.clone()).await;
-                Ok(format!("{} Added moderator: {}", self.config.team_emoji, new_mod_id))
+                Ok(format!("{} Added moderator: {}", profile.team_emoji, self.members.display(&new_mod_id).await))
             },
             BotCommand::RemoveModerator(mod_id) => { 
                 let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?; 
                 if !moderators_store.is_admin(user, &self.config.admin_user_id) { 
-                    return Err(BotError::InvalidCommand(format!("{} Only the admin can remove moderators", self.config.team_emoji))); 
+                    return Err(BotError::InvalidCommand(format!("{} Only the admin can remove moderators", profile.team_emoji))); 
                 } 
                 let removed = moderators_store.remove_moderator(&mod_id).await; 
+                let display = self.members.display(&mod_id).await;
                 if removed { 
-                    Ok(format!("{} Removed moderator: {}", self.config.team_emoji, mod_id)) 
+                    Ok(format!("{} Removed moderator: {}", profile.team_emoji, display)) 
                 } else { 
-                    Ok(format!("{} {} was not a moderator", self.config.team_emoji, mod_id)) 
+                    Ok(format!("{} {} was not a moderator", profile.team_emoji, display)) 
                 } 
             },
             BotCommand::ListModerators => {
-                let mods = moderators_store.list_moderators().await;
+                let mut mods = Vec::new();
+                for mod_id in moderators_store.list_moderators().await {
+                    mods.push(self.members.display(&mod_id).await);
+                }
+                let admin = self.members.display(&self.config.admin_user_id).await;
                 if mods.is_empty() {
-                    Ok(format!("{} No moderators assigned\nAdmin: {}", self.config.team_emoji, self.config.admin_user_id))
+                    Ok(format!("{} No moderators assigned\nAdmin: {}", profile.team_emoji, admin))
                 } else {
-                    Ok(format!("{} Moderators:\n{}\n\nAdmin: {}", self.config.team_emoji, mods.join("\n"), self.config.admin_user_id))
+                    Ok(format!("{} Moderators:\n{}\n\nAdmin: {}", profile.team_emoji, mods.join("\n"), admin))
                 }
             },
             BotCommand::ListBotMessages(count) => {
                 let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
                 if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
-                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can list bot messages", self.config.team_emoji)));
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can list bot messages", profile.team_emoji)));
                 }
-                self.handle_list_bot_messages(count).await
+                self.handle_list_bot_messages(count, profile).await
             }
-        }
-    }
-
-    pub async fn send_response(&self, message: &str) -> Result<()> {
-        self.groupme_client.send_message(message).await
+            BotCommand::WhoIs(target_id) => {
+                match self.members.nickname(&target_id).await {
+                    Some(nickname) => Ok(format!("{} {} is {}", profile.team_emoji, target_id, nickname)),
+                    None => Ok(format!("❓ I couldn't find user {} in the group.", target_id)),
+                }
+            }
+            BotCommand::RequestConfirmation(pending) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can do that", profile.team_emoji)));
+                }
+                Ok(format!("⚠️ {}. Reply 'confirm' within {}s to proceed, or 'cancel'.", self.describe_pending_action(&pending).await, CONFIRMATION_WINDOW_SECONDS))
+            }
+            BotCommand::Confirmed(command) => {
+                Box::pin(self.execute_command(*command, sender_name, user_id, group_id, moderators_store, true)).await
+            }
+            BotCommand::CancelPending => {
+                Ok("👍 Cancelled, nothing was changed.".to_string())
+            }
+            BotCommand::SetGameNote(date, note) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can set game notes", profile.team_emoji)));
+                }
+                let Some(note) = self.content_filter.screen(&note).await else {
+                    return Ok(FILTERED_REPLY.to_string());
+                };
+                let Some(target_date) = self.resolve_target_date(date).await? else {
+                    return Ok("❌ No upcoming games found.".to_string());
+                };
+                match self.google_client.update_game_notes(target_date, &note).await {
+                    Ok(_) => {
+                        self.correlate_data().await?;
+                        Ok(format!("📝 Note added for {}: {}", target_date, note))
+                    }
+                    Err(e) => {
+                        warn!("Failed to update Google Sheet: {}", e);
+                        Ok("❌ Update failed. Code: NOTE001".to_string())
+                    }
+                }
+            }
+            BotCommand::CancelGame(date, reason) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can cancel games", profile.team_emoji)));
+                }
+                let reason = match reason {
+                    Some(reason) => match self.content_filter.screen(&reason).await {
+                        Some(reason) => Some(reason),
+                        None => return Ok(FILTERED_REPLY.to_string()),
+                    },
+                    None => None,
+                };
+                let Some(target_date) = self.resolve_target_date(date).await? else {
+                    return Ok("❌ No upcoming games found.".to_string());
+                };
+                let events = self.find_event_by_date(target_date).await?;
+                let Some(event) = events.first() else {
+                    return Ok(format!("❌ No game found for {}.", self.game_day(target_date)));
+                };
+                let cancelled = match &reason {
+                    Some(reason) => format!("{} - {}", CANCELLED_NOTE, reason),
+                    None => CANCELLED_NOTE.to_string(),
+                };
+                // Keep whatever the note already said ("bring water", "pictures at 9")
+                let note = match event.data.notes.as_deref().map(str::trim).filter(|n| !n.is_empty()) {
+                    Some(existing) => format!("{} | {}", existing, cancelled),
+                    None => cancelled,
+                };
+                match self.google_client.update_game_notes(target_date, &note).await {
+                    Ok(_) => {
+                        self.correlate_data().await?;
+                        self.emit_webhook(WebhookEvent::GameCancelled { date: target_date, note: note.clone() });
+                        Ok(format!("🚫 The game on {} is cancelled{}", self.game_day(target_date), reason.map(|r| format!(": {}", r)).unwrap_or_default()))
+                    }
+                    Err(e) => {
+                        warn!("Failed to update Google Sheet: {}", e);
+                        Ok("❌ Update failed. Code: NOTE003".to_string())
+                    }
+                }
+            }
+            BotCommand::ClearGameNote(date) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can clear game notes", profile.team_emoji)));
+                }
+                let Some(target_date) = self.resolve_target_date(date).await? else {
+                    return Ok("❌ No upcoming games found.".to_string());
+                };
+                match self.google_client.update_game_notes(target_date, "").await {
+                    Ok(_) => {
+                        self.correlate_data().await?;
+                        Ok(format!("🧹 Cleared the note for {}", target_date))
+                    }
+                    Err(e) => {
+                        warn!("Failed to update Google Sheet: {}", e);
+                        Ok("❌ Update failed. Code: NOTE002".to_string())
+                    }
+                }
+            }
+            BotCommand::RequestBringItems(event, items) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can set up bring lists", profile.team_emoji)));
+                }
+                self.bring_lists.request_items(&event, items.clone()).await;
+                Ok(format!("🎉 Added to the {} list: {}\nSign up with \"@{} I'll bring <item> for the {}\"", event, items.join(", "), self.bot_name(), event))
+            }
+            BotCommand::BringItem(event, item, person) => {
+                let (Some(item), Some(person)) = (self.content_filter.screen(&item).await, self.content_filter.screen(&person).await) else {
+                    return Ok(FILTERED_REPLY.to_string());
+                };
+                let Some(event) = self.bring_lists.resolve_event(event.as_deref()).await else {
+                    return Ok(self.which_bring_list_message().await);
+                };
+                self.bring_lists.add_claim(&event, person.clone(), item.clone()).await;
+                Ok(format!("🎉 Thanks {}! You're bringing {} for the {}.", person, item, event))
+            }
+            BotCommand::BringListStatus(event) => {
+                let Some(event) = self.bring_lists.resolve_event(event.as_deref()).await else {
+                    return Ok(self.which_bring_list_message().await);
+                };
+                let Some(list) = self.bring_lists.get(&event).await else {
+                    return Ok(format!("🎉 Nobody has set up a {} list yet.", event));
+                };
+
+                let mut response = format!("🎉 {} bring list:\n", event);
+                for claim in &list.claims {
+                    response.push_str(&format!("✅ {} - {}\n", claim.person, claim.item));
+                }
+                let remaining = list.remaining_items();
+                if remaining.is_empty() {
+                    response.push_str("\nEverything requested is covered! 🙌");
+                } else {
+                    response.push_str(&format!("\n⚠️ Still needed: {}", remaining.join(", ")));
+                }
+                Ok(response)
+            }
+            BotCommand::ClearBringList(event) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can clear bring lists", profile.team_emoji)));
+                }
+                if self.bring_lists.clear(&event).await {
+                    Ok(format!("🧹 Cleared the {} list", event))
+                } else {
+                    Ok(format!("🎉 There's no {} list to clear.", event))
+                }
+            }
+            BotCommand::RecordResult(date, opponent, our_score, their_score) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can record results", profile.team_emoji)));
+                }
+
+                // No date means the most recent game on the schedule
+                let game = match date {
+                    Some(d) => self.find_event_by_date(d).await?.into_iter().next(),
+                    None => self.find_last_event().await?,
+                };
+                let Some(date) = date.or(game.as_ref().map(|g| g.event_date)) else {
+                    return Ok("❌ I couldn't tell which game that was. Try \"final 7-3 vs Chaos on 4/12\"".to_string());
+                };
+                let Some(opponent) = opponent.or_else(|| game.and_then(|g| g.opponent(&self.config.team_name))) else {
+                    return Ok(format!("❌ Who was the {} game against? Try \"final {}-{} vs <team>\"", date.format("%-m/%-d"), our_score, their_score));
+                };
+
+                let result = GameResult { date, opponent: opponent.clone(), our_score, their_score };
+                let summary = result.summary();
+                self.results.record(result).await;
+                let mut response = format!("📊 Recorded: {} vs {}", summary, opponent);
+
+                // A tournament game moves the bracket along
+                let outcome = match our_score.cmp(&their_score) {
+                    std::cmp::Ordering::Greater => Some(Outcome::Won),
+                    std::cmp::Ordering::Less => Some(Outcome::Lost),
+                    std::cmp::Ordering::Equal => None,
+                };
+                if let Some(outcome) = outcome {
+                    if let Some(number) = self.tournament.record_result(date, &opponent, outcome).await {
+                        response.push_str(&format!("\n{}", self.bracket_update(number).await));
+                    }
+                }
+                Ok(response)
+            }
+            BotCommand::LogPlayerStats(date, player, counts) => {
+                // No date means the most recent game, or today if the schedule is empty
+                let date = match date {
+                    Some(d) => d,
+                    None => self.find_last_event().await?
+                        .map(|event| event.event_date)
+                        .unwrap_or_else(|| self.config.today()),
+                };
+                let summary = counts.summary();
+                self.stats.record(StatLine { date, player: player.clone(), counts }).await;
+                Ok(format!("📈 Logged for {} on {}: {}", player, date.format("%-m/%-d"), summary))
+            }
+            BotCommand::PlayerStats(player) => {
+                let Some(totals) = self.stats.player_totals(&player).await else {
+                    return Ok(format!("📈 No stats logged for {} yet. Try \"stat {} 2-3, 1 RBI\"", player, player));
+                };
+                let avg = totals.counts.average().map(|a| format!(" ({})", a)).unwrap_or_default();
+                Ok(format!(
+                    "📈 {}: {}{} over {} game{}",
+                    totals.player,
+                    totals.counts.summary(),
+                    avg,
+                    totals.games,
+                    if totals.games == 1 { "" } else { "s" }
+                ))
+            }
+            BotCommand::TeamStats => {
+                let totals = self.stats.team_totals().await;
+                if totals.is_empty() {
+                    return Ok("📈 No stats logged yet. Try \"stat Jake 2-3, 1 RBI\"".to_string());
+                }
+                let mut response = format!("{} Team stats:\n", profile.team_emoji);
+                for (rank, total) in totals.iter().take(10).enumerate() {
+                    let avg = total.counts.average().map(|a| format!(" ({})", a)).unwrap_or_default();
+                    response.push_str(&format!("{}. {} {}{}\n", rank + 1, total.player, total.counts.summary(), avg));
+                }
+                if let Some(rbi_leader) = totals.iter().filter(|t| t.counts.rbi > 0).max_by_key(|t| t.counts.rbi) {
+                    response.push_str(&format!("\n🏆 RBI leader: {} ({})", rbi_leader.player, rbi_leader.counts.rbi));
+                }
+                Ok(response)
+            }
+            BotCommand::ClearAssignments(person) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can clear someone's assignments", profile.team_emoji)));
+                }
+                let assignments = self.upcoming_assignments_for(&person).await?;
+                if assignments.is_empty() {
+                    return Ok(format!("👍 {} isn't signed up for any upcoming games.", person));
+                }
+
+                let mut cleared = Vec::new();
+                for (date, role) in assignments {
+                    match self.google_client.update_volunteer_assignment(date, role, "").await {
+                        Ok(_) => cleared.push(format!("{} on {}", role, date.format("%-m/%-d"))),
+                        Err(e) => {
+                            warn!("Failed to update Google Sheet: {}", e);
+                            return Ok("❌ Update failed. Code: VOL005".to_string());
+                        }
+                    }
+                }
+                self.correlate_data().await?;
+                Ok(format!("🧹 Cleared {}'s spots: {}", person, cleared.join(", ")))
+            }
+            BotCommand::ClearVolunteers(date) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can clear a game's volunteers", profile.team_emoji)));
+                }
+                let day = date.format("%-m/%-d");
+                let Some(spots) = self.volunteers_on(date).await? else {
+                    return Ok(format!("❌ No game found on {}.", day));
+                };
+                if spots.is_empty() {
+                    return Ok(format!("👍 Nobody is signed up for {}.", day));
+                }
+
+                let changes: Vec<(&str, &str)> = spots.iter().map(|(role, _)| (*role, "")).collect();
+                if let Err(e) = self.google_client.update_volunteer_assignments(date, &changes).await {
+                    warn!("Failed to update Google Sheet: {}", e);
+                    return Ok("❌ Update failed. Code: VOL005".to_string());
+                }
+                self.correlate_data().await?;
+                let cleared: Vec<String> = spots.iter().map(|(role, name)| format!("{} ({})", role, name)).collect();
+                Ok(format!("🧹 Cleared {} spot(s) for {}: {}", cleared.len(), day, cleared.join(", ")))
+            }
+            BotCommand::CopyVolunteers(from, to) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can copy volunteers", profile.team_emoji)));
+                }
+                let (from_day, to_day) = (from.format("%-m/%-d"), to.format("%-m/%-d"));
+                let Some(spots) = self.volunteers_on(from).await? else {
+                    return Ok(format!("❌ No game found on {}.", from_day));
+                };
+                if self.volunteers_on(to).await?.is_none() {
+                    return Ok(format!("❌ No game found on {}.", to_day));
+                }
+                if spots.is_empty() {
+                    return Ok(format!("👍 Nobody is signed up for {}, so there's nothing to copy.", from_day));
+                }
+
+                let changes: Vec<(&str, &str)> = spots.iter().map(|(role, name)| (*role, name.as_str())).collect();
+                if let Err(e) = self.google_client.update_volunteer_assignments(to, &changes).await {
+                    warn!("Failed to update Google Sheet: {}", e);
+                    return Ok("❌ Update failed. Code: VOL005".to_string());
+                }
+                self.correlate_data().await?;
+                let copied: Vec<String> = spots.iter().map(|(role, name)| format!("{}: {}", role, name)).collect();
+                Ok(format!("📋 Copied {} volunteer(s) from {} to {}\n{}", copied.len(), from_day, to_day, copied.join("\n")))
+            }
+            BotCommand::SetVenueTimezone(venue, timezone) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can change venues", profile.team_emoji)));
+                }
+                match timezone {
+                    Some(name) => {
+                        let Ok(tz) = name.parse::<chrono_tz::Tz>() else {
+                            return Ok(format!("❓ I don't know the time zone \"{}\". Use a name like America/Chicago.", name));
+                        };
+                        self.venues.set_timezone(&venue, Some(tz)).await;
+                        Ok(format!("🕐 Games at {} now use {} time for reminders and countdowns.", venue, tz.name()))
+                    }
+                    None => {
+                        self.venues.set_timezone(&venue, None).await;
+                        Ok(format!("🕐 Games at {} are back on our local time.", venue))
+                    }
+                }
+            }
+            BotCommand::ListVenues => {
+                let venues: Vec<_> = self.venues.list().await.into_iter().filter(|v| v.timezone.is_some()).collect();
+                if venues.is_empty() {
+                    return Ok("📍 Every venue uses our local time. Set one with \"timezone for Tournament Park is America/Chicago\".".to_string());
+                }
+                let mut response = "📍 Venue time zones:\n".to_string();
+                for venue in venues {
+                    response.push_str(&format!("• {}: {}\n", venue.name, venue.timezone.unwrap_or_default()));
+                }
+                Ok(response)
+            }
+            BotCommand::StartTournament(name) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can set up a tournament", profile.team_emoji)));
+                }
+                self.tournament.start(&name).await;
+                Ok(format!("🏆 Started {}. Add games with \"bracket game 1 6/14 9am at Tournament Park vs Storm\" and later ones with \"... if we win game 1\".", name))
+            }
+            BotCommand::SetBracketGame(game) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can set up a tournament", profile.team_emoji)));
+                }
+                let line = game.describe();
+                self.tournament.set_game(game).await;
+                Ok(format!("🏆 Saved {}", line))
+            }
+            BotCommand::RecordBracketResult(number, outcome) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can record results", profile.team_emoji)));
+                }
+                if !self.tournament.record_outcome(number, outcome).await {
+                    return Ok(format!("❓ There's no bracket game {}. Try \"bracket\" to see the games.", number));
+                }
+                Ok(self.bracket_update(number).await)
+            }
+            BotCommand::ShowBracket => match self.tournament.current().await {
+                Some(tournament) => Ok(tournament.describe()),
+                None => Ok("🏆 No tournament set up. Moderators can start one with \"start tournament Summer Slam\".".to_string()),
+            },
+            BotCommand::SetTournamentDates(start, end) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can set up a tournament", profile.team_emoji)));
+                }
+                self.tournament.set_dates(start, end).await;
+                Ok(format!("🏆 Tournament runs {} to {}. It'll show up in that week's moderator digest.", start.format("%-m/%-d"), end.format("%-m/%-d")))
+            }
+            BotCommand::SetTournamentLogistics(label, value) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can set up a tournament", profile.team_emoji)));
+                }
+                let response = match &value {
+                    Some(value) => format!("🏆 Tournament {}: {}", label, value),
+                    None => format!("🧹 Removed the tournament {}", label),
+                };
+                self.tournament.set_logistics(&label, value).await;
+                Ok(response)
+            }
+            BotCommand::SignupLink(date) => {
+                let date = match date {
+                    Some(date) => date,
+                    None => match self.find_next_event().await? {
+                        Some(event) => event.event_date,
+                        None => return Ok("❌ No upcoming games found.".to_string()),
+                    },
+                };
+                match crate::signup::signup_url(&self.config, date) {
+                    Some(url) => Ok(format!(
+                        "🔗 Signup page for {} - share it with helpers who aren't in GroupMe:\n{}",
+                        date.format("%-m/%-d"),
+                        url
+                    )),
+                    None => Ok("❌ Signup links need PUBLIC_BASE_URL and SIGNUP_SECRET to be set.".to_string()),
+                }
+            }
+            BotCommand::QrCode(target) => {
+                let (label, url) = match target {
+                    QrTarget::Calendar => ("📅 Scan to subscribe to the season calendar".to_string(), crate::calendar_feed::calendar_feed_url(&self.config)),
+                    QrTarget::Signup(date) => {
+                        let date = match date {
+                            Some(date) => date,
+                            None => match self.find_next_event().await? {
+                                Some(event) => event.event_date,
+                                None => return Ok("❌ No upcoming games found.".to_string()),
+                            },
+                        };
+                        (format!("📝 Scan to volunteer for {}", date.format("%-m/%-d")), crate::signup::signup_url(&self.config, date))
+                    }
+                };
+                let Some(url) = url else {
+                    return Ok("❌ QR codes need PUBLIC_BASE_URL to be set (and SIGNUP_SECRET for signup pages).".to_string());
+                };
+
+                // The image URL on the last line goes out as an attachment
+                let png = crate::qr::qr_png(&url)?;
+                match self.groupme_client.upload_image(png).await {
+                    Ok(image_url) => Ok(format!("{}: {}\n{}", label, url, image_url)),
+                    Err(e) => {
+                        warn!("Failed to upload QR code: {}", e);
+                        Ok(format!("{}: {}\n❌ Couldn't post the QR image. Code: QR001", label, url))
+                    }
+                }
+            }
+            BotCommand::AddToCalendar(date) => {
+                let event = match date {
+                    Some(date) => self.find_event_by_date(date).await?.into_iter().next(),
+                    None => self.find_next_game().await?,
+                };
+                let Some(event) = event else {
+                    return Ok("❌ No upcoming games found.".to_string());
+                };
+                let id = self.id_for_event(&event).await?;
+                match crate::calendar_feed::event_url(&self.config, &id) {
+                    Some(url) => Ok(format!(
+                        "📅 Tap to add {} ({}) to your calendar:\n{}",
+                        event.event_date.format("%-m/%-d"),
+                        event.format_matchup(),
+                        url
+                    )),
+                    None => Ok("❌ Calendar links need PUBLIC_BASE_URL to be set.".to_string()),
+                }
+            }
+            BotCommand::Weather(date) => {
+                if !self.feature_enabled(Feature::Weather, group_id).await {
+                    return Ok("🌤️ Weather is turned off for this group.".to_string());
+                }
+                let event = match date {
+                    Some(date) => self.find_event_by_date(date).await?.into_iter().next(),
+                    None => self.find_next_game().await?,
+                };
+                let Some(event) = event else {
+                    return Ok(match date {
+                        Some(date) => format!("⚾ No game on {}, so there's no forecast to check.", self.game_day(date)),
+                        None => "⚾ No upcoming games found.".to_string(),
+                    });
+                };
+                let day = self.game_day(event.event_date);
+                if event.data.location.trim().is_empty() || event.data.location == "TBD" {
+                    return Ok(format!("📍 The {} game doesn't have a location yet, so I can't check the weather.", event.event_date.format("%a %-m/%-d")));
+                }
+                match self.weather_client.get_forecast(&event.data.location, event.data.date, &event.data.time).await {
+                    Ok(forecast) => Ok(format!("🌤️ {} {} ({})\n{}", day, event.data.time, event.format_matchup(), forecast)),
+                    Err(e) => {
+                        warn!("Failed to fetch weather: {}", e);
+                        Ok("❌ Could not fetch weather forecast.".to_string())
+                    }
+                }
+            }
+            BotCommand::RunPlugin(name, text) => {
+                let Some(plugin) = self.plugins.get(&name) else {
+                    return Ok(format!("❌ The {} plugin isn't loaded.", name));
+                };
+                let today = self.config.today();
+                let mut upcoming: Vec<CorrelatedEvent> = self.schedule_with_bracket().await?
+                    .into_values()
+                    .flatten()
+                    .filter(|e| e.event_date >= today)
+                    .collect();
+                upcoming.sort_by_key(|e| (e.event_date, parse_start_time(&e.data.time)));
+
+                match plugin.run_blocking(&text, sender_name.unwrap_or_default(), upcoming).await {
+                    Ok(output) => {
+                        for message in &output.messages {
+                            if let Err(e) = self.send_to_group(group_id, message).await {
+                                warn!("Failed to send message from plugin {}: {}", name, e);
+                            }
+                        }
+                        Ok(output.reply.unwrap_or_default())
+                    }
+                    Err(e) => {
+                        warn!("{}", e);
+                        Ok(format!("❌ The {} plugin ran into a problem. Code: PLG001", name))
+                    }
+                }
+            }
+            BotCommand::SetFeature(feature, enabled, all_groups) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_admin(user, &self.config.admin_user_id) {
+                    return Err(BotError::InvalidCommand(format!("{} Only the admin can turn features on or off", profile.team_emoji)));
+                }
+                let scope = if all_groups { None } else { group_id };
+                self.features.set(feature, scope, enabled).await;
+                Ok(format!(
+                    "🎛️ {} {} {}.",
+                    feature.label(),
+                    if enabled { "turned on" } else { "turned off" },
+                    if scope.is_some() { "for this group" } else { "for every group" }
+                ))
+            }
+            BotCommand::ListFeatures => {
+                let mut response = "🎛️ Features for this group:\n".to_string();
+                for (feature, enabled) in self.features.summary(group_id).await {
+                    response.push_str(&format!("{} {}\n", if enabled { "✅" } else { "⛔" }, feature.label()));
+                }
+                response.push_str("Admins can say \"disable weather\" or \"enable team facts everywhere\".");
+                Ok(response)
+            }
+            BotCommand::TournamentInfo => match self.tournament.current().await {
+                Some(tournament) => Ok(tournament.info()),
+                None => Ok("🏆 No tournament set up. Moderators can start one with \"start tournament Summer Slam\".".to_string()),
+            },
+            BotCommand::ClearTournament => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can clear the tournament", profile.team_emoji)));
+                }
+                self.tournament.clear().await;
+                Ok("🧹 Tournament cleared.".to_string())
+            }
+            BotCommand::ModeratorDigest => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_authorized(user, &self.config.admin_user_id).await {
+                    return Err(BotError::InvalidCommand(format!("{} Only admins and moderators can see the digest", profile.team_emoji)));
+                }
+                self.moderator_digest().await
+            }
+            BotCommand::ImportSchedule(url) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_admin(user, &self.config.admin_user_id) {
+                    return Err(BotError::InvalidCommand(format!("{} Only the admin can import a schedule", profile.team_emoji)));
+                }
+                let games = match crate::schedule_import::fetch_schedule(&url, &self.config).await {
+                    Ok(games) => games,
+                    Err(e) => {
+                        warn!("Schedule import failed: {}", e);
+                        return Ok(format!("❌ Couldn't read that schedule: {}", e));
+                    }
+                };
+
+                // Skip games the sheet already has so re-running an import doesn't duplicate them
+                let existing: Vec<CorrelatedEvent> = self.correlate_data().await?.into_values().flatten().collect();
+                let new_games: Vec<_> = games.iter().filter(|g| !g.is_on_sheet(&existing, &self.config.team_name)).collect();
+                if new_games.is_empty() {
+                    return Ok(format!("📥 Found {} games, but they're all on the sheet already.", games.len()));
+                }
+
+                if !confirmed {
+                    let mut preview = format!("📥 Found {} new games ({} already on the sheet):\n", new_games.len(), games.len() - new_games.len());
+                    for game in new_games.iter().take(10) {
+                        preview.push_str(&format!("• {}\n", game.describe()));
+                    }
+                    if new_games.len() > 10 {
+                        preview.push_str(&format!("…and {} more\n", new_games.len() - 10));
+                    }
+                    preview.push_str(&format!("Reply \"confirm\" within {} seconds to add them, or \"cancel\".", CONFIRMATION_WINDOW_SECONDS));
+                    return Err(BotError::ConfirmationRequired { prompt: preview, command: Box::new(BotCommand::ImportSchedule(url)) });
+                }
+
+                let rows: Vec<Vec<String>> = new_games.iter().map(|g| g.to_row()).collect();
+                match self.google_client.append_rows(&rows).await {
+                    Ok(_) => {
+                        self.correlate_data().await?;
+                        Ok(format!("📥 Added {} games to the schedule!", rows.len()))
+                    }
+                    Err(e) => {
+                        warn!("Failed to update Google Sheet: {}", e);
+                        Ok("❌ Import failed. Code: IMP001".to_string())
+                    }
+                }
+            }
+            BotCommand::RenameBot(name) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_admin(user, &self.config.admin_user_id) {
+                    return Err(BotError::InvalidCommand(format!("{} Only the admin can rename the bot", profile.team_emoji)));
+                }
+                match self.update_bot_profile(Some(&name), None).await {
+                    Ok(_) => {
+                        // Also recompiles the mention pattern the parsers match against
+                        if let Ok(mut bot_name) = self.bot_name.write() {
+                            bot_name.rename(name.clone());
+                        }
+                        crate::mentions::save_bot_name(&name);
+                        Ok(format!("🏷️ I'm now {}! Mention me as @{}", name, name))
+                    }
+                    Err(e) => {
+                        warn!("Failed to rename bot: {}", e);
+                        Ok("❌ Rename failed. Code: BOT001".to_string())
+                    }
+                }
+            }
+            BotCommand::SetBotAvatar(url) => {
+                let user = user_id.ok_or(BotError::InvalidCommand("User ID required".to_string()))?;
+                if !moderators_store.is_admin(user, &self.config.admin_user_id) {
+                    return Err(BotError::InvalidCommand(format!("{} Only the admin can change the bot's avatar", profile.team_emoji)));
+                }
+                match self.update_bot_profile(None, Some(&url)).await {
+                    Ok(_) => Ok("🖼️ New avatar set!".to_string()),
+                    Err(e) => {
+                        warn!("Failed to set bot avatar: {}", e);
+                        Ok("❌ Avatar update failed. Code: BOT002".to_string())
+                    }
+                }
+            }
+            BotCommand::HeadToHead(opponent) => {
+                let season = self.config.today().year();
+                let games: Vec<GameResult> = self.results.against(&opponent).await
+                    .into_iter()
+                    .filter(|r| r.date.year() == season)
+                    .collect();
+                if games.is_empty() {
+                    return Ok(format!("📊 No recorded games against {} this season.", opponent));
+                }
+
+                let count = |outcome: &str| games.iter().filter(|r| r.outcome() == outcome).count();
+                let mut response = format!("📊 {} vs {} this season: {}-{}", profile.team_name, opponent, count("won"), count("lost"));
+                if count("tied") > 0 {
+                    response.push_str(&format!("-{}", count("tied")));
+                }
+                response.push('\n');
+                for game in &games {
+                    let letter = match game.outcome() { "won" => "W", "lost" => "L", _ => "T" };
+                    response.push_str(&format!("• {}: {} {}-{}\n", game.date.format("%-m/%-d"), letter, game.our_score, game.their_score));
+                }
+                Ok(response)
+            }
+        }
+    }
+
+    /// Upcoming (today or later) volunteer spots held by exactly this person (full name, any case)
+    pub async fn upcoming_assignments_for(&self, person: &str) -> Result<Vec<(NaiveDate, &'static str)>> {
+        let today = self.config.today();
+        let mut events: Vec<CorrelatedEvent> = self.correlate_data().await?
+            .into_values()
+            .flatten()
+            .filter(|event| event.event_date >= today)
+            .collect();
+        events.sort_by_key(|event| event.event_date);
+
+        Ok(events
+            .iter()
+            .flat_map(|event| {
+                event.data.volunteer_assignments()
+                    .into_iter()
+                    .filter(|(_, name)| same_person(name, person))
+                    .map(|(role, _)| (event.event_date, role))
+                    .collect::<Vec<_>>()
+            })
+            .collect())
+    }
+
+    /// Upcoming spots, soonest first, whose holder passes `filter(name, game date)`, as (date, role, name).
+    /// Placeholders like "TBD" or "n/a" aren't people and never match.
+    async fn upcoming_assignments_matching(&self, filter: impl Fn(&str, NaiveDate) -> bool) -> Result<Vec<(NaiveDate, &'static str, String)>> {
+        let today = self.config.today();
+        let mut events: Vec<CorrelatedEvent> = self.correlate_data().await?
+            .into_values()
+            .flatten()
+            .filter(|event| event.event_date >= today)
+            .collect();
+        events.sort_by_key(|event| event.event_date);
+
+        let mut matching = Vec::new();
+        for event in &events {
+            for (role, name) in event.data.volunteer_assignments() {
+                if !is_placeholder_name(name) && filter(name, event.event_date) {
+                    matching.push((event.event_date, role, name.to_string()));
+                }
+            }
+        }
+        Ok(matching)
+    }
+
+    /// Upcoming spots held by names that don't match anyone in the group, as (date, role, name).
+    /// Empty when the member list isn't available, since then everyone would look orphaned.
+    pub async fn orphaned_assignments(&self) -> Result<Vec<(NaiveDate, &'static str, String)>> {
+        let members = self.members.nicknames().await;
+        if members.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.upcoming_assignments_matching(|name, _| !members.iter().any(|member| names_match(member, name))).await
+    }
+
+    /// Upcoming spots held by someone who has said they'll be away that day, as (date, role, name)
+    pub async fn away_assignments(&self) -> Result<Vec<(NaiveDate, &'static str, String)>> {
+        let absences = self.absences.list(self.config.today()).await;
+        if absences.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.upcoming_assignments_matching(|name, date| absences.iter().any(|a| a.covers(name, date))).await
+    }
+
+    /// Weekly summary of things moderators should look at
+    pub async fn moderator_digest(&self) -> Result<String> {
+        let mut sections = Vec::new();
+
+        let orphaned = self.orphaned_assignments().await?;
+        if !orphaned.is_empty() {
+            let mut section = "⚠️ Signed up but not in the group (reassign these):".to_string();
+            for (date, role, name) in &orphaned {
+                section.push_str(&format!("\n• {} on {}: {}", role, date.format("%-m/%-d"), name));
+            }
+            sections.push(section);
+        }
+
+        let away = self.away_assignments().await?;
+        if !away.is_empty() {
+            let mut section = "🏖️ Signed up but away that day (find a swap):".to_string();
+            for (date, role, name) in &away {
+                section.push_str(&format!("\n• {} on {}: {}", role, date.format("%-m/%-d"), name));
+            }
+            sections.push(section);
+        }
+
+        // Travel tournament this week: what families will ask about, and what's still missing
+        if let Some(tournament) = self.tournament.current().await {
+            if tournament.is_within_week_of(self.config.today()) {
+                let mut section = tournament.info().trim_end().to_string();
+                let missing = tournament.missing_logistics();
+                if !missing.is_empty() {
+                    section.push_str(&format!("\nStill needed: {}", missing.join(", ")));
+                }
+                sections.push(section);
+            }
+        }
+
+        // A calendar that can't be fetched shouldn't hold up the rest of the digest
+        match self.schedule_conflicts().await {
+            Ok(conflicts) if !conflicts.is_empty() => {
+                let mut section = "📅 Sheet and league calendar disagree:".to_string();
+                for conflict in &conflicts {
+                    section.push_str(&format!("\n• {}", conflict.describe()));
+                }
+                sections.push(section);
+            }
+            Ok(_) => {}
+            Err(e) => warn!("Failed to compare schedule sources: {}", e),
+        }
+
+        if sections.is_empty() {
+            sections.push("✅ Nothing needs attention this week.".to_string());
+        }
+        let mut digest = format!("🗒️ {} moderator digest\n\n{}", self.config.team_name, sections.join("\n\n"));
+        if let Some(image_url) = self.volunteer_grid_image().await {
+            digest.push_str(&format!("\n\n📋 Upcoming volunteers:\n{}", image_url));
+        }
+        Ok(digest)
+    }
+
+    /// Upload the upcoming games' volunteer grid as an image; None when there's nothing to show
+    /// or the image service isn't reachable, so the digest still goes out as text
+    async fn volunteer_grid_image(&self) -> Option<String> {
+        let today = self.config.today();
+        let mut upcoming: Vec<CorrelatedEvent> = match self.correlate_data().await {
+            Ok(events) => events.into_values().flatten().filter(|event| event.event_date >= today).collect(),
+            Err(e) => {
+                warn!("Failed to load games for the volunteer grid: {}", e);
+                return None;
+            }
+        };
+        if upcoming.is_empty() {
+            return None;
+        }
+        upcoming.sort_by_key(|event| event.event_date);
+        upcoming.truncate(crate::volunteer_grid::GRID_GAMES);
+
+        let png = match crate::volunteer_grid::grid_png(&self.config.team_name, &upcoming) {
+            Ok(png) => png,
+            Err(e) => {
+                warn!("Failed to render the volunteer grid: {}", e);
+                return None;
+            }
+        };
+        match self.groupme_client.upload_image(png).await {
+            Ok(url) => Some(url),
+            Err(e) => {
+                warn!("Failed to upload the volunteer grid: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Boot-time check that the sheet reads cleanly: game count, next game,
+    /// open volunteer spots this week and any rows that had to be skipped
+    pub async fn startup_report(&self) -> Result<String> {
+        let today = self.config.today();
+        let week_out = today + Duration::days(7);
+        let mut upcoming: Vec<CorrelatedEvent> = self.correlate_data().await?
+            .into_values()
+            .flatten()
+            .filter(|event| event.event_date >= today)
+            .collect();
+        upcoming.sort_by_key(|event| event.event_date);
+        let warnings = self.google_client.parse_warnings();
+
+        let open_spots = upcoming
+            .iter()
+            .filter(|event| event.event_date <= week_out)
+            .map(|event| {
+                crate::signup::SIGNUP_ROLES
+                    .iter()
+                    .filter(|(role, _)| event.data.is_role_available(role, &self.config.team_name))
+                    .count()
+            })
+            .sum::<usize>();
+
+        let mut report = format!("✅ {} bot is up\n📅 {} upcoming game(s)", self.config.team_name, upcoming.len());
+        if let Some(next) = upcoming.first() {
+            report.push_str(&format!("\n⏭️ Next: {} {} ({})", self.game_day(next.event_date), next.data.time, next.format_matchup()));
+        }
+        report.push_str(&format!("\n🙋 {} open volunteer spot(s) in the next 7 days", open_spots));
+        if warnings.is_empty() {
+            report.push_str("\n👍 No sheet parse warnings");
+        } else {
+            report.push_str(&format!("\n⚠️ {} sheet row(s) skipped:", warnings.len()));
+            for warning in warnings.iter().take(5) {
+                report.push_str(&format!("\n• {}", warning));
+            }
+            if warnings.len() > 5 {
+                report.push_str(&format!("\n• ...and {} more", warnings.len() - 5));
+            }
+        }
+        Ok(report)
+    }
+
+    /// Load the schedule, geocode every upcoming venue and fetch tomorrow's forecasts right after
+    /// boot, so the first command after a deploy doesn't pay for all of it
+    pub async fn warm_caches(&self) -> Result<String> {
+        let started = std::time::Instant::now();
+        let today = self.config.today();
+        let tomorrow = today + Duration::days(1);
+        let upcoming: Vec<CorrelatedEvent> = self.correlate_data().await?
+            .into_values()
+            .flatten()
+            .filter(|event| event.event_date >= today)
+            .collect();
+
+        let mut venues: Vec<String> = upcoming
+            .iter()
+            .map(|event| event.data.location.trim().to_string())
+            .filter(|location| !location.is_empty() && !location.eq_ignore_ascii_case("TBD"))
+            .collect();
+        venues.sort_by_key(|location| location.to_lowercase());
+        venues.dedup_by_key(|location| location.to_lowercase());
+
+        let mut geocoded = 0;
+        let mut forecasts = 0;
+        if self.feature_enabled(Feature::Weather, self.config.groupme_group_id.as_deref()).await {
+            for venue in &venues {
+                if self.weather_client.warm_location(venue).await {
+                    geocoded += 1;
+                }
+            }
+            for event in upcoming.iter().filter(|event| event.event_date == tomorrow) {
+                if self.weather_client.warm_forecast(&event.data.location, tomorrow).await {
+                    forecasts += 1;
+                }
+            }
+        }
+        Ok(format!(
+            "{} upcoming game(s), {} of {} venue(s) geocoded, {} forecast(s) for tomorrow, in {:.1}s",
+            upcoming.len(),
+            geocoded,
+            venues.len(),
+            forecasts,
+            started.elapsed().as_secs_f64()
+        ))
+    }
+
+    pub async fn send_direct_message(&self, recipient_id: &str, text: &str) -> Result<()> {
+        self.groupme_client.send_direct_message(recipient_id, text).await
+    }
+
+    /// DMs come from the access token's user, and GroupMe won't deliver one to yourself
+    fn is_token_owner(&self, user_id: &str) -> bool {
+        self.token_owner_id.read().is_ok_and(|owner| owner.as_deref() == Some(user_id))
+    }
+
+    /// Tell the admin about something that needs attention: by DM, or in the group when the
+    /// access token is the admin's own and the DM couldn't be delivered
+    pub async fn alert_admin(&self, text: &str) -> Result<()> {
+        if self.is_token_owner(&self.config.admin_user_id) {
+            return self.send_response(text).await;
+        }
+        self.send_direct_message(&self.config.admin_user_id, text).await
+    }
+
+    /// Look up who owns GROUPME_ACCESS_TOKEN, once at startup, so DMs to them fall back to the group
+    pub async fn detect_token_owner(&self) {
+        if self.config.groupme_access_token.is_none() {
+            return;
+        }
+        match self.groupme_client.token_owner_id().await {
+            Ok(owner) => {
+                if owner == self.config.admin_user_id {
+                    warn!("GROUPME_ACCESS_TOKEN belongs to ADMIN_USER_ID, and GroupMe doesn't deliver DMs to yourself: admin alerts will be posted in the group, and private output (digest, DM replies) won't reach the admin");
+                }
+                if let Ok(mut token_owner_id) = self.token_owner_id.write() {
+                    *token_owner_id = Some(owner);
+                }
+            }
+            Err(e) => warn!("Couldn't look up the GROUPME_ACCESS_TOKEN owner: {}", e),
+        }
+    }
+
+    /// Sheet games plus tournament games that are certain to be played, for reminders.
+    /// A bracket game that's also on the sheet (same date and start time) isn't added twice.
+    pub async fn schedule_with_bracket(&self) -> Result<HashMap<NaiveDate, Vec<CorrelatedEvent>>> {
+        let mut events = self.correlate_data().await?;
+        if let Some(tournament) = self.tournament.current().await {
+            for game in tournament.confirmed_games() {
+                let day = events.entry(game.date).or_default();
+                let start = parse_start_time(&game.time);
+                let on_sheet = start.is_some()
+                    && day.iter().any(|e| parse_start_time(&e.data.time) == start);
+                if !on_sheet {
+                    day.push(game.to_event());
+                }
+            }
+        }
+        Ok(events)
+    }
+
+    /// The next game from the sheet or the tournament bracket, whichever starts first
+    async fn find_next_game(&self) -> Result<Option<CorrelatedEvent>> {
+        let sheet_next = self.find_next_event().await?;
+        let Some(tournament) = self.tournament.current().await else {
+            return Ok(sheet_next);
+        };
+
+        let now = Utc::now();
+        let today = self.config.today();
+        let mut candidates: Vec<CorrelatedEvent> = sheet_next.into_iter().collect();
+        for game in tournament.confirmed_games() {
+            let event = game.to_event();
+            let upcoming = match self.event_start(&event).await {
+                Some(start) => start > now,
+                None => game.date >= today,
+            };
+            if upcoming {
+                candidates.push(event);
+            }
+        }
+
+        let mut next = None;
+        let mut next_key = None;
+        for event in candidates {
+            let key = (event.event_date, self.event_start(&event).await);
+            if next_key.as_ref().is_none_or(|current| key < *current) {
+                next_key = Some(key);
+                next = Some(event);
+            }
+        }
+        Ok(next)
+    }
+
+    /// "❔ Depends on earlier results:" lines for undecided bracket games, or empty
+    async fn projected_bracket_games(&self) -> String {
+        let Some(tournament) = self.tournament.current().await else {
+            return String::new();
+        };
+        let projected = tournament.projected_games();
+        if projected.is_empty() {
+            return String::new();
+        }
+        let mut section = "\n❔ Depends on earlier results:\n".to_string();
+        for game in projected {
+            section.push_str(&format!("• {}\n", game.describe()));
+        }
+        section
+    }
+
+    /// What a bracket result means for the rest of the tournament
+    async fn bracket_update(&self, number: u32) -> String {
+        let Some(tournament) = self.tournament.current().await else {
+            return String::new();
+        };
+        let outcome = tournament.game(number).and_then(|g| g.outcome);
+        let mut response = match outcome {
+            Some(Outcome::Won) => format!("🏆 Bracket updated: we won game {}.", number),
+            _ => format!("🏆 Bracket updated: we lost game {}.", number),
+        };
+        let mut next: Vec<_> = tournament.confirmed_games();
+        next.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| {
+            parse_start_time(&a.time).cmp(&parse_start_time(&b.time))
+        }));
+        match next.first() {
+            Some(game) => response.push_str(&format!("\n⏭️ Next up: {}", game.describe())),
+            None if tournament.projected_games().is_empty() => response.push_str("\nThat's the end of the bracket for us."),
+            None => {}
+        }
+        response
+    }
+
+    /// Look a game up by its `calendar_feed::event_id`
+    async fn event_by_id(&self, id: &str) -> Result<Option<CorrelatedEvent>> {
+        let Some((date, idx)) = crate::calendar_feed::parse_event_id(id) else {
+            return Ok(None);
+        };
+        let schedule = self.schedule_with_bracket().await?;
+        Ok(schedule.get(&date).and_then(|events| events.get(idx)).cloned())
+    }
+
+    /// `calendar_feed::event_id` for a game: its position within the day, as `event_by_id` counts it
+    async fn id_for_event(&self, event: &CorrelatedEvent) -> Result<String> {
+        let schedule = self.schedule_with_bracket().await?;
+        let idx = schedule
+            .get(&event.event_date)
+            .and_then(|events| events.iter().position(|e| e.event_summary == event.event_summary && e.data.time == event.data.time))
+            .unwrap_or(0);
+        Ok(crate::calendar_feed::event_id(event.event_date, idx))
+    }
+
+    /// One game as its own calendar file, by `calendar_feed::event_id`
+    pub async fn event_ics(&self, id: &str) -> Result<Option<String>> {
+        let Some(event) = self.event_by_id(id).await? else {
+            return Ok(None);
+        };
+        let start = self.event_start(&event).await;
+        let vevent = crate::calendar_feed::render_event(&self.config.team_name, id, &event, start);
+        Ok(Some(crate::calendar_feed::render_calendar(&self.config.team_name, &[vevent])))
+    }
+
+    /// Shareable game card page, by `calendar_feed::event_id`
+    pub async fn game_card(&self, id: &str) -> Result<Option<String>> {
+        let Some(event) = self.event_by_id(id).await? else {
+            return Ok(None);
+        };
+        let calendar_url = crate::calendar_feed::event_url(&self.config, id);
+        Ok(Some(crate::game_card::render_game_card(&self.config.team_name, &event, calendar_url.as_deref())))
+    }
+
+    /// Every game on the sheet plus confirmed tournament games, as an iCal feed
+    pub async fn calendar_feed(&self) -> Result<String> {
+        let schedule = self.schedule_with_bracket().await?;
+        let mut dates: Vec<&NaiveDate> = schedule.keys().collect();
+        dates.sort();
+
+        let mut vevents = Vec::new();
+        for date in dates {
+            for (idx, event) in schedule[date].iter().enumerate() {
+                let id = crate::calendar_feed::event_id(*date, idx);
+                let start = self.event_start(event).await;
+                vevents.push(crate::calendar_feed::render_event(&self.config.team_name, &id, event, start));
+            }
+        }
+        Ok(crate::calendar_feed::render_calendar(&self.config.team_name, &vevents))
+    }
+
+    /// Start of a game as an absolute time, honoring the venue's time zone override
+    pub async fn event_start(&self, event: &CorrelatedEvent) -> Option<chrono::DateTime<Utc>> {
+        let timezone = self.venues.timezone_for(&event.data.location).await;
+        event_start(event.event_date, &event.data.time, timezone)
+    }
+
+    /// Volunteer from the public signup page: same assignment path as chat, then announced
+    /// in the group so everyone sees who took the spot
+    pub async fn web_signup(&self, date: NaiveDate, role: &str, name: &str) -> Result<String> {
+        let name = name.trim();
+        if name.is_empty() || name.len() > 60 {
+            return Ok("❌ Please enter your name.".to_string());
+        }
+        if !crate::signup::SIGNUP_ROLES.iter().any(|(r, _)| *r == role) {
+            return Ok("❌ Please pick one of the open spots.".to_string());
+        }
+
+        // Screened here too so the announcement doesn't repeat a name the sheet got masked
+        let Some(name) = self.content_filter.screen(name).await else {
+            return Ok(FILTERED_REPLY.to_string());
+        };
+        let response = self.handle_volunteer_assignment(date, role.to_string(), name.clone(), None, false).await?;
+        if response.starts_with('✅') {
+            let announcement = format!("🙌 {} signed up for {} on {} using the signup page.", name, role, date.format("%-m/%-d"));
+            if let Err(e) = self.send_response(&announcement).await {
+                warn!("Failed to announce web signup: {}", e);
+            }
+        }
+        Ok(response)
+    }
+
+    /// DM the admin and every moderator; failures are logged per recipient. The access token's
+    /// owner can't be DMed: an `alert` is posted in the group for them, anything else is skipped.
+    pub async fn notify_moderators(&self, moderators_store: &crate::moderators::ModeratorsStore, text: &str, alert: bool) {
+        let mut recipients = vec![self.config.admin_user_id.clone()];
+        for moderator in moderators_store.list_moderators().await {
+            if !recipients.contains(&moderator) {
+                recipients.push(moderator);
+            }
+        }
+        for recipient in recipients {
+            let sent = if !self.is_token_owner(&recipient) {
+                self.send_direct_message(&recipient, text).await
+            } else if alert {
+                self.send_response(text).await
+            } else {
+                continue;
+            };
+            if let Err(e) = sent {
+                warn!("Failed to notify {}: {}", recipient, e);
+            }
+        }
+    }
+
+    /// Upcoming games where the sheet and CALENDAR_URL disagree on time or location.
+    /// Empty when no calendar is configured.
+    pub async fn schedule_conflicts(&self) -> Result<Vec<ScheduleConflict>> {
+        let Some(url) = &self.config.calendar_url else {
+            return Ok(Vec::new());
+        };
+        let today = self.config.today();
+
+        let calendar: Vec<CorrelatedEvent> = crate::schedule_import::fetch_schedule(url, &self.config).await?
+            .iter()
+            .filter(|game| game.date >= today)
+            .map(|game| game.to_event())
+            .collect();
+        let mut sheet: Vec<CorrelatedEvent> = self.correlate_data().await?
+            .into_values()
+            .flatten()
+            .filter(|event| event.event_date >= today)
+            .collect();
+        sheet.sort_by_key(|event| event.event_date);
+
+        Ok(find_conflicts(&sheet, &calendar, &self.config.team_name))
+    }
+
+    /// Change this bot's GroupMe registration; needs the access token of the bot's owner
+    async fn update_bot_profile(&self, name: Option<&str>, avatar_url: Option<&str>) -> Result<()> {
+        let mut bot = self.groupme_client.list_bots().await?
+            .into_iter()
+            .find(|bot| bot.bot_id.as_deref() == Some(self.config.groupme_bot_id.as_str()))
+            .ok_or_else(|| BotError::GroupMeApi("Bot is not owned by GROUPME_ACCESS_TOKEN".to_string()))?;
+        if let Some(name) = name {
+            bot.name = name.to_string();
+        }
+        if let Some(url) = avatar_url {
+            bot.avatar_url = Some(url.to_string());
+        }
+        self.groupme_client.update_bot(&bot).await
+    }
+
+    /// "Chaos are 6-2; we lost 4-5 on 4/12" from the standings sheet and recorded results
+    async fn opponent_preview(&self, event: &CorrelatedEvent) -> Option<String> {
+        let opponent = event.opponent(&self.config.team_name)?;
+        let standing = self.opponent_standing(&opponent).await;
+        let last_meeting = self.results.last_against(&opponent).await;
+
+        match (standing, last_meeting) {
+            (Some(standing), Some(last)) => Some(format!("{} are {}; {}", opponent, standing.record(), last.summary())),
+            (Some(standing), None) => Some(format!("{} are {}", opponent, standing.record())),
+            (None, Some(last)) => Some(format!("Last time vs {}: {}", opponent, last.summary())),
+            (None, None) => None,
+        }
+    }
+
+    /// Look up a team in the standings range, if one is configured. Header rows are skipped.
+    async fn opponent_standing(&self, opponent: &str) -> Option<TeamStanding> {
+        let range = self.config.standings_range.as_ref()?;
+        match self.google_client.get_range_values(range).await {
+            Ok(rows) => rows
+                .iter()
+                .filter_map(|row| TeamStanding::from_row(row))
+                .find(|standing| teams_match(&standing.team, opponent)),
+            Err(e) => {
+                warn!("Failed to fetch standings: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Reply for bring-list messages that didn't say which event they're about
+    async fn which_bring_list_message(&self) -> String {
+        let events = self.bring_lists.event_names().await;
+        if events.is_empty() {
+            "🎉 There's no party bring list yet. A moderator can start one with \"party needs: plates, cups\".".to_string()
+        } else {
+            format!("🎉 Which event? Open lists: {}", events.join(", "))
+        }
+    }
+
+    /// Use the given date, or fall back to the next game's date
+    async fn resolve_target_date(&self, date: Option<NaiveDate>) -> Result<Option<NaiveDate>> {
+        match date {
+            Some(d) => Ok(Some(d)),
+            None => Ok(self.find_next_event().await?.map(|event| event.event_date)),
+        }
+    }
+
+    /// Filled volunteer spots for the game on `date` as (role, name), or None when there's no game that day
+    async fn volunteers_on(&self, date: NaiveDate) -> Result<Option<Vec<(&'static str, String)>>> {
+        Ok(self.find_event_by_date(date).await?.first().map(|event| {
+            event.data.volunteer_assignments()
+                .into_iter()
+                .map(|(role, name)| (role, name.to_string()))
+                .collect()
+        }))
+    }
+
+    /// One-line summary of what a destructive command will do, for the confirmation prompt
+    async fn describe_pending_action(&self, command: &BotCommand) -> String {
+        match command {
+            BotCommand::ClearVolunteers(date) => {
+                let spots = self.volunteers_on(*date).await.ok().flatten().unwrap_or_default();
+                let listed: Vec<String> = spots.iter().map(|(role, name)| format!("{} ({})", role, name)).collect();
+                if listed.is_empty() {
+                    format!("This will clear every volunteer spot on {}", date.format("%-m/%-d"))
+                } else {
+                    format!("This will clear {} volunteer spot(s) on {}: {}", listed.len(), date.format("%-m/%-d"), listed.join(", "))
+                }
+            }
+            BotCommand::CopyVolunteers(from, to) => {
+                let source = self.volunteers_on(*from).await.ok().flatten().unwrap_or_default();
+                let target = self.volunteers_on(*to).await.ok().flatten().unwrap_or_default();
+                let listed: Vec<String> = source
+                    .iter()
+                    .map(|(role, name)| match target.iter().find(|(r, _)| r == role) {
+                        Some((_, current)) if current != name => format!("{} → {} (replacing {})", role, name, current),
+                        _ => format!("{} → {}", role, name),
+                    })
+                    .collect();
+                if listed.is_empty() {
+                    format!("This will copy {}'s volunteers to {}", from.format("%-m/%-d"), to.format("%-m/%-d"))
+                } else {
+                    format!("This will copy {}'s volunteers to {}: {}", from.format("%-m/%-d"), to.format("%-m/%-d"), listed.join(", "))
+                }
+            }
+            BotCommand::RemoveVolunteer(_, role, date) => {
+                let when = date.map(|d| d.to_string()).unwrap_or_else(|| "the next game".to_string());
+                format!("This will clear the {} volunteer for {}", role, when)
+            }
+            BotCommand::ClearBringList(event) => format!("This will delete the whole {} bring list", event),
+            BotCommand::ClearAssignments(person) => {
+                let spots: Vec<String> = self.upcoming_assignments_for(person).await.unwrap_or_default()
+                    .iter()
+                    .map(|(date, role)| format!("{} on {}", role, date.format("%-m/%-d")))
+                    .collect();
+                if spots.is_empty() {
+                    format!("This will clear every upcoming volunteer spot held by {}", person)
+                } else {
+                    format!("This will clear {} spot(s) held by {}: {}", spots.len(), person, spots.join(", "))
+                }
+            }
+            BotCommand::ClearTournament => "This will delete the tournament bracket and all its results".to_string(),
+            BotCommand::CancelGame(date, _) => {
+                let when = date.map(|d| d.format("%-m/%-d").to_string()).unwrap_or_else(|| "the next game".to_string());
+                format!("This will mark {} as cancelled and notify webhook subscribers", when)
+            }
+            other => format!("This will run {:?}", other),
+        }
+    }
+
+    pub async fn send_response(&self, message: &str) -> Result<()> {
+        self.groupme_client.send_message(message).await
+    }
+
+    pub async fn send_response_as(&self, bot_id: &str, message: &str) -> Result<()> {
+        self.groupme_client.send_message_as(bot_id, message).await
+    }
+
+    /// Post to `group_id` through the bot whose callback route serves it, or the primary bot
+    async fn send_to_group(&self, group_id: Option<&str>, message: &str) -> Result<()> {
+        match group_id.and_then(|group| self.route_bots.get(group)) {
+            Some(bot_id) => self.send_response_as(bot_id, message).await,
+            None => self.send_response(message).await,
+        }
     }
     
     async fn handle_volunteer_removal(&self, date: NaiveDate, role: String, _person: String) -> Result<String> {
         let events = self.find_event_by_date(date).await?;
         
         if events.is_empty() {
-            return Ok(format!("❌ No event found for {}.", date));
+            return Ok(format!("❌ No event found for {}.", self.game_day(date)));
         }
         
         for (_i, mut event) in events.into_iter().enumerate() {
@@ -458,7 +2458,7 @@ impl BotService {
                     // Manually update local event copy just for message formatting (optional, since we reloaded cache)
                     // But we want to show the user what happened.
                     
-                    return Ok(format!("✅ Cleared {} volunteer for {} ({})", role, date, event.format_matchup()));
+                    return Ok(format!("✅ Cleared {} volunteer for {} ({})", role, self.game_day(date), event.format_matchup()));
                 }
                 Err(e) => {
                     warn!("Failed to update Google Sheet: {}", e);
@@ -470,11 +2470,59 @@ impl BotService {
         Ok(format!("❌ Could not find event or role to remove for {}.", date))
     }
 
-    async fn handle_volunteer_assignment(&self, date: NaiveDate, role: String, person: String, sender_name: Option<&str>) -> Result<String> {
+    /// When name validation is on, ask for confirmation before writing a name that doesn't match
+    /// any group member or anyone already on the sheet (catches junk like "Saturday" in the snacks column)
+    async fn check_volunteer_name(&self, person: &str, sender_name: Option<&str>, command: BotCommand) -> Result<()> {
+        if !self.config.validate_volunteer_names || sender_name.is_some_and(|s| s.eq_ignore_ascii_case(person)) {
+            return Ok(());
+        }
+
+        let mut known_names = self.members.nicknames().await;
+        if let Ok(events) = self.get_cached_or_fresh_data().await {
+            for event in events.values().flatten() {
+                known_names.extend(event.data.volunteer_assignments().into_iter().map(|(_, p)| p.to_string()));
+            }
+        }
+
+        // Nothing to validate against (no member access, empty sheet) - don't nag
+        if known_names.is_empty() || known_names.iter().any(|known| names_match(person, known)) {
+            return Ok(());
+        }
+
+        Err(BotError::ConfirmationRequired {
+            prompt: format!("🤔 I don't recognize \"{}\" as anyone in the group. Reply 'confirm' within {}s to sign them up anyway, or 'cancel'.", person, CONFIRMATION_WINDOW_SECONDS),
+            command: Box::new(command),
+        })
+    }
+
+    /// Whether this user may assign volunteers to games that already happened
+    async fn can_backfill(&self, user_id: Option<&str>, moderators_store: &crate::moderators::ModeratorsStore) -> bool {
+        match user_id {
+            Some(user) if self.config.allow_moderator_backfill => moderators_store.is_authorized(user, &self.config.admin_user_id).await,
+            _ => false,
+        }
+    }
+
+    /// The first date on or after `today` that falls on the same weekday as `date`
+    fn next_matching_weekday(date: NaiveDate, today: NaiveDate) -> NaiveDate {
+        let days_ahead = (date.weekday().num_days_from_monday() as i64 - today.weekday().num_days_from_monday() as i64).rem_euclid(7);
+        today + Duration::days(days_ahead)
+    }
+
+    async fn handle_volunteer_assignment(&self, date: NaiveDate, role: String, person: String, sender_name: Option<&str>, allow_past: bool) -> Result<String> {
+        let Some(person) = self.content_filter.screen(&person).await else {
+            return Ok(FILTERED_REPLY.to_string());
+        };
+        let today = self.config.today();
+        if date < today && !allow_past {
+            let suggestion = Self::next_matching_weekday(date, today);
+            return Ok(format!("❌ {} has already passed, so I can't sign anyone up for it. Did you mean {}?", self.game_day(date), self.game_day(suggestion)));
+        }
+
         let events = self.find_event_by_date(date).await?;
         
         if events.is_empty() {
-            return Ok(format!("❌ No event found for {}.", date));
+            return Ok(format!("❌ No event found for {}.", self.game_day(date)));
         }
         
         // Find the first event that has this role available
@@ -508,19 +2556,31 @@ impl BotService {
                     Ok(_) => {
                         // Update cache (reload all data to be safe)
                         self.correlate_data().await?;
+                        self.emit_webhook(WebhookEvent::VolunteerAssigned {
+                            date,
+                            matchup: event.format_matchup(),
+                            role: role.clone(),
+                            person: person.clone(),
+                        });
                         
                         let message = if let Some(sender) = sender_name {
                             let sender_lower = sender.to_lowercase();
                             let person_lower = person.to_lowercase();
                             if sender_lower == person_lower || sender_lower.contains(&person_lower) || person_lower.contains(&sender_lower) {
-                                format!("@{} ✅ You've been assigned to {} for {} ({})!", sender, role, date, event.format_matchup())
+                                format!("@{} ✅ You've been assigned to {} for {} ({})!", sender, role, self.game_day(date), event.format_matchup())
                             } else {
-                                format!("✅ {} has been assigned to {} for {} ({})!", person, role, date, event.format_matchup())
+                                format!("✅ {} has been assigned to {} for {} ({})!", person, role, self.game_day(date), event.format_matchup())
                             }
                         } else {
-                            format!("✅ {} has been assigned to {} for {} ({})!", person, role, date, event.format_matchup())
+                            format!("✅ {} has been assigned to {} for {} ({})!", person, role, self.game_day(date), event.format_matchup())
                         };
-                        return Ok(message);
+                        return Ok(match self.absences.away_on(&person, date).await {
+                            Some(absence) => format!(
+                                "{}\n⚠️ Heads up: {} marked out {}. Still good for this one?",
+                                message, absence.who, absence.describe_dates()
+                            ),
+                            None => message,
+                        });
                     }
                     Err(e) => {
                         warn!("Failed to update Google Sheet: {}", e);
@@ -534,17 +2594,17 @@ impl BotService {
         Ok(format!("❌ Role {} is already filled or not available for games on {}.", role, date))
     }
     
-    async fn handle_show_volunteers(&self, maybe_date: Option<NaiveDate>) -> Result<String> {
+    async fn handle_show_volunteers(&self, maybe_date: Option<NaiveDate>, profile: &GroupProfile) -> Result<String> {
         match maybe_date {
             Some(date) => {
                 let events = self.find_event_by_date(date).await?;
                 if events.is_empty() {
-                    Ok(format!("❌ No event found for {}.", date))
+                    Ok(format!("❌ No event found for {}.", self.game_day(date)))
                 } else {
-                    let mut response = format!("{} Volunteer status for {}:\n\n", self.config.team_emoji, date);
+                    let mut response = format!("{} Volunteer status for {}:\n\n", profile.team_emoji, self.game_day(date));
                     for event in events {
                         response.push_str(&format!("--- {} ---\n", event.format_matchup()));
-                        response.push_str(&event.data.format_all());
+                        response.push_str(&event.data.format_all(self.config.today()));
                         response.push_str(&format!("\n{}\n\n", event.data.format_volunteer_needs(&self.config.team_name)));
                     }
                     Ok(response)
@@ -553,7 +2613,7 @@ impl BotService {
             None => {
                 // Show volunteer status for all upcoming events
                 let events_map = self.correlate_data().await?;
-                let today = Utc::now().date_naive();
+                let today = self.config.today();
                 
                 let mut upcoming_events: Vec<CorrelatedEvent> = events_map.values().flatten().cloned().collect();
                 upcoming_events.sort_by_key(|e| e.event_date);
@@ -565,7 +2625,7 @@ impl BotService {
                 if upcoming_events.is_empty() {
                     Ok("❌ No upcoming events found.".to_string())
                 } else {
-                    let mut response = format!("{} Volunteer status for upcoming events:\n\n", self.config.team_emoji);
+                    let mut response = format!("{} Volunteer status for upcoming events:\n\n", profile.team_emoji);
                     
                     for event in upcoming_events.iter().take(5) {
                         response.push_str(&format!("{} ({}):\n", event.event_date, event.format_matchup()));
@@ -583,10 +2643,10 @@ impl BotService {
         }
     }
     
-    async fn handle_list_bot_messages(&self, count: usize) -> Result<String> {
+    async fn handle_list_bot_messages(&self, count: usize, profile: &GroupProfile) -> Result<String> {
         // Check if message management is configured
         if self.config.groupme_access_token.is_none() || self.config.groupme_group_id.is_none() {
-            return Ok(format!("{} Message management is not configured. Set GROUPME_ACCESS_TOKEN and GROUPME_GROUP_ID in .env", self.config.team_emoji));
+            return Ok(format!("{} Message management is not configured. Set GROUPME_ACCESS_TOKEN and GROUPME_GROUP_ID in .env", profile.team_emoji));
         }
         
         let messages = self.groupme_client.list_messages(100, None).await?;
@@ -596,10 +2656,10 @@ impl BotService {
             .collect();
         
         if bot_messages.is_empty() {
-            return Ok(format!("{} No recent bot messages found.", self.config.team_emoji));
+            return Ok(format!("{} No recent bot messages found.", profile.team_emoji));
         }
         
-        let mut response = format!("{} Recent bot messages (last {}):\n\n", self.config.team_emoji, bot_messages.len());
+        let mut response = format!("{} Recent bot messages (last {}):\n\n", profile.team_emoji, bot_messages.len());
         for (i, msg) in bot_messages.iter().enumerate() {
             let preview = if msg.text.len() > 50 {
                 format!("{}...", &msg.text[..50])
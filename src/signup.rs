@@ -0,0 +1,139 @@
+use chrono::NaiveDate;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::config::Config;
+use crate::models::CorrelatedEvent;
+
+/// Roles the signup page offers, as (sheet role, label)
+pub const SIGNUP_ROLES: [(&str, &str); 5] = [
+    ("snacks", "Snacks"),
+    ("livestream", "Livestream"),
+    ("scoreboard", "Scoreboard"),
+    ("pitchcount", "Pitch count"),
+    ("gamechanger", "GameChanger"),
+];
+
+/// Bytes of the HMAC kept in a token; enough that signatures can't be guessed, short enough for a link
+const SIGNATURE_BYTES: usize = 16;
+
+fn mac_for(secret: &str, date: NaiveDate) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(date.format("%Y-%m-%d").to_string().as_bytes());
+    mac
+}
+
+/// Signature for one game date, so links can't be guessed for other dates
+fn signature(secret: &str, date: NaiveDate) -> String {
+    mac_for(secret, date)
+        .finalize()
+        .into_bytes()
+        .iter()
+        .take(SIGNATURE_BYTES)
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok()).collect()
+}
+
+/// "2025-05-03-1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d"
+pub fn signup_token(secret: &str, date: NaiveDate) -> String {
+    format!("{}-{}", date.format("%Y-%m-%d"), signature(secret, date))
+}
+
+/// The game date a token was issued for, if the signature checks out
+pub fn verify_signup_token(secret: &str, token: &str) -> Option<NaiveDate> {
+    let (date, sig) = token.rsplit_once('-')?;
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+    // A shorter prefix would verify too, so hold it to the full length
+    let sig = decode_hex(sig).filter(|sig| sig.len() == SIGNATURE_BYTES)?;
+    // Constant-time, so response timing doesn't leak how much of a guess was right
+    mac_for(secret, date).verify_truncated_left(&sig).ok().map(|_| date)
+}
+
+/// Public link for a game's signup page; None without PUBLIC_BASE_URL and SIGNUP_SECRET
+pub fn signup_url(config: &Config, date: NaiveDate) -> Option<String> {
+    let base = config.public_base_url.as_ref()?;
+    let secret = config.signup_secret.as_ref()?;
+    Some(format!("{}/signup/{}", base.trim_end_matches('/'), signup_token(secret, date)))
+}
+
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Signup form for the games on one date; `notice` is the result of the last submission
+pub fn render_signup_page(team_name: &str, date: NaiveDate, events: &[CorrelatedEvent], notice: Option<&str>) -> String {
+    let mut body = format!("<h1>{} volunteers</h1>\n", escape(team_name));
+    if let Some(notice) = notice {
+        body.push_str(&format!("<p class=\"notice\">{}</p>\n", escape(notice)));
+    }
+
+    let open_roles: Vec<(&str, &str)> = SIGNUP_ROLES
+        .iter()
+        .copied()
+        .filter(|(role, _)| events.iter().any(|e| e.data.is_role_available(role, team_name)))
+        .collect();
+
+    for event in events {
+        body.push_str(&format!(
+            "<h2>{}</h2>\n<p>{} {}<br>{}</p>\n",
+            escape(&event.format_matchup()),
+            date.format("%A, %B %-d"),
+            escape(&event.data.time),
+            escape(&event.data.location)
+        ));
+    }
+
+    if events.is_empty() {
+        body.push_str("<p>There's no game on the schedule for this date anymore.</p>\n");
+    } else if open_roles.is_empty() {
+        body.push_str("<p>Every volunteer spot is filled. Thank you!</p>\n");
+    } else {
+        body.push_str("<form method=\"post\">\n<p><label>Your name<br><input name=\"name\" required maxlength=\"60\"></label></p>\n<p>");
+        for (idx, (role, label)) in open_roles.iter().enumerate() {
+            body.push_str(&format!(
+                "<label><input type=\"radio\" name=\"role\" value=\"{}\"{}> {}</label><br>\n",
+                role,
+                if idx == 0 { " checked" } else { "" },
+                label
+            ));
+        }
+        body.push_str("</p>\n<p><button type=\"submit\">Sign me up</button></p>\n</form>\n");
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\
+         <title>{} volunteers</title>\
+         <style>body{{font-family:sans-serif;max-width:32em;margin:1em auto;padding:0 1em;font-size:1.2em}}\
+         .notice{{background:#eef;padding:.5em}}input,button{{font-size:1em}}</style></head>\n<body>\n{}</body></html>\n",
+        escape(team_name),
+        body
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signup_token_round_trip() {
+        let date = NaiveDate::from_ymd_opt(2025, 5, 3).unwrap();
+        let token = signup_token("secret", date);
+        assert!(token.starts_with("2025-05-03-"));
+        assert_eq!(verify_signup_token("secret", &token), Some(date));
+        assert_eq!(verify_signup_token("other", &token), None);
+        assert_eq!(verify_signup_token("secret", &token.replace("2025-05-03", "2025-05-10")), None);
+        // A truncated signature doesn't pass
+        assert_eq!(verify_signup_token("secret", &token[..token.len() - 2]), None);
+        assert_eq!(verify_signup_token("secret", &token[..14]), None);
+    }
+}
@@ -0,0 +1,413 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use chrono_tz::Tz;
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::relative_dates::game_day;
+
+const SPECIAL_EVENTS_FILE: &str = "data/special_events.json";
+
+/// A reminder posted `lead_hours` before an event. The text can use {title}, {when}, {time},
+/// {location} and {details} (time and place on one line).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReminderTemplate {
+    pub lead_hours: u32,
+    pub text: String,
+}
+
+/// A kind of non-game event and the reminders it gets unless a moderator changes them
+pub struct EventType {
+    pub key: &'static str,
+    pub label: &'static str,
+    pub emoji: &'static str,
+    aliases: &'static [&'static str],
+    reminders: &'static [(u32, &'static str)],
+}
+
+pub const EVENT_TYPES: [EventType; 4] = [
+    EventType {
+        key: "picture_day",
+        label: "Picture Day",
+        emoji: "📸",
+        aliases: &["picture day", "photo day", "team photos", "team pictures", "pictures"],
+        reminders: &[
+            (72, "📸 {title} is {when}!\n{details}\nClean uniforms and hats, and fill out order forms ahead of time."),
+            (18, "📸 Reminder: {title} is {when}.\n{details}\nFull uniform, and bring your order form and payment."),
+        ],
+    },
+    EventType {
+        key: "fundraiser",
+        label: "Fundraiser Night",
+        emoji: "🎟️",
+        aliases: &["fundraiser", "fundraising", "spirit night"],
+        reminders: &[
+            (168, "🎟️ Save the date: {title} is {when}.\n{details}\nInvite family and friends, part of every order supports the team!"),
+            (24, "🎟️ {title} is {when}!\n{details}\nMention the team when you order."),
+        ],
+    },
+    EventType {
+        key: "trophy_ceremony",
+        label: "Trophy Ceremony",
+        emoji: "🏆",
+        aliases: &["trophy ceremony", "trophy night", "trophies", "awards", "banquet"],
+        reminders: &[
+            (72, "🏆 {title} is {when}.\n{details}\nLet a coach know if you can't make it so every trophy gets home."),
+            (24, "🏆 {title} is {when}! Bring the whole family.\n{details}"),
+        ],
+    },
+    EventType {
+        key: "event",
+        label: "Team Event",
+        emoji: "📅",
+        aliases: &["event", "team event"],
+        reminders: &[(24, "📅 Reminder: {title} is {when}.\n{details}")],
+    },
+];
+
+impl EventType {
+    /// What moderators call it in commands: "picture day", "fundraiser", ...
+    pub fn name(&self) -> &'static str {
+        self.aliases[0]
+    }
+}
+
+/// The type a moderator or sheet row named: "picture day", "photo day", "fundraiser", ...
+pub fn event_type(name: &str) -> Option<&'static EventType> {
+    let name = name.trim().to_lowercase().replace('_', " ");
+    EVENT_TYPES
+        .iter()
+        .find(|t| t.key.replace('_', " ") == name || t.aliases.contains(&name.as_str()))
+}
+
+/// The type an event title sounds like ("Spring Picture Day" is picture day), else a plain event
+pub fn classify(title: &str) -> &'static EventType {
+    let title = title.to_lowercase();
+    EVENT_TYPES
+        .iter()
+        .find(|t| t.aliases.iter().any(|a| title.contains(a)))
+        .unwrap_or(&EVENT_TYPES[3])
+}
+
+fn type_for_key(key: &str) -> &'static EventType {
+    EVENT_TYPES.iter().find(|t| t.key == key).unwrap_or(&EVENT_TYPES[3])
+}
+
+/// "3 days" / "18 hours"
+pub fn describe_lead(hours: u32) -> String {
+    match hours {
+        24 => "1 day".to_string(),
+        h if h % 24 == 0 => format!("{} days", h / 24),
+        1 => "1 hour".to_string(),
+        h => format!("{} hours", h),
+    }
+}
+
+/// A picture day, fundraiser or other non-game date. Sheet rows have id 0.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecialEvent {
+    pub id: u32,
+    pub kind: String,
+    pub title: String,
+    pub date: NaiveDate,
+    pub time: Option<String>,
+    pub location: Option<String>,
+}
+
+impl SpecialEvent {
+    pub fn event_type(&self) -> &'static EventType {
+        type_for_key(&self.kind)
+    }
+
+    /// Parse a sheet row laid out as Date | Time | Type | Title | Location. The type may be
+    /// left blank when the title says it; header rows don't parse and are skipped.
+    pub fn from_row(row: &[String]) -> Option<Self> {
+        let cell = |idx: usize| row.get(idx).map(|v| v.trim()).filter(|v| !v.is_empty());
+        let date = cell(0).and_then(|d| {
+            ["%Y-%m-%d", "%m/%d/%Y", "%m/%d/%y"].iter().find_map(|f| NaiveDate::parse_from_str(d, f).ok())
+        })?;
+        let named = cell(2).and_then(event_type);
+        let title = match (cell(3), named) {
+            (Some(title), _) => title.to_string(),
+            (None, Some(kind)) => kind.label.to_string(),
+            (None, None) => cell(2)?.to_string(),
+        };
+        let kind = named.unwrap_or_else(|| classify(&title));
+        Some(Self {
+            id: 0,
+            kind: kind.key.to_string(),
+            title,
+            date,
+            time: cell(1).map(str::to_string),
+            location: cell(4).map(str::to_string),
+        })
+    }
+
+    /// "9:00 AM at Hall Field", whichever parts are known
+    pub fn details(&self) -> String {
+        match (&self.time, &self.location) {
+            (Some(time), Some(location)) => format!("{} at {}", time, location),
+            (Some(time), None) => time.clone(),
+            (None, Some(location)) => format!("At {}", location),
+            (None, None) => String::new(),
+        }
+    }
+
+    /// "#2 📸 Picture Day: Saturday 5/10 (in 3 weeks), 9:00 AM at Hall Field"
+    pub fn describe(&self, today: NaiveDate) -> String {
+        let mut line = format!("{} {}: {}", self.event_type().emoji, self.title, game_day(self.date, today));
+        if self.id > 0 {
+            line = format!("#{} {}", self.id, line);
+        }
+        let details = self.details();
+        if !details.is_empty() {
+            line.push_str(&format!(", {}", details));
+        }
+        line
+    }
+
+    /// When it starts; events without a time count from `default_hour` that morning
+    pub fn starts_at(&self, timezone: Option<Tz>, default_hour: u32) -> Option<DateTime<Utc>> {
+        let default = format!("{}:00", default_hour);
+        let time = self.time.as_deref().unwrap_or(&default);
+        crate::venues::event_start(self.date, time, timezone)
+            .or_else(|| crate::venues::event_start(self.date, &default, timezone))
+    }
+
+    fn same_as(&self, other: &SpecialEvent) -> bool {
+        self.date == other.date && self.title.eq_ignore_ascii_case(&other.title)
+    }
+
+    fn sent_key(&self, lead_hours: u32) -> String {
+        format!("{}|{}|{}", self.date, self.title.to_lowercase(), lead_hours)
+    }
+}
+
+/// Fill a template in for an event. Lines left empty by missing details are dropped.
+pub fn render(template: &str, event: &SpecialEvent, today: NaiveDate) -> String {
+    template
+        .lines()
+        .filter_map(|line| {
+            let rendered = line
+                .replace("{title}", &event.title)
+                .replace("{when}", &game_day(event.date, today))
+                .replace("{time}", event.time.as_deref().unwrap_or("time TBD"))
+                .replace("{location}", event.location.as_deref().unwrap_or("location TBD"))
+                .replace("{details}", &event.details());
+            (line.trim().is_empty() || !rendered.trim().is_empty()).then_some(rendered)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The reminder to post now for an event starting at `start`, with every lead that's due.
+/// Only the closest one is posted, so an event added late doesn't get a burst of catch-up reminders.
+pub fn due_reminder(
+    templates: &[ReminderTemplate],
+    start: DateTime<Utc>,
+    now: DateTime<Utc>,
+    already_sent: impl Fn(u32) -> bool,
+) -> Option<(ReminderTemplate, Vec<u32>)> {
+    if start <= now {
+        return None;
+    }
+    let due: Vec<&ReminderTemplate> = templates
+        .iter()
+        .filter(|t| now >= start - Duration::hours(t.lead_hours as i64) && !already_sent(t.lead_hours))
+        .collect();
+    let closest = due.iter().min_by_key(|t| t.lead_hours)?;
+    Some(((*closest).clone(), due.iter().map(|t| t.lead_hours).collect()))
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SpecialEvents {
+    next_id: u32,
+    events: Vec<SpecialEvent>,
+    // Moderator overrides of the built-in reminders, by event type
+    #[serde(default)]
+    templates: BTreeMap<String, Vec<ReminderTemplate>>,
+    // "date|title|lead" for reminders already posted
+    #[serde(default)]
+    sent: BTreeSet<String>,
+}
+
+/// Moderator-added special events, reminder template overrides and which reminders have gone out,
+/// kept in data/special_events.json
+#[derive(Clone)]
+pub struct SpecialEventStore {
+    state: Arc<RwLock<SpecialEvents>>,
+}
+
+impl Default for SpecialEventStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpecialEventStore {
+    pub fn new() -> Self {
+        let state = std::fs::read_to_string(SPECIAL_EVENTS_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self { state: Arc::new(RwLock::new(state)) }
+    }
+
+    fn save(state: &SpecialEvents) {
+        if let Err(e) = std::fs::create_dir_all("data") { tracing::error!("Failed to create data dir: {}", e); }
+        if let Err(e) = std::fs::write(SPECIAL_EVENTS_FILE, serde_json::to_string_pretty(state).unwrap_or_default()) {
+            tracing::error!("Failed to save special events: {}", e);
+        }
+    }
+
+    pub async fn add(&self, mut event: SpecialEvent, today: NaiveDate) -> SpecialEvent {
+        let mut state = self.state.write().await;
+        state.events.retain(|e| e.date >= today);
+        state.next_id += 1;
+        event.id = state.next_id;
+        state.events.push(event.clone());
+        state.events.sort_by_key(|e| e.date);
+        Self::save(&state);
+        event
+    }
+
+    /// Upcoming events, soonest first
+    pub async fn list(&self, today: NaiveDate) -> Vec<SpecialEvent> {
+        self.state.read().await.events.iter().filter(|e| e.date >= today).cloned().collect()
+    }
+
+    pub async fn remove(&self, id: u32) -> Option<SpecialEvent> {
+        let mut state = self.state.write().await;
+        let index = state.events.iter().position(|e| e.id == id)?;
+        let event = state.events.remove(index);
+        Self::save(&state);
+        Some(event)
+    }
+
+    /// The reminders a type gets, furthest out first
+    pub async fn templates(&self, kind: &str) -> Vec<ReminderTemplate> {
+        let mut templates = match self.state.read().await.templates.get(kind) {
+            Some(templates) => templates.clone(),
+            None => type_for_key(kind)
+                .reminders
+                .iter()
+                .map(|(lead_hours, text)| ReminderTemplate { lead_hours: *lead_hours, text: text.to_string() })
+                .collect(),
+        };
+        templates.sort_by_key(|t| std::cmp::Reverse(t.lead_hours));
+        templates
+    }
+
+    /// Add or replace the reminder a type gets at this lead time. The first change copies the
+    /// built-in reminders over, so the others are kept.
+    pub async fn set_template(&self, kind: &str, template: ReminderTemplate) {
+        let current = self.templates(kind).await;
+        let mut state = self.state.write().await;
+        let templates = state.templates.entry(kind.to_string()).or_insert(current);
+        templates.retain(|t| t.lead_hours != template.lead_hours);
+        templates.push(template);
+        templates.sort_by_key(|t| std::cmp::Reverse(t.lead_hours));
+        Self::save(&state);
+    }
+
+    /// Back to the built-in reminders; false if they were never changed
+    pub async fn reset_templates(&self, kind: &str) -> bool {
+        let mut state = self.state.write().await;
+        let removed = state.templates.remove(kind).is_some();
+        if removed {
+            Self::save(&state);
+        }
+        removed
+    }
+
+    /// Reminders due now for events starting at the paired times, each marked sent as it's handed back
+    pub async fn take_due(
+        &self,
+        events: &[(SpecialEvent, DateTime<Utc>)],
+        now: DateTime<Utc>,
+        today: NaiveDate,
+    ) -> Vec<(SpecialEvent, ReminderTemplate)> {
+        let mut due = Vec::new();
+        for (event, starts_at) in events {
+            let templates = self.templates(&event.kind).await;
+            let mut state = self.state.write().await;
+            let sent = |lead: u32| state.sent.contains(&event.sent_key(lead));
+            if let Some((template, leads)) = due_reminder(&templates, *starts_at, now, sent) {
+                state.sent.extend(leads.into_iter().map(|lead| event.sent_key(lead)));
+                due.push((event.clone(), template));
+            }
+        }
+        if !due.is_empty() {
+            let mut state = self.state.write().await;
+            // Keys start with the event date, so past events' keys sort first and are dropped
+            let cutoff = (today - Duration::days(1)).to_string();
+            state.sent.retain(|key| key.as_str() >= cutoff.as_str());
+            Self::save(&state);
+        }
+        due
+    }
+}
+
+/// Manual events plus sheet rows, soonest first. A manual event wins over a sheet row with the
+/// same date and title.
+pub fn merge_events(manual: Vec<SpecialEvent>, sheet: Vec<SpecialEvent>, today: NaiveDate) -> Vec<SpecialEvent> {
+    let mut events = manual;
+    for event in sheet {
+        if event.date >= today && !events.iter().any(|e| e.same_as(&event)) {
+            events.push(event);
+        }
+    }
+    events.sort_by_key(|e| e.date);
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    fn row(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|c| c.to_string()).collect()
+    }
+
+    #[test]
+    fn test_event_from_row_and_render() {
+        let event = SpecialEvent::from_row(&row(&["5/9/2026", "9:00 AM", "Picture Day", "Spring Pictures", "Hall Field"])).unwrap();
+        assert_eq!(event.kind, "picture_day");
+        assert_eq!(event.date, date(5, 9));
+        assert_eq!(event.describe(date(5, 6)), "📸 Spring Pictures: Saturday 5/9 (in 3 days), 9:00 AM at Hall Field");
+
+        // Type from the title when the column is blank; header rows skipped
+        let event = SpecialEvent::from_row(&row(&["2026-06-12", "", "", "Trophy Night"])).unwrap();
+        assert_eq!(event.kind, "trophy_ceremony");
+        assert!(SpecialEvent::from_row(&row(&["Date", "Time", "Type", "Title"])).is_none());
+
+        let text = render("🏆 {title} is {when}.\n{details}\nBring the family.", &event, date(6, 11));
+        assert_eq!(text, "🏆 Trophy Night is Friday 6/12 (tomorrow).\nBring the family.");
+    }
+
+    #[test]
+    fn test_due_reminder() {
+        let templates: Vec<ReminderTemplate> = [(72, "three days"), (18, "night before")]
+            .iter()
+            .map(|(lead_hours, text)| ReminderTemplate { lead_hours: *lead_hours, text: text.to_string() })
+            .collect();
+        let start = Utc.with_ymd_and_hms(2026, 5, 9, 16, 0, 0).unwrap();
+        let hours_before = |h: i64| start - Duration::hours(h);
+
+        assert!(due_reminder(&templates, start, hours_before(80), |_| false).is_none());
+        let (template, leads) = due_reminder(&templates, start, hours_before(70), |_| false).unwrap();
+        assert_eq!((template.lead_hours, leads), (72, vec![72]));
+        assert!(due_reminder(&templates, start, hours_before(60), |lead| lead == 72).is_none());
+
+        // Added late: only the closest reminder goes out, and both are marked
+        let (template, leads) = due_reminder(&templates, start, hours_before(10), |_| false).unwrap();
+        assert_eq!((template.text.as_str(), leads), ("night before", vec![72, 18]));
+        assert!(due_reminder(&templates, start, start, |_| false).is_none());
+    }
+}
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+use chrono::NaiveDate;
+use serde::{Serialize, Deserialize};
+use tokio::sync::RwLock;
+
+use crate::members::names_match;
+
+const STATS_FILE: &str = "data/player_stats.json";
+
+/// Counting stats for one player, either a single game or a running total
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatCounts {
+    pub hits: u32,
+    pub at_bats: u32,
+    pub runs: u32,
+    pub rbi: u32,
+    pub home_runs: u32,
+    pub stolen_bases: u32,
+    pub walks: u32,
+}
+
+impl StatCounts {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn add(&mut self, other: &StatCounts) {
+        self.hits += other.hits;
+        self.at_bats += other.at_bats;
+        self.runs += other.runs;
+        self.rbi += other.rbi;
+        self.home_runs += other.home_runs;
+        self.stolen_bases += other.stolen_bases;
+        self.walks += other.walks;
+    }
+
+    /// Batting average in the usual ".417" form, None before any at-bats
+    pub fn average(&self) -> Option<String> {
+        if self.at_bats == 0 {
+            return None;
+        }
+        let avg = format!("{:.3}", self.hits as f64 / self.at_bats as f64);
+        Some(avg.trim_start_matches('0').to_string())
+    }
+
+    /// "2-3, 1 RBI, 2 R" - only the stats that aren't zero
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if self.at_bats > 0 {
+            parts.push(format!("{}-{}", self.hits, self.at_bats));
+        }
+        for (count, label) in [
+            (self.rbi, "RBI"),
+            (self.runs, "R"),
+            (self.home_runs, "HR"),
+            (self.stolen_bases, "SB"),
+            (self.walks, "BB"),
+        ] {
+            if count > 0 {
+                parts.push(format!("{} {}", count, label));
+            }
+        }
+        parts.join(", ")
+    }
+}
+
+/// One player's line from one game
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatLine {
+    pub date: NaiveDate,
+    pub player: String,
+    pub counts: StatCounts,
+}
+
+/// Season totals for one player
+#[derive(Debug, Clone)]
+pub struct PlayerTotals {
+    pub player: String,
+    pub games: usize,
+    pub counts: StatCounts,
+}
+
+#[derive(Clone)]
+pub struct StatsStore {
+    lines: Arc<RwLock<Vec<StatLine>>>,
+}
+
+impl Default for StatsStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StatsStore {
+    pub fn new() -> Self {
+        let _ = std::fs::create_dir_all("data");
+        let lines = std::fs::read_to_string(STATS_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self { lines: Arc::new(RwLock::new(lines)) }
+    }
+
+    fn save(lines: &[StatLine]) {
+        if let Err(e) = std::fs::create_dir_all("data") { tracing::error!("Failed to create data dir: {}", e); }
+        if let Err(e) = std::fs::write(STATS_FILE, serde_json::to_string_pretty(lines).unwrap_or_default()) {
+            tracing::error!("Failed to save player stats: {}", e);
+        }
+    }
+
+    /// Log a player's game; logging the same player and date again replaces the earlier line
+    pub async fn record(&self, line: StatLine) {
+        let mut lines = self.lines.write().await;
+        lines.retain(|l| !(l.date == line.date && l.player.eq_ignore_ascii_case(&line.player)));
+        lines.push(line);
+        lines.sort_by_key(|l| l.date);
+        Self::save(&lines);
+    }
+
+    /// Totals for a player, matching loosely so "Jake" finds "Jake S."
+    pub async fn player_totals(&self, player: &str) -> Option<PlayerTotals> {
+        self.team_totals()
+            .await
+            .into_iter()
+            .find(|t| t.player.eq_ignore_ascii_case(player) || names_match(&t.player, player))
+    }
+
+    /// Totals for every player who has logged stats, sorted by hits and then average
+    pub async fn team_totals(&self) -> Vec<PlayerTotals> {
+        let lines = self.lines.read().await;
+        let mut totals: Vec<PlayerTotals> = Vec::new();
+        for line in lines.iter() {
+            match totals.iter_mut().find(|t| t.player.eq_ignore_ascii_case(&line.player)) {
+                Some(total) => {
+                    total.games += 1;
+                    total.counts.add(&line.counts);
+                }
+                None => totals.push(PlayerTotals { player: line.player.clone(), games: 1, counts: line.counts.clone() }),
+            }
+        }
+
+        let avg = |t: &PlayerTotals| if t.counts.at_bats == 0 { 0.0 } else { t.counts.hits as f64 / t.counts.at_bats as f64 };
+        totals.sort_by(|a, b| b.counts.hits.cmp(&a.counts.hits).then(avg(b).total_cmp(&avg(a))));
+        totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stat_summary_and_average() {
+        let counts = StatCounts { hits: 2, at_bats: 3, rbi: 1, ..Default::default() };
+        assert_eq!(counts.summary(), "2-3, 1 RBI");
+        assert_eq!(counts.average().as_deref(), Some(".667"));
+        assert_eq!(StatCounts { hits: 3, at_bats: 3, ..Default::default() }.average().as_deref(), Some("1.000"));
+    }
+}
@@ -0,0 +1,122 @@
+/// Volunteer roles as (sheet role, words people use for it)
+const ROLES: [(&str, &[&str]); 5] = [
+    ("snacks", &["snacks", "snack", "food", "treats"]),
+    ("livestream", &["livestream", "livestreaming", "stream", "streaming"]),
+    ("scoreboard", &["scoreboard", "score", "scoring"]),
+    ("pitchcount", &["pitchcount", "pitch count"]),
+    ("gamechanger", &["gamechanger", "game changer", "gc", "scorebook"]),
+];
+
+/// Game details besides roles, as (category, words people use for it)
+const DETAILS: [(&str, &[&str]); 3] = [
+    ("time", &["time", "start", "starts"]),
+    ("location", &["location", "where", "field", "address"]),
+    ("home", &["home", "away", "home or away"]),
+];
+
+/// Words that make a message a weather question ("will it rain saturday", "how hot is it")
+const WEATHER_WORDS: [&str; 6] = ["weather", "forecast", "rain", "raining", "rainy", "temperature"];
+/// These only count in a question, so "I'll bring hot chocolate" stays a volunteer message
+const WEATHER_QUESTION_WORDS: [&str; 3] = ["hot", "cold", "sunny"];
+/// Also a team name ("when do we play the Storm?"), so these need weather phrasing and no team phrasing
+const STORM_WORDS: [&str; 2] = ["storm", "storms"];
+const STORM_PHRASES: [&str; 5] = ["will it", "is it", "gonna", "going to", "chance of"];
+const TEAM_PHRASES: [&str; 5] = ["the storm", "vs storm", "vs. storm", "play storm", "against storm"];
+
+/// Byte offset of the first whole-word occurrence of `word` in `text`
+fn find_word(text: &str, word: &str) -> Option<usize> {
+    text.match_indices(word)
+        .find(|(at, _)| {
+            let before = text[..*at].chars().next_back();
+            let after = text[at + word.len()..].chars().next();
+            !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+        })
+        .map(|(at, _)| at)
+}
+
+/// Names from `table` mentioned in `text`, in the order they're mentioned
+fn mentioned(text: &str, table: &[(&'static str, &[&str])]) -> Vec<(usize, &'static str)> {
+    table
+        .iter()
+        .filter_map(|(name, words)| words.iter().filter_map(|w| find_word(text, w)).min().map(|at| (at, *name)))
+        .collect()
+}
+
+fn in_order(mut found: Vec<(usize, &'static str)>) -> Vec<&'static str> {
+    found.sort();
+    found.into_iter().map(|(_, name)| name).collect()
+}
+
+/// Sheet role for a word like "food" or "stream"
+pub fn canonical_role(word: &str) -> Option<&'static str> {
+    let word = word.trim().to_lowercase();
+    ROLES.iter().find(|(_, words)| words.contains(&word.as_str())).map(|(role, _)| *role)
+}
+
+/// Roles mentioned in lowercase `text`, in mention order: "food and stream" → snacks, livestream
+pub fn roles_in(text: &str) -> Vec<&'static str> {
+    in_order(mentioned(text, &ROLES))
+}
+
+/// Whether lowercase `text` asks about the weather
+pub fn is_weather_question(text: &str) -> bool {
+    let is_question = text.contains('?') || ["how ", "will it", "is it", "gonna be", "going to be"].iter().any(|q| text.contains(q));
+    let asks_about_storms = STORM_WORDS.iter().any(|w| find_word(text, w).is_some())
+        && (STORM_PHRASES.iter().any(|p| text.contains(p)) || find_word(text, "any").is_some())
+        && !TEAM_PHRASES.iter().any(|p| text.contains(p));
+    WEATHER_WORDS.iter().any(|w| find_word(text, w).is_some())
+        || asks_about_storms
+        || (is_question && WEATHER_QUESTION_WORDS.iter().any(|w| find_word(text, w).is_some()))
+}
+
+/// Weather words stripped out, so "sunny" doesn't read as Sunday when looking for a date
+pub fn without_weather_words(text: &str) -> String {
+    text.split_whitespace()
+        .filter(|w| {
+            let w = w.trim_matches(|c: char| !c.is_alphanumeric());
+            !WEATHER_WORDS.contains(&w) && !WEATHER_QUESTION_WORDS.contains(&w) && !STORM_WORDS.contains(&w)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Everything a game question asks about, in mention order:
+/// "who's got snacks and score saturday" → snacks, scoreboard
+pub fn categories_in(text: &str) -> Vec<&'static str> {
+    let mut found = mentioned(text, &DETAILS);
+    found.extend(mentioned(text, &ROLES));
+    if is_weather_question(text) {
+        let at = WEATHER_WORDS.iter().chain(&WEATHER_QUESTION_WORDS).chain(&STORM_WORDS).filter_map(|w| find_word(text, w)).min();
+        found.push((at.unwrap_or(text.len()), "weather"));
+    }
+    in_order(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_synonyms() {
+        assert_eq!(roles_in("i'll bring food and run the stream"), vec!["snacks", "livestream"]);
+        assert_eq!(roles_in("who has the scorebook"), vec!["gamechanger"]);
+        assert!(roles_in("i live close to the field").is_empty());
+        assert_eq!(canonical_role("Food"), Some("snacks"));
+        assert_eq!(canonical_role("pitch count"), Some("pitchcount"));
+        // "pitch in" is offering help, not the pitch count
+        assert!(roles_in("i can pitch in saturday").is_empty());
+        assert_eq!(canonical_role("shortstop"), None);
+
+        assert_eq!(categories_in("who's got snacks and score saturday"), vec!["snacks", "scoreboard"]);
+        assert_eq!(categories_in("where and what time is the next game"), vec!["location", "time"]);
+        assert_eq!(categories_in("will it rain at the next game"), vec!["weather"]);
+
+        assert!(is_weather_question("how cold will it be"));
+        assert!(!is_weather_question("i'll bring hot chocolate"));
+        assert!(is_weather_question("will it storm saturday"));
+        assert!(is_weather_question("any storms for the next game?"));
+        assert!(!is_weather_question("when do we play the storm?"));
+        assert!(!is_weather_question("is it the storm saturday?"));
+        assert_eq!(without_weather_words("is it sunny saturday"), "is it saturday");
+    }
+}
@@ -14,11 +14,10 @@ pub struct TeamFactsProvider {
     team_name: String,
     team_emoji: String,
     custom_facts: Option<CustomTeamFacts>,
-    enabled: bool,
 }
 
 impl TeamFactsProvider {
-    pub fn new(team_name: String, team_emoji: String, enabled: bool, facts_file: Option<String>) -> Self {
+    pub fn new(team_name: String, team_emoji: String, facts_file: Option<String>) -> Self {
         let custom_facts = if let Some(path) = facts_file {
             Self::load_custom_facts(&path)
         } else {
@@ -29,7 +28,6 @@ impl TeamFactsProvider {
             team_name,
             team_emoji,
             custom_facts,
-            enabled,
         }
     }
 
@@ -44,11 +42,12 @@ impl TeamFactsProvider {
         None
     }
 
-    pub fn get_fact(&self) -> String {
-        if !self.enabled {
-            return format!("{} Let's go team! ⚾", self.team_emoji);
-        }
+    /// Plain cheer for when team facts are switched off
+    pub fn cheer(&self) -> String {
+        format!("{} Let's go team! ⚾", self.team_emoji)
+    }
 
+    pub fn get_fact(&self) -> String {
         // Try custom facts first
         if let Some(ref custom) = self.custom_facts {
             let mut rng = thread_rng();
@@ -174,7 +173,7 @@ mod tests {
 
     #[test]
     fn test_pirates_facts() {
-        let provider = TeamFactsProvider::new("Pirates".to_string(), "🏴‍☠️".to_string(), true, None);
+        let provider = TeamFactsProvider::new("Pirates".to_string(), "🏴‍☠️".to_string(), None);
         let fact = provider.get_fact();
         assert!(!fact.is_empty());
         assert!(fact.contains("Pirates") || fact.contains("⚾") || fact.contains("🏴‍☠️"));
@@ -182,14 +181,14 @@ mod tests {
 
     #[test]
     fn test_disabled_facts() {
-        let provider = TeamFactsProvider::new("Pirates".to_string(), "🏴‍☠️".to_string(), false, None);
-        let fact = provider.get_fact();
+        let provider = TeamFactsProvider::new("Pirates".to_string(), "🏴‍☠️".to_string(), None);
+        let fact = provider.cheer();
         assert_eq!(fact, "🏴‍☠️ Let's go team! ⚾");
     }
 
     #[test]
     fn test_generic_team() {
-        let provider = TeamFactsProvider::new("Dragons".to_string(), "🐉".to_string(), true, None);
+        let provider = TeamFactsProvider::new("Dragons".to_string(), "🐉".to_string(), None);
         let fact = provider.get_fact();
         assert!(fact.contains("Dragons"));
         assert!(fact.contains("🐉"));
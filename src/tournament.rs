@@ -0,0 +1,391 @@
+use chrono::{Duration, NaiveDate};
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::models::{teams_match, CorrelatedEvent, EventData};
+
+const TOURNAMENT_FILE: &str = "data/tournament.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Outcome {
+    Won,
+    Lost,
+}
+
+impl Outcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Outcome::Won => "win",
+            Outcome::Lost => "lose",
+        }
+    }
+}
+
+/// One bracket or pool game. Later games can depend on how an earlier one goes
+/// ("game 3 is at 3 PM if we win game 2").
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BracketGame {
+    pub number: u32,
+    pub date: NaiveDate,
+    pub time: String,
+    pub location: String,
+    pub opponent: Option<String>,
+    /// Only played if game N goes this way
+    pub condition: Option<(u32, Outcome)>,
+    pub outcome: Option<Outcome>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameStatus {
+    Played(Outcome),
+    /// Will be played: no condition, or its condition was met
+    Confirmed,
+    /// Depends on a game that hasn't been decided yet
+    Projected,
+    /// Its condition went the other way
+    Eliminated,
+}
+
+impl BracketGame {
+    /// View this game like a sheet event so next-game and reminders can use it
+    pub fn to_event(&self) -> CorrelatedEvent {
+        let summary = match &self.opponent {
+            Some(opponent) => format!("Tournament game {} vs {}", self.number, opponent),
+            None => format!("Tournament game {}", self.number),
+        };
+        CorrelatedEvent {
+            event_date: self.date,
+            event_summary: summary,
+            data: EventData::new(
+                self.date,
+                self.time.clone(),
+                self.location.clone(),
+                self.opponent.clone().unwrap_or_default(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+            ),
+        }
+    }
+
+    /// "Game 3: 6/14 3:00 PM @ Tournament Park vs Storm (if we win game 2)"
+    pub fn describe(&self) -> String {
+        let mut line = format!("Game {}: {}", self.number, self.date.format("%-m/%-d"));
+        if !self.time.is_empty() {
+            line.push_str(&format!(" {}", self.time));
+        }
+        if !self.location.is_empty() {
+            line.push_str(&format!(" @ {}", self.location));
+        }
+        if let Some(opponent) = &self.opponent {
+            line.push_str(&format!(" vs {}", opponent));
+        }
+        if let Some((game, outcome)) = self.condition {
+            line.push_str(&format!(" (if we {} game {})", outcome.as_str(), game));
+        }
+        line
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Tournament {
+    pub name: String,
+    pub games: Vec<BracketGame>,
+    /// First and last day, for travel tournaments
+    #[serde(default)]
+    pub dates: Option<(NaiveDate, NaiveDate)>,
+    /// Travel details by label: "hotel", "arrival", "dinner", ...
+    #[serde(default)]
+    pub logistics: BTreeMap<String, String>,
+}
+
+/// What families usually ask about before a travel tournament
+const EXPECTED_LOGISTICS: [&str; 3] = ["hotel", "arrival", "dinner"];
+
+fn logistics_icon(label: &str) -> &'static str {
+    match label {
+        "hotel" | "lodging" => "🏨",
+        "arrival" | "check-in" | "checkin" => "🚗",
+        "dinner" | "team dinner" => "🍝",
+        _ => "📌",
+    }
+}
+
+impl Tournament {
+    fn new(name: &str) -> Self {
+        Self { name: name.to_string(), ..Self::default() }
+    }
+
+    /// "6/13-6/15"
+    pub fn date_range(&self) -> Option<String> {
+        self.dates.map(|(start, end)| {
+            if start == end {
+                start.format("%-m/%-d").to_string()
+            } else {
+                format!("{}-{}", start.format("%-m/%-d"), end.format("%-m/%-d"))
+            }
+        })
+    }
+
+    /// Whether the tournament overlaps the week starting today
+    pub fn is_within_week_of(&self, today: NaiveDate) -> bool {
+        self.dates.is_some_and(|(start, end)| start <= today + Duration::days(6) && end >= today)
+    }
+
+    /// Dates and travel details for "tournament info"
+    pub fn info(&self) -> String {
+        let mut response = format!("🏆 {}", self.name);
+        if let Some(range) = self.date_range() {
+            response.push_str(&format!(" ({})", range));
+        }
+        response.push('\n');
+        if self.logistics.is_empty() {
+            response.push_str("No travel details yet. Moderators can add them with \"tournament hotel: <link>\"");
+        }
+        for (label, value) in &self.logistics {
+            response.push_str(&format!("{} {}: {}\n", logistics_icon(label), capitalize(label), value));
+        }
+        response
+    }
+
+    /// Standard travel details nobody has filled in yet
+    pub fn missing_logistics(&self) -> Vec<&'static str> {
+        EXPECTED_LOGISTICS.iter().copied().filter(|label| !self.logistics.contains_key(*label)).collect()
+    }
+
+    pub fn game(&self, number: u32) -> Option<&BracketGame> {
+        self.games.iter().find(|g| g.number == number)
+    }
+
+    pub fn status(&self, game: &BracketGame) -> GameStatus {
+        self.status_at_depth(game, 0)
+    }
+
+    // Depth guard so a typo like "game 2 if we win game 2" can't recurse forever
+    fn status_at_depth(&self, game: &BracketGame, depth: usize) -> GameStatus {
+        if let Some(outcome) = game.outcome {
+            return GameStatus::Played(outcome);
+        }
+        let Some((number, wanted)) = game.condition else {
+            return GameStatus::Confirmed;
+        };
+        let Some(parent) = self.game(number) else {
+            return GameStatus::Projected;
+        };
+        if depth > self.games.len() {
+            return GameStatus::Projected;
+        }
+        match self.status_at_depth(parent, depth + 1) {
+            GameStatus::Played(outcome) if outcome == wanted => GameStatus::Confirmed,
+            GameStatus::Played(_) | GameStatus::Eliminated => GameStatus::Eliminated,
+            GameStatus::Confirmed | GameStatus::Projected => GameStatus::Projected,
+        }
+    }
+
+    /// Games still to be played that are certain to happen
+    pub fn confirmed_games(&self) -> Vec<&BracketGame> {
+        self.games.iter().filter(|g| self.status(g) == GameStatus::Confirmed).collect()
+    }
+
+    /// Games waiting on an earlier result
+    pub fn projected_games(&self) -> Vec<&BracketGame> {
+        self.games.iter().filter(|g| self.status(g) == GameStatus::Projected).collect()
+    }
+
+    /// Whole bracket with each game's status
+    pub fn describe(&self) -> String {
+        let mut response = format!("🏆 {}\n", self.name);
+        if self.games.is_empty() {
+            response.push_str("No games yet. Add one with \"bracket game 1 6/14 9am at Tournament Park vs Storm\"");
+            return response;
+        }
+        for game in &self.games {
+            let icon = match self.status(game) {
+                GameStatus::Played(Outcome::Won) => "✅",
+                GameStatus::Played(Outcome::Lost) => "❌",
+                GameStatus::Confirmed => "📅",
+                GameStatus::Projected => "❔",
+                GameStatus::Eliminated => "➖",
+            };
+            response.push_str(&format!("{} {}\n", icon, game.describe()));
+        }
+        response
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[derive(Clone)]
+pub struct TournamentStore {
+    tournament: Arc<RwLock<Option<Tournament>>>,
+}
+
+impl Default for TournamentStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TournamentStore {
+    pub fn new() -> Self {
+        let _ = std::fs::create_dir_all("data");
+        let tournament = std::fs::read_to_string(TOURNAMENT_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok());
+        Self { tournament: Arc::new(RwLock::new(tournament)) }
+    }
+
+    fn save(tournament: &Option<Tournament>) {
+        if let Err(e) = std::fs::create_dir_all("data") { tracing::error!("Failed to create data dir: {}", e); }
+        let result = match tournament {
+            Some(t) => std::fs::write(TOURNAMENT_FILE, serde_json::to_string_pretty(t).unwrap_or_default()),
+            None => std::fs::remove_file(TOURNAMENT_FILE).or_else(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound { Ok(()) } else { Err(e) }
+            }),
+        };
+        if let Err(e) = result {
+            tracing::error!("Failed to save tournament: {}", e);
+        }
+    }
+
+    pub async fn current(&self) -> Option<Tournament> {
+        self.tournament.read().await.clone()
+    }
+
+    /// Start a new tournament, replacing any previous bracket
+    pub async fn start(&self, name: &str) {
+        let mut tournament = self.tournament.write().await;
+        *tournament = Some(Tournament::new(name));
+        Self::save(&tournament);
+    }
+
+    /// Add or replace a game by number; creates an unnamed tournament if none is running
+    pub async fn set_game(&self, game: BracketGame) {
+        let mut tournament = self.tournament.write().await;
+        let current = tournament.get_or_insert_with(|| Tournament::new("Tournament"));
+        current.games.retain(|g| g.number != game.number);
+        current.games.push(game);
+        current.games.sort_by_key(|g| g.number);
+        Self::save(&tournament);
+    }
+
+    pub async fn set_dates(&self, start: NaiveDate, end: NaiveDate) {
+        let mut tournament = self.tournament.write().await;
+        tournament.get_or_insert_with(|| Tournament::new("Tournament")).dates = Some((start, end));
+        Self::save(&tournament);
+    }
+
+    /// Set a travel detail, or remove it with None
+    pub async fn set_logistics(&self, label: &str, value: Option<String>) {
+        let mut tournament = self.tournament.write().await;
+        let current = tournament.get_or_insert_with(|| Tournament::new("Tournament"));
+        match value {
+            Some(value) => { current.logistics.insert(label.to_lowercase(), value); }
+            None => { current.logistics.remove(&label.to_lowercase()); }
+        }
+        Self::save(&tournament);
+    }
+
+    /// Returns false if there's no such game
+    pub async fn record_outcome(&self, number: u32, outcome: Outcome) -> bool {
+        let mut tournament = self.tournament.write().await;
+        let Some(game) = tournament.as_mut().and_then(|t| t.games.iter_mut().find(|g| g.number == number)) else {
+            return false;
+        };
+        game.outcome = Some(outcome);
+        Self::save(&tournament);
+        true
+    }
+
+    /// Match a recorded final score to a bracket game by date, and opponent when that day has
+    /// several undecided games. Returns the game number that was updated.
+    pub async fn record_result(&self, date: NaiveDate, opponent: &str, outcome: Outcome) -> Option<u32> {
+        let number = {
+            let tournament = self.tournament.read().await;
+            let candidates: Vec<&BracketGame> = tournament
+                .as_ref()?
+                .games
+                .iter()
+                .filter(|g| g.date == date && g.outcome.is_none())
+                .collect();
+            match candidates.as_slice() {
+                [only] => only.number,
+                many => many.iter().find(|g| g.opponent.as_deref().is_some_and(|o| teams_match(o, opponent)))?.number,
+            }
+        };
+        self.record_outcome(number, outcome).await.then_some(number)
+    }
+
+    pub async fn clear(&self) {
+        let mut tournament = self.tournament.write().await;
+        *tournament = None;
+        Self::save(&tournament);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(number: u32, condition: Option<(u32, Outcome)>) -> BracketGame {
+        BracketGame {
+            number,
+            date: NaiveDate::from_ymd_opt(2025, 6, 14).unwrap(),
+            time: "9:00 AM".to_string(),
+            location: "Tournament Park".to_string(),
+            opponent: None,
+            condition,
+            outcome: None,
+        }
+    }
+
+    #[test]
+    fn test_bracket_status() {
+        let mut tournament = Tournament {
+            name: "Summer Slam".to_string(),
+            dates: None,
+            logistics: BTreeMap::new(),
+            games: vec![
+                game(2, None),
+                game(3, Some((2, Outcome::Won))),
+                game(4, Some((2, Outcome::Lost))),
+                game(5, Some((3, Outcome::Won))),
+            ],
+        };
+        assert_eq!(tournament.status(&tournament.games[1]), GameStatus::Projected);
+
+        tournament.games[0].outcome = Some(Outcome::Won);
+        let statuses: Vec<GameStatus> = tournament.games.iter().map(|g| tournament.status(g)).collect();
+        assert_eq!(statuses, vec![
+            GameStatus::Played(Outcome::Won),
+            GameStatus::Confirmed,
+            GameStatus::Eliminated,
+            GameStatus::Projected,
+        ]);
+        assert_eq!(tournament.confirmed_games().len(), 1);
+        assert_eq!(tournament.games[1].describe(), "Game 3: 6/14 9:00 AM @ Tournament Park (if we win game 2)");
+    }
+
+    #[test]
+    fn test_tournament_week() {
+        let mut tournament = Tournament::new("Summer Slam");
+        let start = NaiveDate::from_ymd_opt(2025, 6, 13).unwrap();
+        tournament.dates = Some((start, start + Duration::days(2)));
+        tournament.logistics.insert("hotel".to_string(), "https://example.com/block".to_string());
+
+        assert!(tournament.is_within_week_of(start - Duration::days(6)));
+        assert!(!tournament.is_within_week_of(start - Duration::days(7)));
+        assert_eq!(tournament.date_range().as_deref(), Some("6/13-6/15"));
+        assert_eq!(tournament.missing_logistics(), vec!["arrival", "dinner"]);
+    }
+}
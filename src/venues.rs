@@ -0,0 +1,122 @@
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::service::parse_start_time;
+
+const VENUES_FILE: &str = "data/venues.json";
+
+/// A field or complex the team plays at, with anything that differs from home
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Venue {
+    pub name: String,
+    /// IANA name like "America/Chicago"; None means the server's local time zone
+    pub timezone: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct VenueStore {
+    venues: Arc<RwLock<BTreeMap<String, Venue>>>,
+}
+
+impl Default for VenueStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VenueStore {
+    pub fn new() -> Self {
+        let _ = std::fs::create_dir_all("data");
+        let venues = std::fs::read_to_string(VENUES_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self { venues: Arc::new(RwLock::new(venues)) }
+    }
+
+    fn save(venues: &BTreeMap<String, Venue>) {
+        if let Err(e) = std::fs::create_dir_all("data") { tracing::error!("Failed to create data dir: {}", e); }
+        if let Err(e) = std::fs::write(VENUES_FILE, serde_json::to_string_pretty(venues).unwrap_or_default()) {
+            tracing::error!("Failed to save venues: {}", e);
+        }
+    }
+
+    /// Set or clear a venue's time zone override
+    pub async fn set_timezone(&self, name: &str, timezone: Option<Tz>) {
+        let mut venues = self.venues.write().await;
+        venues.insert(name.to_lowercase(), Venue {
+            name: name.to_string(),
+            timezone: timezone.map(|tz| tz.name().to_string()),
+        });
+        Self::save(&venues);
+    }
+
+    pub async fn list(&self) -> Vec<Venue> {
+        self.venues.read().await.values().cloned().collect()
+    }
+
+    /// Time zone for a sheet location; the longest venue name found in it wins, so
+    /// "Tournament Park Field 3, Springfield" matches a "Tournament Park" entry
+    pub async fn timezone_for(&self, location: &str) -> Option<Tz> {
+        let location = location.to_lowercase();
+        self.venues
+            .read()
+            .await
+            .iter()
+            .filter(|(key, _)| location.contains(key.as_str()))
+            .max_by_key(|(key, _)| key.len())
+            .and_then(|(_, venue)| venue.timezone.as_ref())
+            .and_then(|tz| tz.parse().ok())
+    }
+}
+
+/// When a game starts, reading its time in the venue's time zone (or ours if it has none).
+/// Ranges like "8am-9:30am" use the start; None for "TBD" and other unparseable times.
+pub fn event_start(date: NaiveDate, time: &str, timezone: Option<Tz>) -> Option<DateTime<Utc>> {
+    let start = parse_start_time(time)?;
+    let local = date.and_time(start);
+    match timezone {
+        Some(tz) => tz.from_local_datetime(&local).earliest().map(|dt| dt.with_timezone(&Utc)),
+        None => Local.from_local_datetime(&local).earliest().map(|dt| dt.with_timezone(&Utc)),
+    }
+}
+
+/// "2 days, 3 hours" / "3 hours, 20 minutes" / "20 minutes"
+pub fn format_countdown(until: Duration) -> String {
+    let plural = |n: i64, unit: &str| format!("{} {}{}", n, unit, if n == 1 { "" } else { "s" });
+    let (days, hours, minutes) = (until.num_days(), until.num_hours() % 24, until.num_minutes() % 60);
+    if days > 0 {
+        format!("{}, {}", plural(days, "day"), plural(hours, "hour"))
+    } else if hours > 0 {
+        format!("{}, {}", plural(hours, "hour"), plural(minutes, "minute"))
+    } else {
+        plural(minutes.max(0), "minute")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_start_in_venue_timezone() {
+        let date = NaiveDate::from_ymd_opt(2025, 6, 14).unwrap();
+        let chicago: Tz = "America/Chicago".parse().unwrap();
+
+        // 10 AM CDT is 15:00 UTC
+        let start = event_start(date, "10:00 AM - 12:00 PM", Some(chicago)).unwrap();
+        assert_eq!(start.format("%H:%M").to_string(), "15:00");
+        assert!(event_start(date, "TBD", Some(chicago)).is_none());
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(Duration::minutes(26 * 60 + 5)), "1 day, 2 hours");
+        assert_eq!(format_countdown(Duration::minutes(95)), "1 hour, 35 minutes");
+        assert_eq!(format_countdown(Duration::minutes(1)), "1 minute");
+    }
+}
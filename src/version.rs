@@ -0,0 +1,111 @@
+use serde::{Serialize, Deserialize};
+
+/// The running build's version, from Cargo.toml
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+const CHANGELOG: &str = include_str!("../CHANGELOG.md");
+const ANNOUNCED_VERSION_FILE: &str = "data/announced_version.json";
+/// Most changes listed in one reply or announcement
+const MAX_CHANGES: usize = 8;
+
+/// One "## x.y.z" section of CHANGELOG.md
+#[derive(Debug, PartialEq)]
+pub struct Release {
+    pub version: String,
+    pub changes: Vec<String>,
+}
+
+/// Releases in CHANGELOG.md, newest first
+fn parse_changelog(text: &str) -> Vec<Release> {
+    let mut releases: Vec<Release> = Vec::new();
+    for line in text.lines().map(str::trim) {
+        if let Some(heading) = line.strip_prefix("## ") {
+            let version = heading.split_whitespace().next().unwrap_or(heading).trim_start_matches('v');
+            releases.push(Release { version: version.to_string(), changes: Vec::new() });
+        } else if let (Some(change), Some(release)) = (line.strip_prefix("- "), releases.last_mut()) {
+            release.changes.push(change.to_string());
+        }
+    }
+    releases
+}
+
+/// Changes in releases newer than `since`, newest first. Unknown or missing `since` means just
+/// the latest release.
+fn changes_since(releases: &[Release], since: Option<&str>) -> Vec<String> {
+    let newer = since
+        .and_then(|since| releases.iter().position(|r| r.version == since))
+        .unwrap_or(1.min(releases.len()));
+    releases[..newer].iter().flat_map(|r| r.changes.iter().cloned()).take(MAX_CHANGES).collect()
+}
+
+/// "@bot version"
+pub fn version_text() -> String {
+    let changes = changes_since(&parse_changelog(CHANGELOG), None);
+    let mut text = format!("🤖 Running v{}", VERSION);
+    if !changes.is_empty() {
+        text.push_str("\nNew in this version:");
+        for change in changes {
+            text.push_str(&format!("\n• {}", change));
+        }
+    }
+    text
+}
+
+#[derive(Serialize, Deserialize)]
+struct AnnouncedVersion {
+    version: String,
+}
+
+fn last_announced() -> Option<String> {
+    std::fs::read_to_string(ANNOUNCED_VERSION_FILE)
+        .ok()
+        .and_then(|content| serde_json::from_str::<AnnouncedVersion>(&content).ok())
+        .map(|announced| announced.version)
+}
+
+/// Remember that this version has been announced (or needn't be)
+pub fn mark_announced() {
+    if let Err(e) = std::fs::create_dir_all("data") { tracing::error!("Failed to create data dir: {}", e); }
+    let announced = AnnouncedVersion { version: VERSION.to_string() };
+    if let Err(e) = std::fs::write(ANNOUNCED_VERSION_FILE, serde_json::to_string_pretty(&announced).unwrap_or_default()) {
+        tracing::error!("Failed to save announced version: {}", e);
+    }
+}
+
+/// The "I've been updated" post, if this build is newer than the last one announced.
+/// A fresh install has nothing to compare with, so it's recorded without a post.
+pub fn update_announcement(bot_name: &str) -> Option<String> {
+    let Some(last) = last_announced() else {
+        mark_announced();
+        return None;
+    };
+    if last == VERSION {
+        return None;
+    }
+    let changes = changes_since(&parse_changelog(CHANGELOG), Some(&last));
+    let mut text = format!("🆕 I've been updated to v{}!", VERSION);
+    if !changes.is_empty() {
+        text.push_str(" New:");
+        for change in changes {
+            text.push_str(&format!("\n• {}", change));
+        }
+    }
+    text.push_str(&format!("\nSay \"@{} version\" any time to see what's new.", bot_name));
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changes_since() {
+        let releases = parse_changelog("# Changelog\n\nIntro\n\n## 0.3.0\n- Carpools\n\n## v0.2.0 - spring\n- Absences\n- Game mode\n\n## 0.1.0\n- Schedule\n");
+        assert_eq!(releases[1], Release { version: "0.2.0".to_string(), changes: vec!["Absences".to_string(), "Game mode".to_string()] });
+        assert_eq!(changes_since(&releases, None), vec!["Carpools"]);
+        assert_eq!(changes_since(&releases, Some("0.1.0")), vec!["Carpools", "Absences", "Game mode"]);
+        assert_eq!(changes_since(&releases, Some("0.9.9")), vec!["Carpools"]);
+
+        // The embedded changelog has an entry for this build
+        assert_eq!(parse_changelog(CHANGELOG).first().map(|r| r.version.as_str()), Some(VERSION));
+    }
+}
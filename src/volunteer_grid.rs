@@ -0,0 +1,117 @@
+use embedded_graphics::mono_font::iso_8859_1::FONT_10X20;
+
+use crate::error::Result;
+use crate::models::CorrelatedEvent;
+use crate::raster::Canvas;
+use crate::signup::SIGNUP_ROLES;
+
+/// How many upcoming games the grid shows
+pub const GRID_GAMES: usize = 8;
+
+const CHAR_WIDTH: u32 = 10;
+const ROW_HEIGHT: u32 = 30;
+const PADDING: u32 = 10;
+/// Longer names are cut so one sign-up can't stretch the whole image
+const MAX_CELL_CHARS: usize = 16;
+
+const HEADER: [u8; 3] = [220, 220, 220];
+const OPEN: [u8; 3] = [255, 215, 205];
+const GRID_LINE: [u8; 3] = [170, 170, 170];
+
+/// Text vertically centred in a row starting at `y`
+fn cell_text(canvas: &mut Canvas, text: &str, x: u32, y: u32) {
+    canvas.text(text, x as i32, (y + (ROW_HEIGHT - 20) / 2) as i32, &FONT_10X20);
+}
+
+fn cell(text: &str) -> String {
+    if text.chars().count() > MAX_CELL_CHARS {
+        format!("{}.", text.chars().take(MAX_CELL_CHARS - 1).collect::<String>())
+    } else {
+        text.to_string()
+    }
+}
+
+/// PNG table of upcoming games (rows) by volunteer role (columns).
+/// Open spots are shaded; roles a game doesn't need (scoreboard at home) show "-".
+pub fn grid_png(team_name: &str, events: &[CorrelatedEvent]) -> Result<Vec<u8>> {
+    let mut header = vec!["Game".to_string()];
+    header.extend(SIGNUP_ROLES.iter().map(|(_, label)| label.to_string()));
+
+    // (text, open) per cell
+    let rows: Vec<Vec<(String, bool)>> = events
+        .iter()
+        .map(|event| {
+            let mut row = vec![(event.event_date.format("%a %-m/%-d").to_string(), false)];
+            for (role, _) in SIGNUP_ROLES {
+                row.push(match event.data.get_field(role) {
+                    Some(person) => (cell(person), false),
+                    None if event.data.is_role_available(role, team_name) => ("open".to_string(), true),
+                    None => ("-".to_string(), false),
+                });
+            }
+            row
+        })
+        .collect();
+
+    let widths: Vec<u32> = (0..header.len())
+        .map(|col| {
+            let chars = rows
+                .iter()
+                .map(|row| row[col].0.chars().count())
+                .chain(std::iter::once(header[col].chars().count()))
+                .max()
+                .unwrap_or(0);
+            chars as u32 * CHAR_WIDTH + 2 * PADDING
+        })
+        .collect();
+
+    let title = format!("{} volunteers", team_name);
+    let table_width: u32 = widths.iter().sum();
+    let width = table_width.max(title.chars().count() as u32 * CHAR_WIDTH + 2 * PADDING);
+    let height = ROW_HEIGHT * (rows.len() as u32 + 2);
+
+    let mut canvas = Canvas::new(width, height);
+    cell_text(&mut canvas, &title, PADDING, 0);
+    canvas.fill(0, ROW_HEIGHT, table_width, ROW_HEIGHT, HEADER);
+
+    let table_rows = std::iter::once(header.into_iter().map(|h| (h, false)).collect()).chain(rows);
+    for (index, row) in table_rows.enumerate() {
+        let y = ROW_HEIGHT * (index as u32 + 1);
+        let mut x = 0;
+        for ((text, open), width) in row.iter().zip(&widths) {
+            if *open {
+                canvas.fill(x, y, *width, ROW_HEIGHT, OPEN);
+            }
+            cell_text(&mut canvas, text, x + PADDING, y);
+            canvas.fill(x, y, 1, ROW_HEIGHT, GRID_LINE);
+            x += width;
+        }
+        canvas.fill(0, y, table_width, 1, GRID_LINE);
+    }
+    canvas.fill(0, height - 1, table_width, 1, GRID_LINE);
+    canvas.fill(table_width - 1, ROW_HEIGHT, 1, height - ROW_HEIGHT, GRID_LINE);
+
+    canvas.into_png()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EventData;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_grid_png() {
+        let date = NaiveDate::from_ymd_opt(2025, 5, 3).unwrap();
+        let mut data = EventData::new(date, "10:00 AM".to_string(), "Hall Field".to_string(), "Away".to_string(),
+            String::new(), String::new(), String::new(), String::new(), String::new());
+        data.assign_volunteer("snacks", "Jane Doe");
+        let event = CorrelatedEvent { event_date: date, event_summary: "Pirates vs Storm".to_string(), data };
+
+        let png = grid_png("Pirates", &[event.clone(), event]).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+        let image = image::load_from_memory(&png).unwrap();
+        assert_eq!(image.height(), ROW_HEIGHT * 4);
+        assert_eq!(cell("Bartholomew Roberts-Smith"), "Bartholomew Rob.");
+    }
+}
@@ -0,0 +1,304 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::signup::SIGNUP_ROLES;
+
+const VOLUNTEER_FILLS_FILE: &str = "data/volunteer_fills.json";
+
+/// Spots filled closer to the game than this count as last-minute
+pub const LAST_MINUTE_HOURS: f64 = 48.0;
+/// A sign-up this soon after a reminder post counts as a response to it
+pub const REMINDER_WINDOW_HOURS: f64 = 6.0;
+
+/// One game's volunteer spots as last seen before first pitch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameFills {
+    pub start: DateTime<Utc>,
+    pub needed: Vec<String>,
+    /// Role -> hours before the start it was filled. None when it was already filled
+    /// the first time the bot saw the game, so the fill time isn't known.
+    pub filled: BTreeMap<String, Option<f64>>,
+    /// Reminder kind ("24h", "briefing", "15m") -> hours before the start it was posted
+    #[serde(default)]
+    pub reminders: BTreeMap<String, f64>,
+}
+
+impl GameFills {
+    pub fn fully_staffed(&self) -> bool {
+        self.needed.iter().all(|role| self.filled.contains_key(role))
+    }
+
+    /// The reminder a fill `lead_hours` before the game answers: the latest one posted
+    /// before it, if that was within the response window
+    fn prompted_by(&self, lead_hours: f64) -> Option<&str> {
+        self.reminders
+            .iter()
+            .filter(|(_, sent)| **sent >= lead_hours && **sent - lead_hours <= REMINDER_WINDOW_HOURS)
+            .min_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(kind, _)| kind.as_str())
+    }
+}
+
+/// A game's volunteer spots right now: (date, start, needed roles, filled roles)
+pub type GameSnapshot = (NaiveDate, DateTime<Utc>, Vec<&'static str>, Vec<&'static str>);
+
+/// How many sign-ups followed one kind of reminder, from `FillStats`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReminderStats {
+    pub reminder: String,
+    pub posts: usize,
+    pub fills: usize,
+}
+
+/// Per-role numbers from `FillStats`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoleStats {
+    pub role: &'static str,
+    pub filled: usize,
+    pub needed: usize,
+    pub avg_lead_hours: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FillStats {
+    /// Games that have started, which is what the staffed percentage is out of
+    pub games_played: usize,
+    pub fully_staffed_percent: Option<f64>,
+    /// Fills with a known time, which the lead time numbers are out of
+    pub timed_fills: usize,
+    pub avg_lead_hours: Option<f64>,
+    pub last_minute_percent: Option<f64>,
+    pub roles: Vec<RoleStats>,
+    pub reminders: Vec<ReminderStats>,
+    /// Timed fills that didn't follow any reminder
+    pub unprompted_fills: usize,
+}
+
+fn average(values: &[f64]) -> Option<f64> {
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+fn percent(part: usize, whole: usize) -> Option<f64> {
+    (whole > 0).then(|| part as f64 * 100.0 / whole as f64)
+}
+
+/// Fill numbers across `games` as of `now`
+pub fn fill_stats(games: &BTreeMap<NaiveDate, GameFills>, now: DateTime<Utc>) -> FillStats {
+    let played: Vec<&GameFills> = games.values().filter(|game| game.start <= now).collect();
+    let leads: Vec<f64> = games.values().flat_map(|game| game.filled.values().flatten().copied()).collect();
+
+    let roles = SIGNUP_ROLES
+        .iter()
+        .map(|(role, _)| {
+            let role_leads: Vec<f64> = games.values().filter_map(|game| game.filled.get(*role).copied().flatten()).collect();
+            RoleStats {
+                role,
+                filled: played.iter().filter(|game| game.filled.contains_key(*role)).count(),
+                needed: played.iter().filter(|game| game.needed.iter().any(|r| r == role)).count(),
+                avg_lead_hours: average(&role_leads),
+            }
+        })
+        .collect();
+
+    let mut reminders: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+    let mut unprompted_fills = 0;
+    for game in games.values() {
+        for kind in game.reminders.keys() {
+            reminders.entry(kind).or_default().0 += 1;
+        }
+        for lead in game.filled.values().flatten() {
+            match game.prompted_by(*lead) {
+                Some(kind) => reminders.entry(kind).or_default().1 += 1,
+                None => unprompted_fills += 1,
+            }
+        }
+    }
+
+    FillStats {
+        games_played: played.len(),
+        fully_staffed_percent: percent(played.iter().filter(|game| game.fully_staffed()).count(), played.len()),
+        timed_fills: leads.len(),
+        avg_lead_hours: average(&leads),
+        last_minute_percent: percent(leads.iter().filter(|hours| **hours < LAST_MINUTE_HOURS).count(), leads.len()),
+        roles,
+        reminders: reminders
+            .into_iter()
+            .map(|(reminder, (posts, fills))| ReminderStats { reminder: reminder.to_string(), posts, fills })
+            .collect(),
+        unprompted_fills,
+    }
+}
+
+/// Prometheus text exposition of `stats`
+pub fn prometheus_text(stats: &FillStats) -> String {
+    let mut out = String::new();
+    let mut gauge = |name: &str, help: &str, samples: Vec<(String, f64)>| {
+        if samples.is_empty() {
+            return;
+        }
+        out.push_str(&format!("# HELP {} {}\n# TYPE {} gauge\n", name, help, name));
+        for (labels, value) in samples {
+            out.push_str(&format!("{}{} {}\n", name, labels, value));
+        }
+    };
+
+    gauge("volunteer_games_played", "Games with first pitch behind them that the bot tracked",
+        vec![(String::new(), stats.games_played as f64)]);
+    gauge("volunteer_games_fully_staffed_percent", "Percent of played games with every needed volunteer spot filled",
+        stats.fully_staffed_percent.map(|p| (String::new(), p)).into_iter().collect());
+    gauge("volunteer_fill_lead_hours_avg", "Average hours between a volunteer spot being filled and first pitch",
+        stats.avg_lead_hours.map(|h| (String::new(), h)).into_iter().collect());
+    gauge("volunteer_last_minute_fill_percent", "Percent of volunteer spots filled within 48 hours of first pitch",
+        stats.last_minute_percent.map(|p| (String::new(), p)).into_iter().collect());
+    gauge("volunteer_role_fill_lead_hours_avg", "Average hours between a spot being filled and first pitch, by role",
+        stats.roles.iter().filter_map(|r| r.avg_lead_hours.map(|h| (format!("{{role=\"{}\"}}", r.role), h))).collect());
+    gauge("volunteer_role_fill_percent", "Percent of played games where the role was filled, by role",
+        stats.roles.iter().filter_map(|r| percent(r.filled, r.needed).map(|p| (format!("{{role=\"{}\"}}", r.role), p))).collect());
+    gauge("volunteer_reminder_posts", "Reminder posts for tracked games, by reminder",
+        stats.reminders.iter().map(|r| (format!("{{reminder=\"{}\"}}", r.reminder), r.posts as f64)).collect());
+    gauge("volunteer_reminder_fills", "Sign-ups within 6 hours after a reminder post, by reminder",
+        stats.reminders.iter().map(|r| (format!("{{reminder=\"{}\"}}", r.reminder), r.fills as f64)).collect());
+    out
+}
+
+/// When each game's volunteer spots got filled, learned by comparing each sheet load with the last.
+/// A game stops changing once it starts; kept in data/volunteer_fills.json.
+#[derive(Clone)]
+pub struct FillTracker {
+    games: Arc<RwLock<BTreeMap<NaiveDate, GameFills>>>,
+}
+
+impl Default for FillTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FillTracker {
+    pub fn new() -> Self {
+        let games = std::fs::read_to_string(VOLUNTEER_FILLS_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self { games: Arc::new(RwLock::new(games)) }
+    }
+
+    fn save(games: &BTreeMap<NaiveDate, GameFills>) {
+        if let Err(e) = std::fs::create_dir_all("data") { tracing::error!("Failed to create data dir: {}", e); }
+        if let Err(e) = std::fs::write(VOLUNTEER_FILLS_FILE, serde_json::to_string_pretty(games).unwrap_or_default()) {
+            tracing::error!("Failed to save volunteer fills: {}", e);
+        }
+    }
+
+    /// Note newly filled (and newly freed) spots on games that haven't started yet
+    pub async fn observe(&self, snapshots: &[GameSnapshot], now: DateTime<Utc>) {
+        let mut games = self.games.write().await;
+        let mut changed = false;
+        for (date, start, needed, filled) in snapshots {
+            if *start <= now {
+                continue;
+            }
+            let needed: Vec<String> = needed.iter().map(|r| r.to_string()).collect();
+            let Some(game) = games.get_mut(date) else {
+                let filled = filled.iter().map(|r| (r.to_string(), None)).collect();
+                games.insert(*date, GameFills { start: *start, needed, filled, reminders: BTreeMap::new() });
+                changed = true;
+                continue;
+            };
+
+            let lead_hours = (*start - now).num_minutes() as f64 / 60.0;
+            let before = (game.start, game.needed.clone(), game.filled.clone());
+            game.start = *start;
+            game.needed = needed;
+            game.filled.retain(|role, _| filled.contains(&role.as_str()));
+            for role in filled {
+                game.filled.entry(role.to_string()).or_insert(Some(lead_hours));
+            }
+            changed |= before != (game.start, game.needed.clone(), game.filled.clone());
+        }
+        if changed {
+            Self::save(&games);
+        }
+    }
+
+    /// Note a reminder post for the game starting at `start`, so later sign-ups can be credited to it
+    pub async fn record_reminder(&self, date: NaiveDate, start: DateTime<Utc>, kind: &str, now: DateTime<Utc>) {
+        let mut games = self.games.write().await;
+        // Only the day's first game is tracked
+        let Some(game) = games.get_mut(&date).filter(|game| game.start == start) else {
+            return;
+        };
+        game.reminders.insert(kind.to_string(), (start - now).num_minutes() as f64 / 60.0);
+        Self::save(&games);
+    }
+
+    pub async fn stats(&self, now: DateTime<Utc>) -> FillStats {
+        fill_stats(&*self.games.read().await, now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_fill_tracking() {
+        let tracker = FillTracker { games: Arc::new(RwLock::new(BTreeMap::new())) };
+        let date = NaiveDate::from_ymd_opt(2026, 5, 2).unwrap();
+        let start = date.and_hms_opt(15, 0, 0).unwrap().and_utc();
+        let needed = vec!["snacks", "livestream"];
+
+        let observe = |filled: Vec<&'static str>, hours_before: i64| {
+            let tracker = tracker.clone();
+            let needed = needed.clone();
+            async move { tracker.observe(&[(date, start, needed, filled)], start - Duration::hours(hours_before)).await }
+        };
+        // Snacks was already taken when the bot first saw the game, so its timing is unknown
+        observe(vec!["snacks"], 100).await;
+        observe(vec!["snacks", "livestream"], 24).await;
+        // Seen after first pitch: ignored
+        observe(vec!["snacks"], -1).await;
+
+        let stats = tracker.stats(start + Duration::hours(3)).await;
+        assert_eq!(stats.games_played, 1);
+        assert_eq!(stats.fully_staffed_percent, Some(100.0));
+        assert_eq!(stats.timed_fills, 1);
+        assert_eq!(stats.avg_lead_hours, Some(24.0));
+        assert_eq!(stats.last_minute_percent, Some(100.0));
+
+        let text = prometheus_text(&stats);
+        assert!(text.contains("volunteer_games_fully_staffed_percent 100\n"));
+        assert!(text.contains("volunteer_role_fill_lead_hours_avg{role=\"livestream\"} 24\n"));
+        assert!(!text.contains("role=\"snacks\"} 24"));
+    }
+
+    #[test]
+    fn test_reminder_attribution() {
+        let start = NaiveDate::from_ymd_opt(2026, 5, 2).unwrap().and_hms_opt(15, 0, 0).unwrap().and_utc();
+        let game = GameFills {
+            start,
+            needed: vec!["snacks".to_string(), "livestream".to_string(), "scoreboard".to_string()],
+            filled: [("snacks", Some(22.0)), ("livestream", Some(100.0)), ("scoreboard", Some(4.0))]
+                .into_iter()
+                .map(|(role, lead)| (role.to_string(), lead))
+                .collect(),
+            reminders: [("24h".to_string(), 23.5), ("briefing".to_string(), 6.0)].into_iter().collect(),
+        };
+        assert_eq!(game.prompted_by(22.0), Some("24h"));
+        assert_eq!(game.prompted_by(4.0), Some("briefing"));
+        assert_eq!(game.prompted_by(100.0), None);
+        // Too long after the 24h reminder to credit it
+        assert_eq!(game.prompted_by(10.0), None);
+
+        let stats = fill_stats(&[(start.date_naive(), game)].into_iter().collect(), start);
+        assert_eq!(stats.unprompted_fills, 1);
+        assert_eq!(stats.reminders, vec![
+            ReminderStats { reminder: "24h".to_string(), posts: 1, fills: 1 },
+            ReminderStats { reminder: "briefing".to_string(), posts: 1, fills: 1 },
+        ]);
+    }
+}
@@ -0,0 +1,116 @@
+use chrono::{DateTime, Utc};
+use std::future::Future;
+use std::sync::atomic::{AtomicI64, AtomicU32, Ordering};
+use std::sync::Arc;
+use tokio::time::{sleep, Duration as TokioDuration};
+use tracing::{error, info, warn};
+
+use crate::service::BotService;
+
+/// Last time a background loop checked in, shared with /healthz
+#[derive(Clone)]
+pub struct Heartbeat {
+    last_tick: Arc<AtomicI64>,
+    restarts: Arc<AtomicU32>,
+    /// A loop that hasn't ticked for this long counts as stalled
+    pub stall_after: chrono::Duration,
+}
+
+impl Heartbeat {
+    pub fn new(stall_after: chrono::Duration) -> Self {
+        Self {
+            // Starting counts as a tick so a fresh loop isn't reported stalled
+            last_tick: Arc::new(AtomicI64::new(Utc::now().timestamp())),
+            restarts: Arc::new(AtomicU32::new(0)),
+            stall_after,
+        }
+    }
+
+    pub fn tick(&self) {
+        self.last_tick.store(Utc::now().timestamp(), Ordering::Relaxed);
+    }
+
+    pub fn last_tick(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp(self.last_tick.load(Ordering::Relaxed), 0).unwrap_or_default()
+    }
+
+    pub fn since_last_tick(&self) -> chrono::Duration {
+        Utc::now() - self.last_tick()
+    }
+
+    pub fn is_stalled(&self) -> bool {
+        self.since_last_tick() > self.stall_after
+    }
+
+    pub fn restarts(&self) -> u32 {
+        self.restarts.load(Ordering::Relaxed)
+    }
+}
+
+/// Keep a background loop running: restart it when it panics, returns, or stops ticking,
+/// and DM the admin when it stalls or dies so a silent outage doesn't go unnoticed
+pub fn supervise<F, Fut>(name: &'static str, heartbeat: Heartbeat, bot_service: Arc<BotService>, admin_user_id: String, run: F)
+where
+    F: Fn() -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    tokio::spawn(async move {
+        let check_every = TokioDuration::from_secs(60);
+
+        loop {
+            heartbeat.tick();
+            let mut handle = tokio::spawn(run());
+
+            let problem = loop {
+                tokio::select! {
+                    result = &mut handle => {
+                        break match result {
+                            Err(e) if e.is_panic() => format!("the {} loop crashed", name),
+                            Err(e) => format!("the {} loop was cancelled: {}", name, e),
+                            Ok(()) => format!("the {} loop exited", name),
+                        };
+                    }
+                    _ = sleep(check_every) => {
+                        if heartbeat.is_stalled() {
+                            handle.abort();
+                            break format!(
+                                "the {} loop hasn't checked in since {}",
+                                name,
+                                heartbeat.last_tick().with_timezone(&chrono::Local).format("%-I:%M %p")
+                            );
+                        }
+                    }
+                }
+            };
+
+            heartbeat.restarts.fetch_add(1, Ordering::Relaxed);
+            error!("Watchdog: {}; restarting it", problem);
+            if !admin_user_id.is_empty() {
+                let text = format!("🚨 Watchdog: {}. I restarted it, but check the logs if this keeps happening.", problem);
+                if let Err(e) = bot_service.alert_admin(&text).await {
+                    warn!("Failed to alert the admin about the {} loop: {}", name, e);
+                }
+            }
+
+            sleep(TokioDuration::from_secs(30)).await;
+            info!("Watchdog: restarting the {} loop", name);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heartbeat_stall() {
+        let heartbeat = Heartbeat::new(chrono::Duration::minutes(15));
+        assert!(!heartbeat.is_stalled());
+
+        heartbeat.last_tick.store((Utc::now() - chrono::Duration::minutes(20)).timestamp(), Ordering::Relaxed);
+        assert!(heartbeat.is_stalled());
+
+        heartbeat.tick();
+        assert!(!heartbeat.is_stalled());
+    }
+}
@@ -0,0 +1,115 @@
+use embedded_graphics::mono_font::iso_8859_1::{FONT_7X13, FONT_9X15};
+
+use crate::error::{BotError, Result};
+use crate::raster::Canvas;
+use crate::weather_client::DayForecast;
+
+const SLOT_WIDTH: u32 = 56;
+const LEFT: u32 = 12;
+const TITLE_HEIGHT: u32 = 30;
+const PLOT_TOP: u32 = TITLE_HEIGHT + 20;
+const PLOT_HEIGHT: u32 = 140;
+const HEIGHT: u32 = PLOT_TOP + PLOT_HEIGHT + 44;
+
+const GAME_TIME: [u8; 3] = [255, 244, 200];
+const RAIN: [u8; 3] = [150, 190, 235];
+const TEMPERATURE: [u8; 3] = [210, 60, 40];
+const AXIS: [u8; 3] = [170, 170, 170];
+
+/// Hours worth charting for a game: two before the start to three after, or daytime when the time isn't known
+pub fn game_window(game_hour: Option<u32>) -> (u32, u32) {
+    match game_hour {
+        Some(hour) => (hour.saturating_sub(2), (hour + 3).min(23)),
+        None => (8, 20),
+    }
+}
+
+/// "9a", "12p", "3p"
+fn hour_label(hour: u32) -> String {
+    let suffix = if hour < 12 { "a" } else { "p" };
+    let twelve = match hour % 12 {
+        0 => 12,
+        h => h,
+    };
+    format!("{}{}", twelve, suffix)
+}
+
+/// PNG chart of one day's forecast between two hours: rain chance as bars, temperature as a line.
+/// The game's start hour is shaded.
+pub fn chart_png(forecast: &DayForecast, first_hour: u32, last_hour: u32, game_hour: Option<u32>) -> Result<Vec<u8>> {
+    let points: Vec<_> = forecast.hours.iter().filter(|p| p.hour >= first_hour && p.hour <= last_hour).collect();
+    if points.is_empty() {
+        return Err(BotError::InvalidCommand("No forecast hours to chart".to_string()));
+    }
+
+    let title = format!("{} - {} and chance of rain", forecast.location_name, forecast.unit);
+    let width = (LEFT * 2 + SLOT_WIDTH * points.len() as u32).max(title.chars().count() as u32 * 9 + LEFT * 2);
+    let mut canvas = Canvas::new(width, HEIGHT);
+    canvas.text(&title, LEFT as i32, 8, &FONT_9X15);
+
+    // Keep at least a 10 degree range so a flat day doesn't look like a roller coaster
+    let low = points.iter().map(|p| p.temperature).fold(f64::MAX, f64::min);
+    let high = points.iter().map(|p| p.temperature).fold(f64::MIN, f64::max).max(low + 10.0);
+    let plot_bottom = PLOT_TOP + PLOT_HEIGHT;
+    let temperature_y = |temperature: f64| {
+        let share = (temperature - low) / (high - low);
+        // Leave room above the line for its labels
+        (plot_bottom as f64 - 10.0 - share * (PLOT_HEIGHT as f64 - 30.0)) as i32
+    };
+
+    let mut line_points = Vec::new();
+    for (index, point) in points.iter().enumerate() {
+        let x = LEFT + SLOT_WIDTH * index as u32;
+        let center = (x + SLOT_WIDTH / 2) as i32;
+        if Some(point.hour) == game_hour {
+            canvas.fill(x, PLOT_TOP - 10, SLOT_WIDTH, PLOT_HEIGHT + 10, GAME_TIME);
+        }
+
+        let bar = (point.precipitation_chance.clamp(0.0, 100.0) / 100.0 * PLOT_HEIGHT as f64) as u32;
+        canvas.fill(x + 10, plot_bottom - bar, SLOT_WIDTH - 20, bar, RAIN);
+
+        let y = temperature_y(point.temperature);
+        canvas.text(&format!("{:.0}°", point.temperature), center - 10, y - 18, &FONT_7X13);
+        line_points.push((center, y));
+
+        canvas.text(&hour_label(point.hour), center - 10, plot_bottom as i32 + 6, &FONT_7X13);
+        canvas.text(&format!("{:.0}%", point.precipitation_chance), center - 10, plot_bottom as i32 + 24, &FONT_7X13);
+    }
+
+    for pair in line_points.windows(2) {
+        canvas.line(pair[0], pair[1], TEMPERATURE, 3);
+    }
+    for (x, y) in &line_points {
+        canvas.fill((*x - 3) as u32, (*y - 3) as u32, 7, 7, TEMPERATURE);
+    }
+    canvas.fill(LEFT, plot_bottom, SLOT_WIDTH * points.len() as u32, 1, AXIS);
+
+    canvas.into_png()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weather_client::HourlyPoint;
+
+    #[test]
+    fn test_chart_png() {
+        let forecast = DayForecast {
+            location_name: "Plano, Texas".to_string(),
+            unit: "°F".to_string(),
+            hours: (0..24)
+                .map(|hour| HourlyPoint { hour, temperature: 60.0 + hour as f64, precipitation_chance: (hour * 4) as f64 })
+                .collect(),
+        };
+
+        let (first, last) = game_window(Some(10));
+        assert_eq!((first, last), (8, 13));
+        let png = chart_png(&forecast, first, last, Some(10)).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+
+        assert_eq!(game_window(Some(22)), (20, 23));
+        assert_eq!(hour_label(0), "12a");
+        assert_eq!(hour_label(15), "3p");
+        assert!(chart_png(&forecast, 30, 31, None).is_err());
+    }
+}
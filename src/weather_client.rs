@@ -1,9 +1,16 @@
 use reqwest::Client;
 use serde::Deserialize;
 use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
 use crate::error::{BotError, Result};
 use tracing::{info, warn};
 
+/// How long a fetched forecast is reused before asking Open-Meteo again
+const FORECAST_TTL: Duration = Duration::from_secs(1800);
+
 #[derive(Debug, Deserialize)]
 struct GeocodingResponse {
     results: Option<Vec<GeocodingResult>>,
@@ -17,13 +24,13 @@ struct GeocodingResult {
     admin1: Option<String>, // State/Region
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct WeatherResponse {
     hourly: HourlyWeather,
     hourly_units: HourlyUnits,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct HourlyWeather {
     time: Vec<String>,
     temperature_2m: Vec<f64>,
@@ -31,47 +38,64 @@ struct HourlyWeather {
     weather_code: Vec<i32>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct HourlyUnits {
     temperature_2m: String,
 }
 
+/// One hour of a day's forecast
+#[derive(Debug, Clone)]
+pub struct HourlyPoint {
+    pub hour: u32,
+    pub temperature: f64,
+    pub precipitation_chance: f64,
+}
+
+/// Hour-by-hour forecast for one day at a game's location
+#[derive(Debug, Clone)]
+pub struct DayForecast {
+    pub location_name: String,
+    pub unit: String,
+    pub hours: Vec<HourlyPoint>,
+}
+
+type Geocode = (f64, f64, String);
+type CachedForecast = (Instant, String, WeatherResponse);
+
 #[derive(Clone)]
 pub struct WeatherClient {
     client: Client,
+    // Sheet location -> coordinates and display name; venues don't move, so kept for the process lifetime
+    geocodes: Arc<RwLock<HashMap<String, Geocode>>>,
+    // (sheet location, date) -> when fetched, display name, forecast
+    forecasts: Arc<RwLock<HashMap<(String, NaiveDate), CachedForecast>>>,
 }
 
 impl WeatherClient {
     pub fn new() -> Self {
         Self {
             client: Client::new(),
+            geocodes: Arc::new(RwLock::new(HashMap::new())),
+            forecasts: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
+    /// Look up a location ahead of time so the first weather question doesn't wait on geocoding
+    pub async fn warm_location(&self, location: &str) -> bool {
+        self.geocode(location).await.is_ok()
+    }
+
+    /// Fetch a day's forecast ahead of time; it's reused for FORECAST_TTL
+    pub async fn warm_forecast(&self, location: &str, date: NaiveDate) -> bool {
+        self.fetch_day(location, date).await.is_ok()
+    }
+
     pub async fn get_forecast(&self, location: &str, date: NaiveDate, time_str: &str) -> Result<String> {
-        // 1. Geocode location
-        let (lat, lon, location_name) = self.geocode(location).await?;
-        
-        // 2. Parse game time to find relevant forecast hour
+        // Parse game time to find relevant forecast hour
         // time_str expected format: "HH:MM AM/PM" or "HH:MM"
         // We need to construct a target datetime to match against hourly forecast
         let hour_offset = self.parse_hour_from_time(time_str).unwrap_or(12); // Default to noon if parse fails
-        
-        // 3. Fetch weather
-        let url = format!(
-            "https://api.open-meteo.com/v1/forecast?latitude={}&longitude={}&hourly=temperature_2m,precipitation_probability,weather_code&temperature_unit=fahrenheit&start_date={}&end_date={}&timezone=auto",
-            lat, lon, date, date
-        );
-        
-        info!("Fetching weather for {} ({}, {}) on {}", location_name, lat, lon, date);
-        
-        let response = self.client.get(&url).send().await?;
-        
-        if !response.status().is_success() {
-            return Err(BotError::GoogleApi(format!("Weather API failed: {}", response.status())));
-        }
-        
-        let weather_data: WeatherResponse = response.json().await?;
+        let (location_name, weather_data) = self.fetch_day(location, date).await?;
         
         // Find index for the game time (approximate)
         // API returns hourly data starting from 00:00 local time
@@ -92,8 +116,71 @@ impl WeatherClient {
             Ok("Weather data not available for this time.".to_string())
         }
     }
-    
+
+    /// Temperature and precipitation chance for every hour of `date`, for the game-day chart
+    pub async fn get_hourly_forecast(&self, location: &str, date: NaiveDate) -> Result<DayForecast> {
+        let (location_name, weather_data) = self.fetch_day(location, date).await?;
+        let hourly = weather_data.hourly;
+        let hours = hourly.temperature_2m
+            .iter()
+            .zip(&hourly.precipitation_probability)
+            .enumerate()
+            .map(|(hour, (temperature, precipitation_chance))| HourlyPoint {
+                hour: hour as u32,
+                temperature: *temperature,
+                precipitation_chance: *precipitation_chance,
+            })
+            .collect();
+        Ok(DayForecast { location_name, unit: weather_data.hourly_units.temperature_2m, hours })
+    }
+
+    /// The hour a game starts, from the sheet's time column
+    pub fn game_hour(&self, time_str: &str) -> Option<u32> {
+        self.parse_hour_from_time(time_str).filter(|hour| *hour < 24)
+    }
+
+    /// Geocode the location and fetch its hourly forecast for one day, reusing a recent fetch
+    async fn fetch_day(&self, location: &str, date: NaiveDate) -> Result<(String, WeatherResponse)> {
+        let key = (location.trim().to_lowercase(), date);
+        if let Some((fetched_at, name, data)) = self.forecasts.read().await.get(&key) {
+            if fetched_at.elapsed() < FORECAST_TTL {
+                return Ok((name.clone(), data.clone()));
+            }
+        }
+
+        let (lat, lon, location_name) = self.geocode(location).await?;
+
+        let url = format!(
+            "https://api.open-meteo.com/v1/forecast?latitude={}&longitude={}&hourly=temperature_2m,precipitation_probability,weather_code&temperature_unit=fahrenheit&start_date={}&end_date={}&timezone=auto",
+            lat, lon, date, date
+        );
+        
+        info!("Fetching weather for {} ({}, {}) on {}", location_name, lat, lon, date);
+        
+        let response = self.client.get(&url).send().await?;
+        
+        if !response.status().is_success() {
+            return Err(BotError::GoogleApi(format!("Weather API failed: {}", response.status())));
+        }
+        
+        let weather_data: WeatherResponse = response.json().await?;
+        let mut forecasts = self.forecasts.write().await;
+        forecasts.retain(|_, (fetched_at, _, _)| fetched_at.elapsed() < FORECAST_TTL);
+        forecasts.insert(key, (Instant::now(), location_name.clone(), weather_data.clone()));
+        Ok((location_name, weather_data))
+    }
+
     async fn geocode(&self, location: &str) -> Result<(f64, f64, String)> {
+        let key = location.trim().to_lowercase();
+        if let Some(found) = self.geocodes.read().await.get(&key) {
+            return Ok(found.clone());
+        }
+        let found = self.lookup_location(location).await?;
+        self.geocodes.write().await.insert(key, found.clone());
+        Ok(found)
+    }
+
+    async fn lookup_location(&self, location: &str) -> Result<(f64, f64, String)> {
         // Strategy 1: Try content inside parentheses (often City)
         // e.g. "Field 1 (Plano)" -> "Plano"
         if let (Some(start), Some(end)) = (location.find('('), location.find(')')) {